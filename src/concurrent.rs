@@ -0,0 +1,904 @@
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::key::Key;
+
+use alloc_crate::sync::Arc;
+use core::any;
+use core::borrow::Borrow;
+use core::fmt;
+use core::marker;
+use core::mem;
+use core::ops;
+use opaque::index_map::TypeErasedIndexMap;
+use std::hash;
+use std::sync;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+use hashbrown::hash_map;
+
+/// The storage of one type in a concurrent heterogeneous hash map.
+struct TypeCell(TypeErasedIndexMap);
+
+// SAFETY: A type cell is only constructed by `ConcurrentHeterogeneousHashMap::new_type_lock`,
+// which requires the key type, the value type, the hash builder, and the memory allocator of the
+// storage to be `Send` and `Sync`. The storage therefore only ever contains data that is safe to
+// send to, and to share between, threads.
+unsafe impl Send for TypeCell {}
+
+// SAFETY: See the `Send` implementation above.
+unsafe impl Sync for TypeCell {}
+
+/// The lock guarding the storage of one type in a concurrent heterogeneous hash map.
+///
+/// The lock is reference counted so that a guard keeps the storage alive even if the type is
+/// removed from the map while the guard is held.
+type TypeLock = Arc<sync::RwLock<TypeCell>>;
+
+/// A heterogeneous hash map that can be shared between threads, with one lock per type.
+///
+/// A concurrent heterogeneous hash map stores the values of each type in its own
+/// [`HomogeneousHashMap`], behind its own [`RwLock`]. The table of types is guarded by a separate
+/// lock that is only held long enough to look up, insert, or remove a type. Threads working on
+/// different types therefore never contend with each other, and threads reading the same type
+/// share its lock.
+///
+/// The values of one type are accessed through the guards returned by [`read`] and [`write`],
+/// which dereference to the homogeneous hash map storing that type. The convenience methods
+/// [`insert`], [`get_cloned`], and [`remove`] acquire and release the lock of a type for a single
+/// operation.
+///
+/// Every type stored in a concurrent heterogeneous hash map must be [`Send`] and [`Sync`], so the
+/// map itself can be shared between threads whenever its key type can.
+///
+/// A panic while a guard is held does not poison the map, and subsequent calls simply acquire
+/// the lock again. They observe the storage exactly as the panicking thread left it, so a panic
+/// in the middle of a sequence of updates through a write guard can leave that sequence
+/// partially applied. Callers that need all-or-nothing updates must stage them before acquiring
+/// the write guard.
+///
+/// [`RwLock`]: std::sync::RwLock
+/// [`read`]: ConcurrentHeterogeneousHashMap::read
+/// [`write`]: ConcurrentHeterogeneousHashMap::write
+/// [`insert`]: ConcurrentHeterogeneousHashMap::insert
+/// [`get_cloned`]: ConcurrentHeterogeneousHashMap::get_cloned
+/// [`remove`]: ConcurrentHeterogeneousHashMap::remove
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+/// # use std::thread;
+/// #
+/// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+///
+/// thread::scope(|scope| {
+///     scope.spawn(|| {
+///         for i in 0..10 {
+///             het_map.insert(Key::new(i), i as i32);
+///         }
+///     });
+///     scope.spawn(|| {
+///         for i in 0..10 {
+///             het_map.insert(Key::new(i), i.to_string());
+///         }
+///     });
+/// });
+///
+/// assert_eq!(het_map.len_types(), 2);
+/// assert_eq!(het_map.len::<i32>(), Some(10));
+/// assert_eq!(het_map.len::<String>(), Some(10));
+/// assert_eq!(het_map.get_cloned::<i32, _>(&3_usize), Some(3_i32));
+/// assert_eq!(het_map.get_cloned::<String, _>(&3_usize), Some(String::from("3")));
+/// ```
+pub struct ConcurrentHeterogeneousHashMap<K, S = hash::RandomState, A = alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    types: sync::RwLock<hash_map::HashMap<any::TypeId, TypeLock, S>>,
+    build_hasher: S,
+    alloc: A,
+    _marker: marker::PhantomData<K>,
+}
+
+impl<K, S, A> ConcurrentHeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new empty concurrent heterogeneous hash map with the custom hash builder and
+    /// the custom memory allocator.
+    ///
+    /// The storage for the values of every type inserted into the map is allocated from `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::ConcurrentHeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize, RandomState, Global> = ConcurrentHeterogeneousHashMap::with_hasher_in(
+    ///     RandomState::new(),
+    ///     Global,
+    /// );
+    ///
+    /// assert!(het_map.is_empty_types());
+    ///
+    /// het_map.insert_type::<i8>();
+    ///
+    /// assert_eq!(het_map.len_types(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_hasher_in(build_hasher: S, alloc: A) -> Self {
+        Self {
+            types: sync::RwLock::new(hash_map::HashMap::with_hasher(build_hasher.clone())),
+            build_hasher,
+            alloc,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<K, S> ConcurrentHeterogeneousHashMap<K, S, alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+{
+    /// Constructs a new empty concurrent heterogeneous hash map with the custom hash builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::ConcurrentHeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize, RandomState> = ConcurrentHeterogeneousHashMap::with_hasher(RandomState::new());
+    ///
+    /// assert!(het_map.is_empty_types());
+    ///
+    /// het_map.insert_type::<i8>();
+    ///
+    /// assert_eq!(het_map.len_types(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_hasher(build_hasher: S) -> Self {
+        Self::with_hasher_in(build_hasher, alloc::Global)
+    }
+}
+
+impl<K> ConcurrentHeterogeneousHashMap<K, hash::RandomState, alloc::Global>
+where
+    K: any::Any,
+{
+    /// Constructs a new empty concurrent heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::ConcurrentHeterogeneousHashMap;
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.is_empty_types());
+    ///
+    /// het_map.insert_type::<i8>();
+    ///
+    /// assert_eq!(het_map.len_types(), 1);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(hash::RandomState::new())
+    }
+}
+
+impl<K, S, A> ConcurrentHeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Acquires the lock on the table of types for reading.
+    fn read_types(&self) -> sync::RwLockReadGuard<'_, hash_map::HashMap<any::TypeId, TypeLock, S>> {
+        self.types.read().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+
+    /// Acquires the lock on the table of types for writing.
+    fn write_types(&self) -> sync::RwLockWriteGuard<'_, hash_map::HashMap<any::TypeId, TypeLock, S>> {
+        self.types.write().unwrap_or_else(sync::PoisonError::into_inner)
+    }
+
+    /// Constructs the lock guarding a new empty storage for the type `T`.
+    fn new_type_lock<T>(&self) -> TypeLock
+    where
+        K: Send + Sync,
+        T: any::Any + Send + Sync,
+    {
+        let proj_map = opaque::index_map::TypeProjectedIndexMap::<Key<K, T>, T, S, A>::with_hasher_in(
+            self.build_hasher.clone(),
+            self.alloc.clone(),
+        );
+
+        Arc::new(sync::RwLock::new(TypeCell(TypeErasedIndexMap::from_proj(proj_map))))
+    }
+
+    /// Returns the lock guarding the storage of the type `T`, if the type exists in the map.
+    ///
+    /// The lock on the table of types is released before this method returns.
+    fn type_lock<T>(&self) -> Option<TypeLock>
+    where
+        T: any::Any,
+    {
+        self.read_types().get(&any::TypeId::of::<T>()).cloned()
+    }
+
+    /// Returns the lock guarding the storage of the type `T`, inserting the type into the map
+    /// first if it does not exist.
+    ///
+    /// The lock on the table of types is released before this method returns.
+    fn type_lock_or_insert<T>(&self) -> TypeLock
+    where
+        K: Send + Sync,
+        T: any::Any + Send + Sync,
+    {
+        if let Some(lock) = self.type_lock::<T>() {
+            return lock;
+        }
+
+        let mut types = self.write_types();
+        let lock = types
+            .entry(any::TypeId::of::<T>())
+            .or_insert_with(|| self.new_type_lock::<T>());
+
+        Arc::clone(lock)
+    }
+
+    /// Returns the number of types stored in the concurrent heterogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time, after acquiring the lock on the table of types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.len_types(), 0);
+    ///
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert(Key::new(1_usize), 2_f64);
+    ///
+    /// assert_eq!(het_map.len_types(), 2);
+    /// ```
+    pub fn len_types(&self) -> usize {
+        self.read_types().len()
+    }
+
+    /// Determines whether the concurrent heterogeneous hash map contains no types.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time, after acquiring the lock on the table of types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::ConcurrentHeterogeneousHashMap;
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.is_empty_types());
+    ///
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(!het_map.is_empty_types());
+    /// ```
+    pub fn is_empty_types(&self) -> bool {
+        self.len_types() == 0
+    }
+
+    /// Determines whether the concurrent heterogeneous hash map contains the type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time, after acquiring the lock on the table of types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::ConcurrentHeterogeneousHashMap;
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert!(!het_map.contains_type::<i32>());
+    ///
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(het_map.contains_type::<i32>());
+    /// assert!(!het_map.contains_type::<u64>());
+    /// ```
+    pub fn contains_type<T>(&self) -> bool
+    where
+        T: any::Any,
+    {
+        self.read_types().contains_key(&any::TypeId::of::<T>())
+    }
+
+    /// Inserts a new type into the concurrent heterogeneous hash map.
+    ///
+    /// This method returns `true` if the type `T` did not exist in the map, and `false` if it
+    /// did. If the type already exists, its values are left untouched.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** amortized time, after acquiring the lock on the table of
+    /// types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.insert_type::<i32>());
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    ///
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert!(!het_map.insert_type::<i32>());
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// ```
+    pub fn insert_type<T>(&self) -> bool
+    where
+        K: Send + Sync,
+        T: any::Any + Send + Sync,
+    {
+        let type_id = any::TypeId::of::<T>();
+        if self.read_types().contains_key(&type_id) {
+            return false;
+        }
+
+        let mut types = self.write_types();
+        if types.contains_key(&type_id) {
+            return false;
+        }
+
+        types.insert(type_id, self.new_type_lock::<T>());
+
+        true
+    }
+
+    /// Removes the type `T` from the concurrent heterogeneous hash map, together with every value
+    /// of that type.
+    ///
+    /// This method returns `Some(count)`, where `count` is the number of values removed, if the
+    /// type `T` existed in the map. This method returns `None` otherwise.
+    ///
+    /// The type is detached from the map before this method returns, so later calls no longer
+    /// see it, but the storage itself is reference counted. A guard for the type `T` acquired
+    /// before the removal keeps working on the detached storage, its changes are not visible
+    /// through the map, and the storage is deallocated once the last such guard is dropped. To
+    /// count the removed values, this method acquires the read lock of the detached storage, so
+    /// it blocks while a write guard for the type `T` is held, and returns the number of values
+    /// at the moment it acquires the lock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// assert_eq!(het_map.remove_type::<i32>(), Some(2));
+    /// assert_eq!(het_map.remove_type::<i32>(), None);
+    /// assert!(!het_map.contains_type::<i32>());
+    /// ```
+    pub fn remove_type<T>(&self) -> Option<usize>
+    where
+        T: any::Any,
+    {
+        let lock = self.write_types().remove(&any::TypeId::of::<T>())?;
+        let map = lock.read().unwrap_or_else(sync::PoisonError::into_inner);
+        let map = HomogeneousHashMap::<K, T, S, A>::from_inner_ref(map.0.as_proj::<Key<K, T>, T, S, A>());
+
+        Some(map.len())
+    }
+
+    /// Acquires shared read access to the values of the type `T`.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map. Otherwise, this
+    /// method blocks the current thread until no writer holds the lock of the type `T`, and
+    /// returns a guard that dereferences to the homogeneous hash map storing the values of that
+    /// type. Any number of readers of the same type may hold a guard at the same time. The locks
+    /// of other types are unaffected.
+    ///
+    /// Acquiring a second guard for the same type on the same thread while holding a write guard
+    /// for it deadlocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// let map = het_map.read::<i32>().unwrap();
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&3_usize), Some(&4_i32));
+    /// assert!(het_map.read::<u64>().is_none());
+    /// ```
+    pub fn read<T>(&self) -> Option<TypeReadGuard<'_, K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.type_lock::<T>().map(TypeReadGuard::new)
+    }
+
+    /// Acquires exclusive write access to the values of the type `T`.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map. Otherwise, this
+    /// method blocks the current thread until no other thread holds the lock of the type `T`,
+    /// and returns a guard that mutably dereferences to the homogeneous hash map storing the
+    /// values of that type. The locks of other types are unaffected.
+    ///
+    /// Acquiring a second guard for the same type on the same thread while holding the write
+    /// guard deadlocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// {
+    ///     let mut map = het_map.write::<i32>().unwrap();
+    ///     map.insert(Key::new(1_usize), 2_i32);
+    ///     map.insert(Key::new(3_usize), 4_i32);
+    /// }
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert!(het_map.write::<u64>().is_none());
+    /// ```
+    pub fn write<T>(&self) -> Option<TypeWriteGuard<'_, K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.type_lock::<T>().map(TypeWriteGuard::new)
+    }
+
+    /// Returns the number of values of the type `T` in the concurrent heterogeneous hash map.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.len::<i32>(), None);
+    ///
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// ```
+    pub fn len<T>(&self) -> Option<usize>
+    where
+        T: any::Any,
+    {
+        self.read::<T>().map(|map| map.len())
+    }
+
+    /// Determines whether the concurrent heterogeneous hash map contains no values of the type
+    /// `T`.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert(Key::new(1_usize), 2_f64);
+    ///
+    /// assert_eq!(het_map.is_empty::<i32>(), Some(true));
+    /// assert_eq!(het_map.is_empty::<f64>(), Some(false));
+    /// assert_eq!(het_map.is_empty::<u64>(), None);
+    /// ```
+    pub fn is_empty<T>(&self) -> Option<bool>
+    where
+        T: any::Any,
+    {
+        self.read::<T>().map(|map| map.is_empty())
+    }
+
+    /// Determines whether the concurrent heterogeneous hash map contains a value of the type `T`
+    /// with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
+    /// assert!(!het_map.contains_key::<i32, _>(&3_usize));
+    /// assert!(!het_map.contains_key::<u64, _>(&1_usize));
+    /// ```
+    pub fn contains_key<T, Q>(&self, key: &Q) -> bool
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.read::<T>().is_some_and(|map| map.contains_key(key))
+    }
+
+    /// Inserts a value of the type `T` into the concurrent heterogeneous hash map under the
+    /// given key.
+    ///
+    /// The type `T` is inserted into the map first if it does not exist. This method returns
+    /// `Some(old_value)` if the map already contained a value of the type `T` with the same key,
+    /// and `None` otherwise. The lock of the type `T` is held only for the duration of the
+    /// insertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.insert(Key::new(1_usize), 2_i32), None);
+    /// assert_eq!(het_map.insert(Key::new(1_usize), 3_i32), Some(2_i32));
+    /// assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), Some(3_i32));
+    /// ```
+    pub fn insert<T>(&self, key: Key<K, T>, value: T) -> Option<T>
+    where
+        K: hash::Hash + Eq + Send + Sync,
+        T: any::Any + Send + Sync,
+    {
+        let mut map = TypeWriteGuard::<K, T, S, A>::new(self.type_lock_or_insert::<T>());
+
+        map.insert(key, value)
+    }
+
+    /// Returns a clone of the value of the type `T` with the given key, if it exists.
+    ///
+    /// The value is cloned while the read lock of the type `T` is held, so that no reference into
+    /// the map outlives the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// assert_eq!(het_map.get_cloned::<String, _>(&1_usize), Some(String::from("foo")));
+    /// assert_eq!(het_map.get_cloned::<String, _>(&2_usize), None);
+    /// assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), None);
+    /// ```
+    pub fn get_cloned<T, Q>(&self, key: &Q) -> Option<T>
+    where
+        T: any::Any + Clone,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.read::<T>()?.get(key).cloned()
+    }
+
+    /// Removes the value of the type `T` with the given key from the concurrent heterogeneous
+    /// hash map, and returns it, if it exists.
+    ///
+    /// The removal behaves like [`HomogeneousHashMap::remove`]: the last value of the type `T`
+    /// takes the place of the removed value in storage order. The type `T` stays in the map even
+    /// if it has no values left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+    /// #
+    /// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.remove::<i32, _>(&1_usize), Some(2_i32));
+    /// assert_eq!(het_map.remove::<i32, _>(&1_usize), None);
+    /// assert!(het_map.contains_type::<i32>());
+    /// ```
+    pub fn remove<T, Q>(&self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.write::<T>()?.remove(key)
+    }
+}
+
+impl<K, S, A> Default for ConcurrentHeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone + Default,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher_in(S::default(), A::default())
+    }
+}
+
+impl<K, S, A> fmt::Debug for ConcurrentHeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Formats the concurrent heterogeneous hash map.
+    ///
+    /// Only the number of types is formatted, so that formatting never waits on the lock of a
+    /// type.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ConcurrentHeterogeneousHashMap")
+            .field("len_types", &self.len_types())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A guard granting shared read access to the values of one type in a concurrent heterogeneous
+/// hash map.
+///
+/// A read guard dereferences to the [`HomogeneousHashMap`] storing the values of the type `T`.
+/// The read lock of the type is released when the guard is dropped. Read guards are returned by
+/// [`ConcurrentHeterogeneousHashMap::read`].
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+/// #
+/// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+///
+/// let first = het_map.read::<i32>().unwrap();
+/// let second = het_map.read::<i32>().unwrap();
+///
+/// assert_eq!(first.get(&1_usize), second.get(&1_usize));
+/// ```
+pub struct TypeReadGuard<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    // The guard borrows the lock kept alive by `_lock`, so it must be dropped first. The `Drop`
+    // implementation below drops it explicitly, independently of the field order.
+    guard: mem::ManuallyDrop<sync::RwLockReadGuard<'a, TypeCell>>,
+    _lock: TypeLock,
+    _marker: marker::PhantomData<(K, T, S, A)>,
+}
+
+impl<'a, K, T, S, A> TypeReadGuard<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Acquires the read lock of the storage of the type `T`.
+    fn new(lock: TypeLock) -> Self {
+        // SAFETY: The `RwLock` lives in the heap allocation of the `Arc`, so moving `lock` into
+        // the returned guard does not move it. The guard keeps `lock` alive for as long as the
+        // lock guard exists, since `Drop for TypeReadGuard` drops the lock guard before `lock`
+        // is released. The reference is never handed out with the lifetime `'a`, as `Deref` only
+        // reborrows it for the lifetime of the guard itself.
+        let rw_lock: &'a sync::RwLock<TypeCell> = unsafe { &*Arc::as_ptr(&lock) };
+        let guard = rw_lock.read().unwrap_or_else(sync::PoisonError::into_inner);
+
+        Self {
+            guard: mem::ManuallyDrop::new(guard),
+            _lock: lock,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<K, T, S, A> Drop for TypeReadGuard<'_, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn drop(&mut self) {
+        // SAFETY: The lock guard is dropped exactly once, here, and is never accessed again. The
+        // lock it borrows is still alive, because `self._lock` is only released after this method
+        // returns.
+        unsafe { mem::ManuallyDrop::drop(&mut self.guard) };
+    }
+}
+
+impl<K, T, S, A> ops::Deref for TypeReadGuard<'_, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Target = HomogeneousHashMap<K, T, S, A>;
+
+    fn deref(&self) -> &Self::Target {
+        HomogeneousHashMap::from_inner_ref(self.guard.0.as_proj::<Key<K, T>, T, S, A>())
+    }
+}
+
+impl<K, T, S, A> fmt::Debug for TypeReadGuard<'_, K, T, S, A>
+where
+    K: any::Any + fmt::Debug,
+    T: any::Any + fmt::Debug,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
+
+/// A guard granting exclusive write access to the values of one type in a concurrent
+/// heterogeneous hash map.
+///
+/// A write guard mutably dereferences to the [`HomogeneousHashMap`] storing the values of the
+/// type `T`. The write lock of the type is released when the guard is dropped. Write guards are
+/// returned by [`ConcurrentHeterogeneousHashMap::write`].
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{ConcurrentHeterogeneousHashMap, Key};
+/// #
+/// let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(3_usize), 4_i32);
+///
+/// for value in het_map.write::<i32>().unwrap().values_mut() {
+///     *value *= 10;
+/// }
+///
+/// assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), Some(20_i32));
+/// assert_eq!(het_map.get_cloned::<i32, _>(&3_usize), Some(40_i32));
+/// ```
+pub struct TypeWriteGuard<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    // The guard borrows the lock kept alive by `_lock`, so it must be dropped first. The `Drop`
+    // implementation below drops it explicitly, independently of the field order.
+    guard: mem::ManuallyDrop<sync::RwLockWriteGuard<'a, TypeCell>>,
+    _lock: TypeLock,
+    _marker: marker::PhantomData<(K, T, S, A)>,
+}
+
+impl<'a, K, T, S, A> TypeWriteGuard<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Acquires the write lock of the storage of the type `T`.
+    fn new(lock: TypeLock) -> Self {
+        // SAFETY: The `RwLock` lives in the heap allocation of the `Arc`, and does not move when
+        // `lock` is moved into the returned guard. `Drop for TypeWriteGuard` releases the write
+        // lock before `lock`, so the `RwLock` outlives the lock guard borrowing it. `Deref` and
+        // `DerefMut` only reborrow the storage for the lifetime of the guard, never for `'a`.
+        let rw_lock: &'a sync::RwLock<TypeCell> = unsafe { &*Arc::as_ptr(&lock) };
+        let guard = rw_lock.write().unwrap_or_else(sync::PoisonError::into_inner);
+
+        Self {
+            guard: mem::ManuallyDrop::new(guard),
+            _lock: lock,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<K, T, S, A> Drop for TypeWriteGuard<'_, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn drop(&mut self) {
+        // SAFETY: The lock guard is dropped exactly once, here, and is never accessed again. The
+        // lock it borrows is still alive, because `self._lock` is only released after this method
+        // returns.
+        unsafe { mem::ManuallyDrop::drop(&mut self.guard) };
+    }
+}
+
+impl<K, T, S, A> ops::Deref for TypeWriteGuard<'_, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Target = HomogeneousHashMap<K, T, S, A>;
+
+    fn deref(&self) -> &Self::Target {
+        HomogeneousHashMap::from_inner_ref(self.guard.0.as_proj::<Key<K, T>, T, S, A>())
+    }
+}
+
+impl<K, T, S, A> ops::DerefMut for TypeWriteGuard<'_, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        HomogeneousHashMap::from_inner_ref_mut(self.guard.0.as_proj_mut::<Key<K, T>, T, S, A>())
+    }
+}
+
+impl<K, T, S, A> fmt::Debug for TypeWriteGuard<'_, K, T, S, A>
+where
+    K: any::Any + fmt::Debug,
+    T: any::Any + fmt::Debug,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
@@ -3,6 +3,12 @@ use crate::key::Key;
 use core::any;
 use core::fmt;
 
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
 /// A view into a single entry in a [`HomogeneousHashMap`] or a [`HeterogeneousHashMap`], which may
 /// be occupied or vacant.
 ///
@@ -74,21 +80,23 @@ use core::fmt;
 /// }
 /// assert_eq!(map.entry(Key::new("garply")).key(), &Key::new("garply"));
 /// ```
-pub enum Entry<'a, K, T>
+pub enum Entry<'a, K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// An occupied entry.
-    Occupied(OccupiedEntry<'a, K, T>),
+    Occupied(OccupiedEntry<'a, K, T, A>),
     /// A vacant entry.
-    Vacant(VacantEntry<'a, K, T>),
+    Vacant(VacantEntry<'a, K, T, A>),
 }
 
-impl<'a, K, T> Entry<'a, K, T>
+impl<'a, K, T, A> Entry<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Sets the value of the entry (after inserting the entry if it is vacant), and returning an
     /// [`opaque::index_map::map::OccupiedEntry`].
@@ -137,7 +145,7 @@ where
     /// }
     /// assert_eq!(het_map.len::<i32>(), Some(1));
     /// ```
-    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, K, T> {
+    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, K, T, A> {
         match self {
             Entry::Occupied(mut entry) => {
                 entry.insert(value);
@@ -525,10 +533,11 @@ where
     }
 }
 
-impl<K, T> fmt::Debug for Entry<'_, K, T>
+impl<K, T, A> fmt::Debug for Entry<'_, K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut tuple = formatter.debug_tuple("Entry");
@@ -542,22 +551,24 @@ where
 
 /// A view into an occupied entry in a [`HomogeneousHashMap`] or [`HeterogeneousHashMap`]. It is
 /// part of the [`Entry`] sum type.
-pub struct OccupiedEntry<'a, K, T>
+pub struct OccupiedEntry<'a, K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    inner: opaque::index_map::map::OccupiedEntry<'a, Key<K, T>, T>,
+    inner: opaque::index_map::map::OccupiedEntry<'a, Key<K, T>, T, A>,
 }
 
-impl<'a, K, T> OccupiedEntry<'a, K, T>
+impl<'a, K, T, A> OccupiedEntry<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new occupied entry.
     #[inline]
-    pub(crate) const fn new(inner: opaque::index_map::map::OccupiedEntry<'a, Key<K, T>, T>) -> Self {
+    pub(crate) const fn new(inner: opaque::index_map::map::OccupiedEntry<'a, Key<K, T>, T, A>) -> Self {
         Self { inner }
     }
 
@@ -906,6 +917,12 @@ where
 
     /// Removes the occupied entry from the hash map, and returns the value of the entry.
     ///
+    /// This method is equivalent to [`swap_remove`], so it does not preserve the storage order of
+    /// the remaining entries. Use [`shift_remove`] to preserve the storage order instead.
+    ///
+    /// [`swap_remove`]: OccupiedEntry::swap_remove
+    /// [`shift_remove`]: OccupiedEntry::shift_remove
+    ///
     /// # Examples
     ///
     /// Using this method with a homogeneous hash map.
@@ -984,6 +1001,13 @@ where
 
     /// Removes the occupied entry from the hash map, and returns the key-value pair for the entry.
     ///
+    /// This method is equivalent to [`swap_remove_entry`], so it does not preserve the storage
+    /// order of the remaining entries. Use [`shift_remove_entry`] to preserve the storage order
+    /// instead.
+    ///
+    /// [`swap_remove_entry`]: OccupiedEntry::swap_remove_entry
+    /// [`shift_remove_entry`]: OccupiedEntry::shift_remove_entry
+    ///
     /// # Examples
     ///
     /// Using this method with a homogeneous hash map.
@@ -1059,12 +1083,317 @@ where
     pub fn remove_entry(self) -> (Key<K, T>, T) {
         self.inner.swap_remove_entry()
     }
+
+    /// Removes the occupied entry from the hash map by swapping it with the last entry, and
+    /// returns the value of the entry.
+    ///
+    /// This method moves the last entry of the map into the vacated position, so it does not
+    /// preserve the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// Using this method with a homogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(map.entry(Key::new("bar")));
+    /// let result = occupied_entry.swap_remove();
+    ///
+    /// assert_eq!(result, 2_i32);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    ///
+    /// Using this method with a heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(het_map.entry_or_insert_type::<i32>(Key::new("bar")));
+    /// let result = occupied_entry.swap_remove();
+    ///
+    /// assert_eq!(result, 2_i32);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn swap_remove(self) -> T {
+        self.inner.swap_remove()
+    }
+
+    /// Removes the occupied entry from the hash map by swapping it with the last entry, and
+    /// returns the key-value pair for the entry.
+    ///
+    /// This method moves the last entry of the map into the vacated position, so it does not
+    /// preserve the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// Using this method with a homogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(map.entry(Key::new("bar")));
+    /// let result = occupied_entry.swap_remove_entry();
+    ///
+    /// assert_eq!(result, (Key::new("bar"), 2_i32));
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    ///
+    /// Using this method with a heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(het_map.entry_or_insert_type::<i32>(Key::new("bar")));
+    /// let result = occupied_entry.swap_remove_entry();
+    ///
+    /// assert_eq!(result, (Key::new("bar"), 2_i32));
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn swap_remove_entry(self) -> (Key<K, T>, T) {
+        self.inner.swap_remove_entry()
+    }
+
+    /// Removes the occupied entry from the hash map by shifting every following entry down by
+    /// one position, and returns the value of the entry.
+    ///
+    /// This method preserves the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// hash map.
+    ///
+    /// # Examples
+    ///
+    /// Using this method with a homogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(map.entry(Key::new("bar")));
+    /// let result = occupied_entry.shift_remove();
+    ///
+    /// assert_eq!(result, 2_i32);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    ///
+    /// Using this method with a heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(het_map.entry_or_insert_type::<i32>(Key::new("bar")));
+    /// let result = occupied_entry.shift_remove();
+    ///
+    /// assert_eq!(result, 2_i32);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    pub fn shift_remove(self) -> T {
+        self.inner.shift_remove()
+    }
+
+    /// Removes the occupied entry from the hash map by shifting every following entry down by
+    /// one position, and returns the key-value pair for the entry.
+    ///
+    /// This method preserves the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// hash map.
+    ///
+    /// # Examples
+    ///
+    /// Using this method with a homogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(map.entry(Key::new("bar")));
+    /// let result = occupied_entry.shift_remove_entry();
+    ///
+    /// assert_eq!(result, (Key::new("bar"), 2_i32));
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    ///
+    /// Using this method with a heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Entry, Key, OccupiedEntry};
+    /// # use core::any::Any;
+    /// #
+    /// fn into_occupied<K: Any, T: Any>(entry: Entry<'_, K, T>) -> OccupiedEntry<'_, K, T> {
+    ///     match entry {
+    ///         Entry::Occupied(occupied_entry) => occupied_entry,
+    ///         _ => panic!("This method only destructures occupied entries")
+    ///     }
+    /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let occupied_entry = into_occupied(het_map.entry_or_insert_type::<i32>(Key::new("bar")));
+    /// let result = occupied_entry.shift_remove_entry();
+    ///
+    /// assert_eq!(result, (Key::new("bar"), 2_i32));
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    pub fn shift_remove_entry(self) -> (Key<K, T>, T) {
+        self.inner.shift_remove_entry()
+    }
 }
 
-impl<K, T> fmt::Debug for OccupiedEntry<'_, K, T>
+impl<K, T, A> fmt::Debug for OccupiedEntry<'_, K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter
@@ -1077,22 +1406,24 @@ where
 
 /// A view into a vacant entry in a [`HomogeneousHashMap`] or [`HeterogeneousHashMap`]. It is
 /// part of the [`Entry`] sum type.
-pub struct VacantEntry<'a, K, T>
+pub struct VacantEntry<'a, K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    inner: opaque::index_map::map::VacantEntry<'a, Key<K, T>, T>,
+    inner: opaque::index_map::map::VacantEntry<'a, Key<K, T>, T, A>,
 }
 
-impl<'a, K, T> VacantEntry<'a, K, T>
+impl<'a, K, T, A> VacantEntry<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new vacant entry.
     #[inline]
-    pub(crate) const fn new(inner: opaque::index_map::map::VacantEntry<'a, Key<K, T>, T>) -> Self {
+    pub(crate) const fn new(inner: opaque::index_map::map::VacantEntry<'a, Key<K, T>, T, A>) -> Self {
         Self { inner }
     }
 
@@ -1330,15 +1661,16 @@ where
     /// assert_eq!(occupied_entry.key(), &Key::new("corge"));
     /// assert_eq!(occupied_entry.get(), &i32::MAX);
     /// ```
-    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, K, T> {
+    pub fn insert_entry(self, value: T) -> OccupiedEntry<'a, K, T, A> {
         OccupiedEntry::new(self.inner.insert_entry(value))
     }
 }
 
-impl<K, T> fmt::Debug for VacantEntry<'_, K, T>
+impl<K, T, A> fmt::Debug for VacantEntry<'_, K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.debug_tuple("VacantEntry").field(self.key()).finish()
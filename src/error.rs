@@ -1,7 +1,11 @@
+use crate::metadata::TypeMetadata;
+
 use alloc_crate::alloc;
-use core::error;
 use core::fmt;
 
+#[cfg(feature = "std")]
+use core::error;
+
 /// The error type for `try_reserve` methods.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct TryReserveError {
@@ -15,6 +19,39 @@ impl TryReserveError {
     pub fn kind(&self) -> TryReserveErrorKind {
         self.kind.clone()
     }
+
+    /// Converts an allocation error from the underlying type-erased index maps.
+    pub(crate) fn from_opaque(error: opaque::error::TryReserveError) -> Self {
+        Self::from(match error.kind() {
+            opaque::error::TryReserveErrorKind::CapacityOverflow => TryReserveErrorKind::CapacityOverflow,
+            opaque::error::TryReserveErrorKind::AllocError { layout } => TryReserveErrorKind::AllocError { layout },
+        })
+    }
+
+    /// Constructs the allocation error for a failed attempt to reserve capacity for `additional`
+    /// more elements of type `T` in a vector of length `len`.
+    ///
+    /// The reason for the failure of a vector reservation is not exposed on stable Rust, so the
+    /// error reports the layout of the requested allocation, or a capacity overflow if that
+    /// layout cannot be computed.
+    pub(crate) fn from_vec<T>(len: usize, additional: usize) -> Self {
+        let layout = len
+            .checked_add(additional)
+            .and_then(|capacity| alloc::Layout::array::<T>(capacity).ok());
+
+        Self::from(match layout {
+            Some(layout) => TryReserveErrorKind::AllocError { layout },
+            None => TryReserveErrorKind::CapacityOverflow,
+        })
+    }
+
+    /// Converts an allocation error from the underlying type tables.
+    pub(crate) fn from_hashbrown(error: hashbrown::TryReserveError) -> Self {
+        Self::from(match error {
+            hashbrown::TryReserveError::CapacityOverflow => TryReserveErrorKind::CapacityOverflow,
+            hashbrown::TryReserveError::AllocError { layout } => TryReserveErrorKind::AllocError { layout },
+        })
+    }
 }
 
 /// Details of the allocation that caused a `TryReserveError`
@@ -59,3 +96,91 @@ impl fmt::Display for TryReserveError {
 
 #[cfg(feature = "std")]
 impl error::Error for TryReserveError {}
+
+/// The error type for `try_clone` methods.
+///
+/// This error occurs when a heterogeneous hash map stores a type that was not registered with a
+/// clone operation, so the values of that type cannot be cloned.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TryCloneError {
+    metadata: TypeMetadata,
+}
+
+impl TryCloneError {
+    /// Constructs a new clone error for the type with the given metadata.
+    #[inline]
+    pub(crate) const fn new(metadata: TypeMetadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Returns the metadata of the type that could not be cloned.
+    #[inline]
+    #[must_use]
+    pub const fn metadata(&self) -> TypeMetadata {
+        self.metadata
+    }
+
+    /// Returns the name of the type that could not be cloned.
+    #[inline]
+    #[must_use]
+    pub const fn type_name(&self) -> &str {
+        self.metadata.type_name()
+    }
+}
+
+impl fmt::Display for TryCloneError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "cloning failed because the type `{}` was not registered with a clone operation",
+            self.metadata.type_name()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for TryCloneError {}
+
+/// The error type for `try_eq` methods.
+///
+/// This error occurs when a heterogeneous hash map stores a type that was not registered with an
+/// equality operation, so the values of that type cannot be compared.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TryEqError {
+    metadata: TypeMetadata,
+}
+
+impl TryEqError {
+    /// Constructs a new comparison error for the type with the given metadata.
+    #[inline]
+    pub(crate) const fn new(metadata: TypeMetadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Returns the metadata of the type that could not be compared.
+    #[inline]
+    #[must_use]
+    pub const fn metadata(&self) -> TypeMetadata {
+        self.metadata
+    }
+
+    /// Returns the name of the type that could not be compared.
+    #[inline]
+    #[must_use]
+    pub const fn type_name(&self) -> &str {
+        self.metadata.type_name()
+    }
+}
+
+impl fmt::Display for TryEqError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "comparison failed because the type `{}` is not comparable",
+            self.metadata.type_name()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for TryEqError {}
@@ -1,4 +1,9 @@
 use crate::entry::Entry;
+use crate::error::{
+    TryCloneError,
+    TryEqError,
+    TryReserveError,
+};
 use crate::homogeneous_hash_map::HomogeneousHashMap;
 use crate::iterator::{
     Drain,
@@ -12,12 +17,31 @@ use crate::iterator::{
 };
 use crate::key::Key;
 use crate::metadata::TypeMetadata;
+use crate::signature_index::TypeSignatureIndex;
+use crate::slice::Slice;
+use crate::storage::{
+    TypeStorage,
+    TypeStorageMut,
+    TypeVisitor,
+    TypeVisitorMut,
+};
+use crate::trait_view::{
+    self,
+    TraitView,
+    TraitViews,
+};
+use crate::type_tuple::TypeTuple;
+use crate::vtable::TypeVTable;
 
+use alloc_crate::boxed::Box;
+use alloc_crate::sync::Arc;
 use alloc_crate::vec::Vec;
 use core::any;
 use core::borrow::Borrow;
+use core::cmp;
 use core::fmt;
 use core::marker;
+use core::ops;
 
 #[cfg(feature = "std")]
 use std::hash;
@@ -68,6 +92,13 @@ use hashbrown::hash_map;
 /// builder type (typically the default one from the standard library), and one backing memory
 /// allocator (typically the global one).
 ///
+/// The memory allocator of the map is only used for the storage of the values of each type. The
+/// bookkeeping tables of the map itself, such as the table from each [`TypeId`] to the storage
+/// for that type, the type metadata, and the vtables of the registered types, are always
+/// allocated from the global allocator.
+///
+/// [`TypeId`]: core::any::TypeId
+///
 /// # Examples
 ///
 /// ```
@@ -263,46 +294,68 @@ use hashbrown::hash_map;
 /// assert!(het_map.is_empty_types());
 /// ```
 #[cfg(feature = "std")]
-pub struct HeterogeneousHashMap<K, S = hash::RandomState>
+pub struct HeterogeneousHashMap<K, S = hash::RandomState, A = alloc::Global>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     map: hash_map::HashMap<any::TypeId, opaque::index_map::TypeErasedIndexMap, S>,
     registry: hash_map::HashMap<any::TypeId, TypeMetadata, S>,
+    vtables: hash_map::HashMap<any::TypeId, TypeVTable, S>,
+    views: TraitViews<S>,
+    signatures: Option<TypeSignatureIndex<K, S, A>>,
     build_hasher: S,
+    alloc: A,
     _marker: marker::PhantomData<K>,
 }
 
 #[cfg(not(feature = "std"))]
-pub struct HeterogeneousHashMap<K, S>
+pub struct HeterogeneousHashMap<K, S, A = alloc::Global>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     map: hash_map::HashMap<any::TypeId, opaque::index_map::TypeErasedIndexMap, S>,
     registry: hash_map::HashMap<any::TypeId, TypeMetadata, S>,
+    vtables: hash_map::HashMap<any::TypeId, TypeVTable, S>,
+    views: TraitViews<S>,
+    signatures: Option<TypeSignatureIndex<K, S, A>>,
     build_hasher: S,
+    alloc: A,
     _marker: marker::PhantomData<K>,
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
-    /// Constructs a new empty heterogeneous hash map with the custom hash builder.
+    /// Constructs a new empty heterogeneous hash map with the custom hash builder and the custom
+    /// memory allocator.
+    ///
+    /// The storage for the values of every type inserted into the map is allocated from `alloc`.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
     /// # use heterogeneous_hash_map::HeterogeneousHashMap;
     /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
     /// #
-    /// let mut het_map: HeterogeneousHashMap<usize, RandomState> = HeterogeneousHashMap::with_hasher(RandomState::new());
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState, Global> = HeterogeneousHashMap::with_hasher_in(
+    ///     RandomState::new(),
+    ///     Global,
+    /// );
     ///
     /// assert!(het_map.is_empty_types());
     /// assert_eq!(het_map.len_types(), 0);
@@ -315,25 +368,40 @@ where
     /// assert!(het_map.capacity_types() > 0);
     /// ```
     #[must_use]
-    pub fn with_hasher(build_hasher: S) -> Self {
+    pub fn with_hasher_in(build_hasher: S, alloc: A) -> Self {
         Self {
             map: hash_map::HashMap::with_hasher(build_hasher.clone()),
             registry: hash_map::HashMap::with_hasher(build_hasher.clone()),
+            vtables: hash_map::HashMap::with_hasher(build_hasher.clone()),
+            views: hash_map::HashMap::with_hasher(build_hasher.clone()),
+            signatures: None,
             build_hasher,
+            alloc,
             _marker: marker::PhantomData,
         }
     }
 
-    /// Constructs a new empty heterogeneous hash map with at least the specified capacity and
-    /// using the custom hash builder.
+    /// Constructs a new empty heterogeneous hash map with at least the specified capacity, using
+    /// the custom hash builder and the custom memory allocator.
+    ///
+    /// The storage for the values of every type inserted into the map is allocated from `alloc`.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
     /// # use heterogeneous_hash_map::HeterogeneousHashMap;
     /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
     /// #
-    /// let mut het_map: HeterogeneousHashMap<usize, RandomState> = HeterogeneousHashMap::with_capacity_and_hasher(3, RandomState::new());
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState, Global> = HeterogeneousHashMap::with_capacity_and_hasher_in(
+    ///     3,
+    ///     RandomState::new(),
+    ///     Global,
+    /// );
     ///
     /// assert!(het_map.is_empty_types());
     /// assert_eq!(het_map.len_types(), 0);
@@ -349,18 +417,157 @@ where
     /// assert_eq!(het_map.capacity_types(), old_capacity);
     /// ```
     #[must_use]
-    pub fn with_capacity_and_hasher(capacity: usize, build_hasher: S) -> Self {
+    pub fn with_capacity_and_hasher_in(capacity: usize, build_hasher: S, alloc: A) -> Self {
         Self {
             map: hash_map::HashMap::with_capacity_and_hasher(capacity, build_hasher.clone()),
             registry: hash_map::HashMap::with_capacity_and_hasher(capacity, build_hasher.clone()),
+            vtables: hash_map::HashMap::with_capacity_and_hasher(capacity, build_hasher.clone()),
+            views: hash_map::HashMap::with_hasher(build_hasher.clone()),
+            signatures: None,
             build_hasher,
+            alloc,
             _marker: marker::PhantomData,
         }
     }
 }
 
+impl<K, S> HeterogeneousHashMap<K, S, alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+{
+    /// Constructs a new empty heterogeneous hash map with the custom hash builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState> = HeterogeneousHashMap::with_hasher(RandomState::new());
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 0);
+    /// assert_eq!(het_map.capacity_types(), 0);
+    ///
+    /// het_map.insert_type::<i8>();
+    ///
+    /// assert!(!het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 1);
+    /// assert!(het_map.capacity_types() > 0);
+    /// ```
+    #[must_use]
+    pub fn with_hasher(build_hasher: S) -> Self {
+        Self::with_hasher_in(build_hasher, alloc::Global)
+    }
+
+    /// Constructs a new empty heterogeneous hash map with at least the specified capacity and
+    /// using the custom hash builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState> = HeterogeneousHashMap::with_capacity_and_hasher(3, RandomState::new());
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 0);
+    /// assert!(het_map.capacity_types() >= 3);
+    ///
+    /// let old_capacity = het_map.capacity_types();
+    /// het_map.insert_type::<i8>();
+    /// het_map.insert_type::<i16>();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(!het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 3);
+    /// assert_eq!(het_map.capacity_types(), old_capacity);
+    /// ```
+    #[must_use]
+    pub fn with_capacity_and_hasher(capacity: usize, build_hasher: S) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, build_hasher, alloc::Global)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, A> HeterogeneousHashMap<K, hash::RandomState, A>
+where
+    K: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new empty heterogeneous hash map with the custom memory allocator.
+    ///
+    /// The storage for the values of every type inserted into the map is allocated from `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState, Global> = HeterogeneousHashMap::new_in(Global);
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 0);
+    /// assert_eq!(het_map.capacity_types(), 0);
+    ///
+    /// het_map.insert_type::<i8>();
+    ///
+    /// assert!(!het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 1);
+    /// assert!(het_map.capacity_types() > 0);
+    /// ```
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_hasher_in(hash::RandomState::new(), alloc)
+    }
+
+    /// Constructs a new empty heterogeneous hash map with a minimum type capacity of `capacity`,
+    /// using the custom memory allocator.
+    ///
+    /// The storage for the values of every type inserted into the map is allocated from `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize, RandomState, Global> = HeterogeneousHashMap::with_capacity_in(3, Global);
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// assert_eq!(het_map.len_types(), 0);
+    /// assert!(het_map.capacity_types() >= 3);
+    ///
+    /// let old_capacity = het_map.capacity_types();
+    /// het_map.insert_type::<i8>();
+    /// het_map.insert_type::<i16>();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert_eq!(het_map.len_types(), 3);
+    /// assert_eq!(het_map.capacity_types(), old_capacity);
+    /// ```
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, hash::RandomState::new(), alloc)
+    }
+}
+
 #[cfg(feature = "std")]
-impl<K> HeterogeneousHashMap<K, hash::RandomState>
+impl<K> HeterogeneousHashMap<K, hash::RandomState, alloc::Global>
 where
     K: any::Any,
 {
@@ -417,17 +624,27 @@ where
     }
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Inserts a new type into the heterogeneous hash map.
     ///
     /// This method registers the type in the heterogeneous hash map, but does not allocate memory
     /// for values of that type. A subsequent value insertion will trigger a memory allocation to
-    /// occur.
+    /// occur. Registering the type also captures the operations that access the values of that
+    /// type by [`TypeId`] at runtime, such as [`get_dyn`] and [`remove_dyn`]. Registering a type
+    /// does not require a hashable key type, so those operations only hash their keys once a
+    /// method that does, such as [`insert`] or [`extend`], has inserted values of the type.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`get_dyn`]: HeterogeneousHashMap::get_dyn
+    /// [`remove_dyn`]: HeterogeneousHashMap::remove_dyn
+    /// [`insert`]: HeterogeneousHashMap::insert
+    /// [`extend`]: HeterogeneousHashMap::extend
     ///
     /// This method returns `true` if the type `T` does not exist in the map. This method returns
     /// `false` if the type `T` already exists in the map.
@@ -462,13 +679,11 @@ where
             return false;
         }
 
-        let type_metadata = TypeMetadata::of::<T>();
-        let map = opaque::index_map::TypeErasedIndexMap::new::<Key<K, T>, T>();
-
-        self.registry.insert(type_id, type_metadata);
-        self.map.insert(type_id, map);
-
-        debug_assert_eq!(self.registry.len(), self.map.len());
+        let proj_map = opaque::index_map::TypeProjectedIndexMap::<Key<K, T>, T, S, A>::with_hasher_in(
+            self.build_hasher.clone(),
+            self.alloc.clone(),
+        );
+        self.insert_type_map::<T>(opaque::index_map::TypeErasedIndexMap::from_proj(proj_map));
 
         true
     }
@@ -515,147 +730,245 @@ where
             return false;
         }
 
-        let type_metadata = TypeMetadata::of::<T>();
-        let map = opaque::index_map::TypeErasedIndexMap::with_capacity::<Key<K, T>, T>(capacity);
-
-        self.registry.insert(type_id, type_metadata);
-        self.map.insert(type_id, map);
-
-        debug_assert_eq!(self.registry.len(), self.map.len());
+        let proj_map = opaque::index_map::TypeProjectedIndexMap::<Key<K, T>, T, S, A>::with_capacity_and_hasher_in(
+            capacity,
+            self.build_hasher.clone(),
+            self.alloc.clone(),
+        );
+        self.insert_type_map::<T>(opaque::index_map::TypeErasedIndexMap::from_proj(proj_map));
 
         true
     }
 
-    /// Determines whether a heterogeneous hash map contains the given type.
+    /// Tries to insert a new type into the heterogeneous hash map with a given minimum type
+    /// capacity.
     ///
-    /// This method returns `true` if the type `T` exists in the map. This method returns `false`
-    /// if the type `T` does not exist in the map.
+    /// This method is the fallible counterpart of [`insert_type_with_capacity`]. It registers the
+    /// type in the heterogeneous hash map, and allocates memory for at least `capacity` entries
+    /// for values of that type, reporting an allocation failure instead of aborting.
+    ///
+    /// This method returns `Ok(true)` if the type `T` does not exist in the map. This method
+    /// returns `Ok(false)` if the type `T` already exists in the map, in which case the map is
+    /// left unchanged.
+    ///
+    /// [`insert_type_with_capacity`]: HeterogeneousHashMap::insert_type_with_capacity
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure. If an error occurs, the
+    /// type `T` is not inserted into the map, and the map is left unchanged.
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in **O(1)** time.
+    /// This method runs in amortized **O(1 + m)** time, where `m` is an affine function of the
+    /// value capacity of the given type provided to the method.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
     /// #
     /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
     ///
-    /// assert!(!het_map.contains_type::<i32>());
-    /// assert!(!het_map.contains_type::<u64>());
-    ///
-    /// het_map.insert_type::<i32>();
-    ///
-    /// assert!(het_map.contains_type::<i32>());
-    /// assert!(!het_map.contains_type::<u64>());
-    ///
-    /// het_map.insert_type::<u64>();
-    ///
-    /// assert!(het_map.contains_type::<i32>());
-    /// assert!(het_map.contains_type::<u64>());
-    ///
-    /// het_map.remove_type::<i32>();
-    ///
-    /// assert!(!het_map.contains_type::<i32>());
-    /// assert!(het_map.contains_type::<u64>());
-    ///
-    /// het_map.remove_type::<u64>();
+    /// assert_eq!(het_map.try_insert_type_with_capacity::<i32>(3), Ok(true));
+    /// assert_eq!(het_map.try_insert_type_with_capacity::<i32>(3), Ok(false));
+    /// assert!(het_map.capacity::<i32>() >= Some(3));
     ///
-    /// assert!(!het_map.contains_type::<i32>());
+    /// // The capacity overflows, so the type is not inserted.
+    /// assert!(het_map.try_insert_type_with_capacity::<u64>(usize::MAX).is_err());
     /// assert!(!het_map.contains_type::<u64>());
     /// ```
-    pub fn contains_type<T>(&self) -> bool
+    pub fn try_insert_type_with_capacity<T>(&mut self, capacity: usize) -> Result<bool, TryReserveError>
     where
         T: any::Any,
     {
         let type_id = any::TypeId::of::<T>();
+        if self.map.contains_key(&type_id) {
+            return Ok(false);
+        }
 
-        self.map.contains_key(&type_id)
+        let mut proj_map = opaque::index_map::TypeProjectedIndexMap::<Key<K, T>, T, S, A>::with_hasher_in(
+            self.build_hasher.clone(),
+            self.alloc.clone(),
+        );
+        proj_map.try_reserve(capacity).map_err(TryReserveError::from_opaque)?;
+        self.try_reserve_type_tables(1)?;
+        if let Some(index) = self.signatures.as_mut() {
+            index.try_reserve_type()?;
+        }
+        self.insert_type_map::<T>(opaque::index_map::TypeErasedIndexMap::from_proj(proj_map));
+
+        Ok(true)
     }
 
-    /// Returns a reference to the homogeneous hash map containing all values of a given type
-    /// from the heterogeneous hash map.
+    /// Registers the type `T` in the heterogeneous hash map, with the given storage for its
+    /// values.
     ///
-    /// # Complexity Characteristics
+    /// This method records the metadata and the vtable of the type, stores the map, and assigns
+    /// the type a bit in the signature index, if it exists. The caller must ensure that the type
+    /// `T` does not exist in the map.
+    fn insert_type_map<T>(&mut self, map: opaque::index_map::TypeErasedIndexMap)
+    where
+        T: any::Any,
+    {
+        let type_id = any::TypeId::of::<T>();
+        self.registry.insert(type_id, TypeMetadata::of::<T>());
+        self.vtables.insert(type_id, TypeVTable::new::<K, T, S, A>());
+        self.map.insert(type_id, map);
+        if let Some(index) = self.signatures.as_mut() {
+            index.insert_type(type_id);
+        }
+
+        debug_assert_eq!(self.registry.len(), self.map.len());
+        debug_assert_eq!(self.vtables.len(), self.map.len());
+    }
+
+    /// Tries to reserve capacity for at least `additional` more types in each of the type tables
+    /// of the heterogeneous hash map.
+    fn try_reserve_type_tables(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional).map_err(TryReserveError::from_hashbrown)?;
+        self.registry
+            .try_reserve(additional)
+            .map_err(TryReserveError::from_hashbrown)?;
+        self.vtables
+            .try_reserve(additional)
+            .map_err(TryReserveError::from_hashbrown)?;
+
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type, inserting the type `T` if it does not exist in the heterogeneous hash map, and
+    /// reporting an allocation failure instead of aborting.
+    fn try_get_or_insert_map_mut<T>(&mut self) -> Result<&mut HomogeneousHashMap<K, T, S, A>, TryReserveError>
+    where
+        T: any::Any,
+    {
+        self.try_insert_type_with_capacity::<T>(0)?;
+
+        Ok(self.get_map_mut_untracked::<T>().unwrap())
+    }
+
+    /// Inserts a new cloneable type into the heterogeneous hash map.
     ///
-    /// This method runs in **O(1)** time.
+    /// This method registers the type in the heterogeneous hash map in the same way as
+    /// [`insert_type`], and additionally records a clone operation for the values of type `T`,
+    /// so that [`try_clone`] and [`Clone::clone`] can clone the entries of type `T`. If the type
+    /// `T` already exists in the map, this method records the clone operation for the existing
+    /// type and leaves its values untouched.
     ///
-    /// # Panics
+    /// This method returns `true` if the type `T` does not exist in the map. This method returns
+    /// `false` if the type `T` already exists in the map.
     ///
-    /// This method panics if the given type `T` does not exist in the map.
+    /// [`insert_type`]: HeterogeneousHashMap::insert_type
+    /// [`try_clone`]: HeterogeneousHashMap::try_clone
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1)** time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
     /// #
-    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
+    /// let mut het_map = HeterogeneousHashMap::new();
     ///
-    /// let map = het_map.get_map_unchecked::<i32>();
+    /// assert!(het_map.insert_cloneable_type::<i32>());
+    /// assert!(!het_map.insert_cloneable_type::<i32>());
     ///
-    /// assert_eq!(map.len(), 0);
+    /// het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+    ///
+    /// let cloned_het_map = het_map.try_clone().unwrap();
+    ///
+    /// assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
     /// ```
-    #[track_caller]
-    pub fn get_map_unchecked<T>(&self) -> &HomogeneousHashMap<K, T, S>
+    pub fn insert_cloneable_type<T>(&mut self) -> bool
     where
-        T: any::Any,
+        K: Clone,
+        T: any::Any + Clone,
     {
+        let inserted = self.insert_type::<T>();
+
         let type_id = any::TypeId::of::<T>();
-        let map = self.map[&type_id].as_proj::<Key<K, T>, T, S, alloc::Global>();
+        self.vtables
+            .get_mut(&type_id)
+            .expect("Every stored type must have a registered vtable")
+            .set_clone::<K, T, S, A>();
 
-        HomogeneousHashMap::from_inner_ref(map)
+        inserted
     }
 
-    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
-    /// type from the heterogeneous hash map.
+    /// Inserts a new comparable type into the heterogeneous hash map.
     ///
-    /// # Complexity Characteristics
+    /// This method registers the type in the heterogeneous hash map in the same way as
+    /// [`insert_type`], and additionally records an equality operation for the values of type
+    /// `T`, so that [`try_eq`] and [`PartialEq::eq`] can compare the entries of type `T`. If the
+    /// type `T` already exists in the map, this method records the equality operation for the
+    /// existing type and leaves its values untouched.
     ///
-    /// This method runs in **O(1)** time.
+    /// This method returns `true` if the type `T` does not exist in the map. This method returns
+    /// `false` if the type `T` already exists in the map.
     ///
-    /// # Panics
+    /// [`insert_type`]: HeterogeneousHashMap::insert_type
+    /// [`try_eq`]: HeterogeneousHashMap::try_eq
     ///
-    /// This method panics if the given type `T` does not exist in the map.
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1)** time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
     /// #
-    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
+    /// let mut het_map1 = HeterogeneousHashMap::new();
+    /// let mut het_map2 = HeterogeneousHashMap::new();
     ///
-    /// let map = het_map.get_map_mut_unchecked::<i32>();
+    /// assert!(het_map1.insert_comparable_type::<i32>());
+    /// assert!(!het_map1.insert_comparable_type::<i32>());
+    /// assert!(het_map2.insert_comparable_type::<i32>());
     ///
-    /// assert_eq!(map.len(), 0);
+    /// het_map1.insert::<i32>(Key::new(0_usize), 1_i32);
+    /// het_map2.insert::<i32>(Key::new(0_usize), 1_i32);
+    ///
+    /// assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
     /// ```
-    #[track_caller]
-    pub fn get_map_mut_unchecked<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S>
+    pub fn insert_comparable_type<T>(&mut self) -> bool
     where
-        T: any::Any,
+        K: hash::Hash + Eq,
+        T: any::Any + PartialEq,
     {
+        let inserted = self.insert_type::<T>();
+
         let type_id = any::TypeId::of::<T>();
-        let map = self
-            .map
+        self.vtables
             .get_mut(&type_id)
-            .unwrap()
-            .as_proj_mut::<Key<K, T>, T, S, alloc::Global>();
+            .expect("Every stored type must have a registered vtable")
+            .set_eq::<K, T, S, A>();
 
-        HomogeneousHashMap::from_inner_ref_mut(map)
+        inserted
     }
 
-    /// Returns a reference to the homogeneous hash map containing all values of a given
-    /// type from the heterogeneous hash map.
+    /// Inserts a new debuggable type into the heterogeneous hash map.
     ///
-    /// If `T` exists in the heterogeneous hash map, this method returns `Some(&map)`, where
-    /// `map` is the homogeneous hash map containing all values of type `T`. If `T` does not exist
-    /// in the heterogeneous map, this method returns `None`.
+    /// This method registers the type in the heterogeneous hash map in the same way as
+    /// [`insert_type`], and additionally records a debug formatting operation for the values of
+    /// type `T`, so that the [`Debug`] output of the map prints every key and value of type `T`
+    /// instead of a summary of the type. If the type `T` already exists in the map, this method
+    /// records the debug formatting operation for the existing type and leaves its values
+    /// untouched.
+    ///
+    /// This method returns `true` if the type `T` does not exist in the map. This method returns
+    /// `false` if the type `T` already exists in the map.
+    ///
+    /// [`insert_type`]: HeterogeneousHashMap::insert_type
+    /// [`Debug`]: fmt::Debug
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in **O(1)** time.
+    /// This method runs in amortized **O(1)** time.
     ///
     /// # Examples
     ///
@@ -663,7 +976,296 @@ where
     /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(het_map.insert_debug_type::<i32>());
+    /// assert!(!het_map.insert_debug_type::<i32>());
+    ///
+    /// het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+    ///
+    /// let formatted = format!("{:?}", het_map);
+    ///
+    /// assert!(formatted.contains("values: {Key { id: 0 }: 1}"));
+    /// ```
+    pub fn insert_debug_type<T>(&mut self) -> bool
+    where
+        K: fmt::Debug,
+        T: any::Any + fmt::Debug,
+    {
+        let inserted = self.insert_type::<T>();
+
+        let type_id = any::TypeId::of::<T>();
+        self.vtables
+            .get_mut(&type_id)
+            .expect("Every stored type must have a registered vtable")
+            .set_debug::<K, T, S, A>();
+
+        inserted
+    }
+
+    /// Registers a view of the values of type `T` as the type `U`, inserting the type `T` into
+    /// the heterogeneous hash map if it does not exist.
+    ///
+    /// The type `U` is typically a trait object type implemented by `T`, such as
+    /// `dyn Display`, and the upcast is typically the unsizing coercion `|value| value`. Once
+    /// registered, the values of type `T` are included in [`iter_as`] and [`get_as`] for `U`,
+    /// alongside the values of every other type registered as `U`. This provides dynamic
+    /// dispatch over the values of many types without boxing each value. Registering a view
+    /// for a pair of types that already has one replaces the previous view. To also include the
+    /// values of type `T` in [`iter_as_mut`], use [`register_as_mut`] instead.
+    ///
+    /// This method returns `true` if the type `T` does not exist in the map. This method returns
+    /// `false` if the type `T` already exists in the map.
+    ///
+    /// [`iter_as`]: HeterogeneousHashMap::iter_as
+    /// [`iter_as_mut`]: HeterogeneousHashMap::iter_as_mut
+    /// [`get_as`]: HeterogeneousHashMap::get_as
+    /// [`register_as_mut`]: HeterogeneousHashMap::register_as_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::fmt::Display;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.register_as::<i32, dyn Display>(|value| value));
+    /// assert!(!het_map.register_as::<i32, dyn Display>(|value| value));
+    /// assert!(het_map.register_as::<String, dyn Display>(|value| value));
+    ///
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// let mut formatted: Vec<String> = het_map.get_as::<dyn Display>(&1_usize).map(|value| value.to_string()).collect();
+    /// formatted.sort();
+    ///
+    /// assert_eq!(formatted, ["2", "foo"]);
+    /// ```
+    pub fn register_as<T, U>(&mut self, upcast: fn(&T) -> &U) -> bool
+    where
+        T: any::Any,
+        U: ?Sized + 'static,
+    {
+        let inserted = self.insert_type::<T>();
+
+        let key = (any::TypeId::of::<T>(), any::TypeId::of::<U>());
+        self.views.insert(key, Arc::new(TraitView::new(upcast)));
+
+        inserted
+    }
+
+    /// Registers a view of the values of type `T` as the type `U`, with both shared and mutable
+    /// access, inserting the type `T` into the heterogeneous hash map if it does not exist.
+    ///
+    /// This method behaves like [`register_as`], and additionally includes the values of type
+    /// `T` in [`iter_as_mut`] for `U`.
+    ///
+    /// This method returns `true` if the type `T` does not exist in the map. This method returns
+    /// `false` if the type `T` already exists in the map.
+    ///
+    /// [`register_as`]: HeterogeneousHashMap::register_as
+    /// [`iter_as_mut`]: HeterogeneousHashMap::iter_as_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// trait Tick {
+    ///     fn tick(&mut self);
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Timer(u32);
+    ///
+    /// impl Tick for Timer {
+    ///     fn tick(&mut self) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.register_as_mut::<Timer, dyn Tick>(|value| value, |value| value));
+    ///
+    /// het_map.insert(Key::new(1_usize), Timer(0));
+    ///
+    /// for (_key, value) in het_map.iter_as_mut::<dyn Tick>() {
+    ///     value.tick();
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<Timer, _>(&1_usize), Some(&Timer(1)));
+    /// ```
+    pub fn register_as_mut<T, U>(&mut self, upcast: fn(&T) -> &U, upcast_mut: fn(&mut T) -> &mut U) -> bool
+    where
+        T: any::Any,
+        U: ?Sized + 'static,
+    {
+        let inserted = self.insert_type::<T>();
+
+        let key = (any::TypeId::of::<T>(), any::TypeId::of::<U>());
+        self.views.insert(key, Arc::new(TraitView::with_mut(upcast, upcast_mut)));
+
+        inserted
+    }
+
+    /// Determines whether a heterogeneous hash map contains the given type.
+    ///
+    /// This method returns `true` if the type `T` exists in the map. This method returns `false`
+    /// if the type `T` does not exist in the map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    ///
+    /// assert!(!het_map.contains_type::<i32>());
+    /// assert!(!het_map.contains_type::<u64>());
+    ///
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(het_map.contains_type::<i32>());
+    /// assert!(!het_map.contains_type::<u64>());
+    ///
+    /// het_map.insert_type::<u64>();
+    ///
+    /// assert!(het_map.contains_type::<i32>());
+    /// assert!(het_map.contains_type::<u64>());
+    ///
+    /// het_map.remove_type::<i32>();
+    ///
+    /// assert!(!het_map.contains_type::<i32>());
+    /// assert!(het_map.contains_type::<u64>());
+    ///
+    /// het_map.remove_type::<u64>();
+    ///
+    /// assert!(!het_map.contains_type::<i32>());
+    /// assert!(!het_map.contains_type::<u64>());
+    /// ```
+    pub fn contains_type<T>(&self) -> bool
+    where
+        T: any::Any,
+    {
+        let type_id = any::TypeId::of::<T>();
+
+        self.map.contains_key(&type_id)
+    }
+
+    /// Returns a reference to the homogeneous hash map containing all values of a given type
+    /// from the heterogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the given type `T` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// let map = het_map.get_map_unchecked::<i32>();
+    ///
+    /// assert_eq!(map.len(), 0);
+    /// ```
+    #[track_caller]
+    pub fn get_map_unchecked<T>(&self) -> &HomogeneousHashMap<K, T, S, A>
+    where
+        T: any::Any,
+    {
+        let type_id = any::TypeId::of::<T>();
+        let map = self.map[&type_id].as_proj::<Key<K, T>, T, S, A>();
+
+        HomogeneousHashMap::from_inner_ref(map)
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type from the heterogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the given type `T` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// let map = het_map.get_map_mut_unchecked::<i32>();
+    ///
+    /// assert_eq!(map.len(), 0);
+    /// ```
+    #[track_caller]
+    pub fn get_map_mut_unchecked<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S, A>
+    where
+        T: any::Any,
+    {
+        self.mark_signature_stale(any::TypeId::of::<T>());
+
+        self.get_map_mut_unchecked_untracked::<T>()
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type, without marking the type as stale in the signature index.
+    ///
+    /// The caller must either leave the keys of the map untouched, or update the signature index
+    /// itself.
+    #[track_caller]
+    fn get_map_mut_unchecked_untracked<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S, A>
+    where
+        T: any::Any,
+    {
+        let type_id = any::TypeId::of::<T>();
+        let map = self.map.get_mut(&type_id).unwrap().as_proj_mut::<Key<K, T>, T, S, A>();
+
+        HomogeneousHashMap::from_inner_ref_mut(map)
+    }
+
+    /// Returns a reference to the homogeneous hash map containing all values of a given
+    /// type from the heterogeneous hash map.
+    ///
+    /// If `T` exists in the heterogeneous hash map, this method returns `Some(&map)`, where
+    /// `map` is the homogeneous hash map containing all values of type `T`. If `T` does not exist
+    /// in the heterogeneous map, this method returns `None`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
     /// het_map.insert_type::<u32>();
     /// het_map.extend([
     ///     (Key::new(0_usize), 2_i32),
@@ -685,7 +1287,7 @@ where
     /// assert_eq!(map1.len(), 3);
     /// assert_eq!(map2.len(), 0);
     /// ```
-    pub fn get_map<T>(&self) -> Option<&HomogeneousHashMap<K, T, S>>
+    pub fn get_map<T>(&self) -> Option<&HomogeneousHashMap<K, T, S, A>>
     where
         T: any::Any,
     {
@@ -694,10 +1296,7 @@ where
             return None;
         }
 
-        let map = self
-            .map
-            .get(&type_id)
-            .map(|m| m.as_proj::<Key<K, T>, T, S, alloc::Global>())?;
+        let map = self.map.get(&type_id).map(|m| m.as_proj::<Key<K, T>, T, S, A>())?;
 
         Some(HomogeneousHashMap::from_inner_ref(map))
     }
@@ -747,7 +1346,22 @@ where
     ///     assert_eq!(map2.len(), 0);
     /// }
     /// ```
-    pub fn get_map_mut<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S>>
+    pub fn get_map_mut<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.mark_signature_stale(any::TypeId::of::<T>());
+
+        self.get_map_mut_untracked::<T>()
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type, if the type exists in the heterogeneous hash map, without marking the type as stale
+    /// in the signature index.
+    ///
+    /// The caller must either leave the keys of the map untouched, or update the signature index
+    /// itself.
+    pub(crate) fn get_map_mut_untracked<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S, A>>
     where
         T: any::Any,
     {
@@ -756,10 +1370,7 @@ where
             return None;
         }
 
-        let map = self
-            .map
-            .get_mut(&type_id)
-            .map(|m| m.as_proj_mut::<Key<K, T>, T, S, alloc::Global>())?;
+        let map = self.map.get_mut(&type_id).map(|m| m.as_proj_mut::<Key<K, T>, T, S, A>())?;
 
         Some(HomogeneousHashMap::from_inner_ref_mut(map))
     }
@@ -806,7 +1417,7 @@ where
     ///
     /// assert!(het_map.contains_type::<f64>());
     /// ```
-    pub fn get_or_insert_map_mut<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S>
+    pub fn get_or_insert_map_mut<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S, A>
     where
         T: any::Any,
     {
@@ -816,6 +1427,7 @@ where
         }
 
         debug_assert_eq!(self.registry.len(), self.map.len());
+        debug_assert_eq!(self.vtables.len(), self.map.len());
 
         self.get_map_mut::<T>().unwrap()
     }
@@ -864,7 +1476,7 @@ where
     ///
     /// assert!(het_map.contains_type::<f64>());
     /// ```
-    pub fn get_or_insert_with_capacity_map_mut<T>(&mut self, capacity: usize) -> &mut HomogeneousHashMap<K, T, S>
+    pub fn get_or_insert_with_capacity_map_mut<T>(&mut self, capacity: usize) -> &mut HomogeneousHashMap<K, T, S, A>
     where
         T: any::Any,
     {
@@ -874,31 +1486,178 @@ where
         }
 
         debug_assert_eq!(self.registry.len(), self.map.len());
+        debug_assert_eq!(self.vtables.len(), self.map.len());
 
         self.get_map_mut::<T>().unwrap()
     }
 
-    /// Removes a type from a heterogeneous hash map.
-    ///
-    /// This method behaves as follows:
-    ///
-    /// * If the given type `T` exists in the heterogeneous hash map, this method removes every
-    ///   value of type `T` from the map, deallocates memory allocated for values of type `T`, and
-    ///   returns `Some(count)`, when `count` is the number of values of type `T` that were stored
-    ///   in the map. This method returns `Some(0)` even when the type `T` exists in the map, but
-    ///   no values of type `T` do.
-    /// * If the given type `T` does not exist in the heterogeneous hash map, this method returns
-    ///   `None`.
-    ///
-    /// # Complexity Characteristics
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type, if the type exists in the heterogeneous hash map, and registers the hashed key
+    /// lookup of the type.
     ///
-    /// This method runs in amortized **O(1 + m)** time, where `m` is an affine function of the
-    /// value capacity of the given type provided to the method.
+    /// Registering a type does not require a hashable key type, so the type-erased methods of the
+    /// map, such as [`get_dyn`], can only hash their keys once a method that inserts values with
+    /// a hashable key type, such as [`insert`], has registered the key lookup. Until then they
+    /// fall back to a linear scan over the keys of the type.
     ///
-    /// # Examples
+    /// [`get_dyn`]: HeterogeneousHashMap::get_dyn
+    /// [`insert`]: HeterogeneousHashMap::insert
+    fn get_hashed_map_mut<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S, A>>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        self.set_key_lookup::<T>();
+
+        self.get_map_mut_untracked::<T>()
+    }
+
+    /// Registers the hashed key lookup of the type `T`, if the type exists in the heterogeneous
+    /// hash map.
     ///
-    /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// Every method that can fill the storage of a type while a hashable key type is in scope
+    /// calls this method, so that the type-erased methods of the map hash their keys instead of
+    /// scanning the keys of the type.
+    pub(crate) fn set_key_lookup<T>(&mut self)
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        if let Some(vtable) = self.vtables.get_mut(&any::TypeId::of::<T>()) {
+            vtable.set_key_lookup::<K, T, S, A>();
+        }
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map containing all values of a given
+    /// type, inserting the type `T` if it does not exist in the heterogeneous hash map, and
+    /// registers the hashed key lookup of the type.
+    fn get_or_insert_hashed_map_mut<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S, A>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        self.insert_type::<T>();
+
+        self.get_hashed_map_mut::<T>().unwrap()
+    }
+
+    /// Returns mutable references to the homogeneous hash maps storing the values of each type in
+    /// a tuple of types, if every type exists in the heterogeneous hash map.
+    ///
+    /// Unlike [`get_map_mut`], which borrows the whole heterogeneous hash map, this method
+    /// returns disjoint mutable borrows of the maps of several types at once, since each type
+    /// has separate storage. This method returns `None` if any type in the tuple `Types` does not
+    /// exist in the map. Tuples of up to twelve types are supported.
+    ///
+    /// [`get_map_mut`]: HeterogeneousHashMap::get_map_mut
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuple `Types` contains the same type more than once, to
+    /// preserve soundness.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(m)** time, where `m` is the number of types in the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([(Key::new(1_usize), 1_i32), (Key::new(2_usize), 2_i32)]);
+    /// het_map.extend([(Key::new(1_usize), 0.5_f64), (Key::new(2_usize), 1.5_f64)]);
+    ///
+    /// let (ints, floats) = het_map.get_maps_mut::<(i32, f64)>().unwrap();
+    /// for (key, float) in floats.iter_mut() {
+    ///     *float += ints[&Key::new(*key.id())] as f64;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<f64, _>(&Key::new(1_usize)), Some(&1.5_f64));
+    /// assert_eq!(het_map.get::<f64, _>(&Key::new(2_usize)), Some(&3.5_f64));
+    ///
+    /// assert!(het_map.get_maps_mut::<(i32, String)>().is_none());
+    /// ```
+    #[track_caller]
+    pub fn get_maps_mut<Types>(&mut self) -> Option<Types::MapsMut<'_>>
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        for type_id in Types::type_ids() {
+            self.mark_signature_stale(type_id);
+        }
+
+        Types::get_maps_mut(self)
+    }
+
+    /// Returns mutable references to the homogeneous hash maps storing the values of each type in
+    /// a tuple of types, inserting the types that do not exist in the heterogeneous hash map.
+    ///
+    /// This method is the infallible counterpart of [`get_maps_mut`]. Tuples of up to twelve
+    /// types are supported.
+    ///
+    /// [`get_maps_mut`]: HeterogeneousHashMap::get_maps_mut
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuple `Types` contains the same type more than once, to
+    /// preserve soundness.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(m)** time, where `m` is the number of types in the
+    /// tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([(Key::new(1_usize), 1_i32), (Key::new(2_usize), 2_i32)]);
+    /// het_map.extend([(Key::new(1_usize), 0.5_f64), (Key::new(2_usize), 1.5_f64)]);
+    ///
+    /// let (ints, names) = het_map.get_or_insert_maps_mut::<(i32, String)>();
+    /// for (key, value) in ints.iter() {
+    ///     names.insert(Key::new(*key.id()), value.to_string());
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(2_usize)), Some(&String::from("2")));
+    /// ```
+    #[track_caller]
+    pub fn get_or_insert_maps_mut<Types>(&mut self) -> Types::MapsMut<'_>
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        for type_id in Types::type_ids() {
+            self.mark_signature_stale(type_id);
+        }
+
+        Types::get_or_insert_maps_mut(self)
+    }
+
+    /// Removes a type from a heterogeneous hash map.
+    ///
+    /// This method behaves as follows:
+    ///
+    /// * If the given type `T` exists in the heterogeneous hash map, this method removes every
+    ///   value of type `T` from the map, deallocates memory allocated for values of type `T`, and
+    ///   returns `Some(count)`, when `count` is the number of values of type `T` that were stored
+    ///   in the map. This method returns `Some(0)` even when the type `T` exists in the map, but
+    ///   no values of type `T` do.
+    /// * If the given type `T` does not exist in the heterogeneous hash map, this method returns
+    ///   `None`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1 + m)** time, where `m` is an affine function of the
+    /// value capacity of the given type provided to the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
     ///
@@ -964,7 +1723,7 @@ where
         T: any::Any,
     {
         let removed_count = {
-            let map = self.get_map_mut::<T>()?;
+            let map = self.get_map_mut_untracked::<T>()?;
             let _removed_count = map.len();
             map.clear();
             _removed_count
@@ -972,9 +1731,15 @@ where
 
         let type_id = any::TypeId::of::<T>();
         self.map.remove(&type_id);
+        if let Some(index) = self.signatures.as_mut() {
+            index.remove_type(type_id);
+        }
         self.registry.remove(&type_id);
+        self.vtables.remove(&type_id);
+        self.views.retain(|(view_type_id, _), _| *view_type_id != type_id);
 
         debug_assert_eq!(self.registry.len(), self.map.len());
+        debug_assert_eq!(self.vtables.len(), self.map.len());
 
         Some(removed_count)
     }
@@ -1086,17 +1851,23 @@ where
     /// assert!(het_map.is_empty_types());
     /// ```
     #[rustfmt::skip]
-    pub fn take_type<T>(&mut self) -> Option<HomogeneousHashMap<K, T, S>>
+    pub fn take_type<T>(&mut self) -> Option<HomogeneousHashMap<K, T, S, A>>
     where
         T: any::Any,
     {
         let type_id = any::TypeId::of::<T>();
         let removed_map = self.map.remove(&type_id)?;
+        if let Some(index) = self.signatures.as_mut() {
+            index.remove_type(type_id);
+        }
         self.registry.remove(&type_id);
+        self.vtables.remove(&type_id);
+        self.views.retain(|(view_type_id, _), _| *view_type_id != type_id);
 
         debug_assert_eq!(self.registry.len(), self.map.len());
+        debug_assert_eq!(self.vtables.len(), self.map.len());
 
-        Some(HomogeneousHashMap::from_inner(removed_map.into_proj::<Key<K, T>, T, S, alloc::Global>()))
+        Some(HomogeneousHashMap::from_inner(removed_map.into_proj::<Key<K, T>, T, S, A>()))
     }
 
     /// Removes all types and all values for each type from the heterogeneous hash map.
@@ -1145,18 +1916,135 @@ where
         for type_id in type_ids.iter() {
             let _ = self.map.remove(type_id);
             let _ = self.registry.remove(type_id);
+            let _ = self.vtables.remove(type_id);
+        }
+        self.views.clear();
+        if let Some(index) = self.signatures.as_mut() {
+            index.clear();
         }
 
         debug_assert_eq!(self.registry.len(), 0);
+        debug_assert_eq!(self.vtables.len(), 0);
         debug_assert_eq!(self.map.len(), 0);
     }
+
+    /// Removes every value of every type from the heterogeneous hash map, while keeping every
+    /// type stored in the map.
+    ///
+    /// Unlike [`clear`], this method keeps the types, their metadata, and their registered
+    /// operations, and it keeps the memory allocated for the values of each type. This makes it
+    /// cheap to refill the map with values of the same types.
+    ///
+    /// [`clear`]: HeterogeneousHashMap::clear
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the total number of
+    /// values in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_cloneable_type::<i32>();
+    /// het_map.insert_type_with_capacity::<f64>(10);
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_f64),
+    ///     (Key::new(1_usize), 2_f64),
+    ///     (Key::new(2_usize), 3_f64),
+    /// ]);
+    ///
+    /// let old_capacity = het_map.capacity::<f64>();
+    /// het_map.clear_values();
+    ///
+    /// assert_eq!(het_map.len_types(), 2);
+    /// assert_eq!(het_map.len_map(), 0);
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    /// assert_eq!(het_map.len::<f64>(), Some(0));
+    /// assert_eq!(het_map.capacity::<f64>(), old_capacity);
+    ///
+    /// // The registered operations survive clearing the values.
+    /// assert!(het_map.try_clone().is_err());
+    /// het_map.remove_type::<f64>();
+    /// assert!(het_map.try_clone().is_ok());
+    /// ```
+    pub fn clear_values(&mut self) {
+        for (type_id, map) in self.map.iter_mut() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            vtable.clear_map(map);
+        }
+        if let Some(index) = self.signatures.as_mut() {
+            index.clear_keys();
+        }
+
+        debug_assert_eq!(self.len_map(), 0);
+    }
+
+    /// Shrinks the capacity of the heterogeneous hash map as much as possible.
+    ///
+    /// This method shrinks both the storage for the values of every type, and the type tables of
+    /// the heterogeneous hash map, as in [`shrink_types_to_fit`]. The resulting heterogeneous
+    /// hash map might still have some excess capacity, depending on the resize policy of the
+    /// internal structures.
+    ///
+    /// [`shrink_types_to_fit`]: HeterogeneousHashMap::shrink_types_to_fit
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the total number of
+    /// values and types in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::with_capacity(10);
+    /// het_map.insert_type_with_capacity::<i32>(10);
+    /// het_map.insert_type_with_capacity::<f64>(10);
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    ///
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// assert!(het_map.capacity::<f64>() >= Some(10));
+    ///
+    /// het_map.shrink_to_fit_all();
+    ///
+    /// assert!(het_map.capacity::<i32>() >= Some(2));
+    /// assert!(het_map.capacity::<i32>() < Some(10));
+    /// assert!(het_map.capacity::<f64>() < Some(10));
+    /// assert!(het_map.capacity_types() >= 2);
+    /// ```
+    pub fn shrink_to_fit_all(&mut self) {
+        for (type_id, map) in self.map.iter_mut() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            vtable.shrink_map_to_fit(map);
+        }
+
+        self.shrink_types_to_fit();
+    }
 }
 
-impl<S, K> HeterogeneousHashMap<K, S>
+impl<S, K, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Computes the total number of values across all types in the heterogeneous hash map.
     ///
@@ -1256,13 +2144,39 @@ where
     pub const fn hasher(&self) -> &S {
         &self.build_hasher
     }
+
+    /// Returns a reference to the memory allocator of the heterogeneous hash map.
+    ///
+    /// Every type stored in the heterogeneous hash map allocates the storage for its values from
+    /// this allocator. The bookkeeping tables of the heterogeneous hash map itself are allocated
+    /// from the global allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let het_map: HeterogeneousHashMap<usize, RandomState, Global> = HeterogeneousHashMap::new_in(Global);
+    /// let alloc: &Global = het_map.allocator();
+    /// ```
+    #[inline]
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
+    }
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Returns the number of types stored in the heterogeneous hash map.
     ///
@@ -1338,6 +2252,80 @@ where
         self.map.capacity()
     }
 
+    /// Reserves capacity for at least `additional` more types in the heterogeneous hash map.
+    ///
+    /// The collection may reserve more space to speculatively avoid frequent reallocations.
+    /// After calling this method, the type capacity will be greater than or equal to
+    /// `self.len_types() + additional`. This method does nothing if the type capacity is already
+    /// sufficient. This method does not allocate memory for the values of any type.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the new allocation size overflows `usize`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of types
+    /// in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.reserve_types(4);
+    ///
+    /// assert!(het_map.capacity_types() >= 4);
+    /// let old_capacity = het_map.capacity_types();
+    ///
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u64>();
+    /// het_map.insert_type::<f64>();
+    /// het_map.insert_type::<String>();
+    ///
+    /// assert_eq!(het_map.capacity_types(), old_capacity);
+    /// ```
+    pub fn reserve_types(&mut self, additional: usize) {
+        self.map.reserve(additional);
+        self.registry.reserve(additional);
+        self.vtables.reserve(additional);
+    }
+
+    /// Shrinks the type capacity of the heterogeneous hash map as much as possible.
+    ///
+    /// This method only shrinks the type tables of the heterogeneous hash map. It leaves the
+    /// storage for the values of every type untouched. The resulting heterogeneous hash map might
+    /// still have some excess type capacity, depending on the resize policy of the internal
+    /// structures.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of types
+    /// in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::with_capacity(100);
+    /// het_map.insert_type_with_capacity::<i32>(10);
+    ///
+    /// assert!(het_map.capacity_types() >= 100);
+    ///
+    /// het_map.shrink_types_to_fit();
+    ///
+    /// assert!(het_map.capacity_types() >= 1);
+    /// assert!(het_map.capacity_types() < 100);
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// ```
+    pub fn shrink_types_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+        self.registry.shrink_to_fit();
+        self.vtables.shrink_to_fit();
+    }
+
     /// Determines whether the heterogeneous hash map is empty.
     ///
     /// A heterogeneous hash map is **empty** if it contains no types. Equivalently, a
@@ -1382,11 +2370,12 @@ where
     }
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Returns the capacity the heterogeneous hash map has for the given type.
     ///
@@ -1418,69 +2407,168 @@ where
         Some(map.capacity())
     }
 
-    /// Returns the number of elements the heterogeneous hash map has for the given type.
+    /// Reserves capacity for at least `additional` more values of a given type in the
+    /// heterogeneous hash map.
     ///
-    /// The **length** for a given type is the number of values of the given type the
-    /// heterogeneous hash map currently stores.
+    /// If the given type `T` does not exist in the map when this method is called, this method
+    /// inserts the type into the map. The collection may reserve more space to speculatively
+    /// avoid frequent reallocations. After calling this method, the capacity for the type `T`
+    /// will be greater than or equal to `self.len::<T>() + additional`. This method does nothing
+    /// if the capacity for the type `T` is already sufficient.
     ///
-    /// This method returns `Some(length)` if the given type `T` exists in the map. This method
-    /// returns `None` if the given type `T` does not exist in the map.
+    /// # Panics
+    ///
+    /// This method panics if the new allocation size overflows `usize`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
     ///
     /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
-    /// # use core::f64;
     /// #
     /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type_with_capacity::<f64>(10);
     /// het_map.extend([
-    ///     (Key::new(0_usize), f64::consts::SQRT_2),
-    ///     (Key::new(1_usize), f64::consts::PI),
-    ///     (Key::new(2_usize), f64::consts::E),
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    ///     (Key::new(2_usize), 3_i32),
     /// ]);
+    /// het_map.reserve::<i32>(10);
     ///
-    /// assert_eq!(het_map.len::<i32>(),    Some(0));
-    /// assert_eq!(het_map.len::<f64>(),    Some(3));
-    /// assert_eq!(het_map.len::<String>(), None);
+    /// assert!(het_map.capacity::<i32>() >= Some(3 + 10));
+    ///
+    /// // Reserving capacity for a new type inserts the type into the map.
+    /// het_map.reserve::<f64>(10);
+    ///
+    /// assert!(het_map.capacity::<f64>() >= Some(10));
     /// ```
-    pub fn len<T>(&self) -> Option<usize>
+    pub fn reserve<T>(&mut self, additional: usize)
     where
         T: any::Any,
     {
-        let map = self.get_map::<T>()?;
+        let map = self.get_or_insert_map_mut::<T>();
 
-        Some(map.len())
+        map.reserve(additional)
     }
 
-    /// Determines whether the heterogeneous hash map contains no values of the given type.
+    /// Tries to reserve capacity for at least `additional` more values of a given type in the
+    /// heterogeneous hash map.
     ///
-    /// This method behaves as follows:
+    /// If the given type `T` does not exist in the map when this method is called, this method
+    /// inserts the type into the map. The collection may reserve more space to speculatively
+    /// avoid frequent reallocations. After calling this method, the capacity for the type `T`
+    /// will be greater than or equal to `self.len::<T>() + additional` if it returns `Ok(())`.
+    /// This method does nothing if the capacity for the type `T` is already sufficient. This
+    /// method preserves the contents of the map even if an error occurs.
     ///
-    /// * If the heterogeneous hash map contains the type `T`, and contains at least one value
-    ///   of type `T`, this method returns `Some(true)`.
-    /// * If the heterogeneous hash map contains the type `T`, but contains no values of type
-    ///   `T`, this method returns `Some(false)`.
-    /// * If the heterogeneous hash map does not contain the type `T`, this method returns `None`.
+    /// # Errors
     ///
-    /// In particular, this method returns an [`Option<bool>`] instead of a regular [`bool`] to
-    /// distinguish between the last two cases above.
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
     ///
     /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
-    /// # use core::f64;
     /// #
     /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type_with_capacity::<f64>(10);
     /// het_map.extend([
-    ///     (Key::new(0_usize), f64::consts::SQRT_2),
-    ///     (Key::new(1_usize), f64::consts::PI),
-    ///     (Key::new(2_usize), f64::consts::E),
-    /// ]);
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    ///     (Key::new(2_usize), 3_i32),
+    /// ]);
+    ///
+    /// assert!(het_map.try_reserve::<i32>(10).is_ok());
+    /// assert!(het_map.capacity::<i32>() >= Some(3 + 10));
+    ///
+    /// // Reserving capacity for a new type inserts the type into the map.
+    /// assert!(het_map.try_reserve::<f64>(10).is_ok());
+    /// assert!(het_map.capacity::<f64>() >= Some(10));
+    ///
+    /// // The capacity overflows, so the map is left unchanged.
+    /// assert!(het_map.try_reserve::<i32>(usize::MAX).is_err());
+    /// assert_eq!(het_map.len::<i32>(), Some(3));
+    /// ```
+    pub fn try_reserve<T>(&mut self, additional: usize) -> Result<(), TryReserveError>
+    where
+        T: any::Any,
+    {
+        let map = self.try_get_or_insert_map_mut::<T>()?;
+
+        map.try_reserve(additional)
+    }
+
+    /// Returns the number of elements the heterogeneous hash map has for the given type.
+    ///
+    /// The **length** for a given type is the number of values of the given type the
+    /// heterogeneous hash map currently stores.
+    ///
+    /// This method returns `Some(length)` if the given type `T` exists in the map. This method
+    /// returns `None` if the given type `T` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::f64;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type_with_capacity::<f64>(10);
+    /// het_map.extend([
+    ///     (Key::new(0_usize), f64::consts::SQRT_2),
+    ///     (Key::new(1_usize), f64::consts::PI),
+    ///     (Key::new(2_usize), f64::consts::E),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.len::<i32>(),    Some(0));
+    /// assert_eq!(het_map.len::<f64>(),    Some(3));
+    /// assert_eq!(het_map.len::<String>(), None);
+    /// ```
+    pub fn len<T>(&self) -> Option<usize>
+    where
+        T: any::Any,
+    {
+        let map = self.get_map::<T>()?;
+
+        Some(map.len())
+    }
+
+    /// Determines whether the heterogeneous hash map contains no values of the given type.
+    ///
+    /// This method behaves as follows:
+    ///
+    /// * If the heterogeneous hash map contains the type `T`, and contains at least one value
+    ///   of type `T`, this method returns `Some(true)`.
+    /// * If the heterogeneous hash map contains the type `T`, but contains no values of type
+    ///   `T`, this method returns `Some(false)`.
+    /// * If the heterogeneous hash map does not contain the type `T`, this method returns `None`.
+    ///
+    /// In particular, this method returns an [`Option<bool>`] instead of a regular [`bool`] to
+    /// distinguish between the last two cases above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::f64;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type_with_capacity::<f64>(10);
+    /// het_map.extend([
+    ///     (Key::new(0_usize), f64::consts::SQRT_2),
+    ///     (Key::new(1_usize), f64::consts::PI),
+    ///     (Key::new(2_usize), f64::consts::E),
+    /// ]);
     ///
     /// assert_eq!(het_map.is_empty::<i32>(),    Some(true));
     /// assert_eq!(het_map.is_empty::<f64>(),    Some(false));
@@ -1494,13 +2582,55 @@ where
 
         Some(map.is_empty())
     }
+
+    /// Returns the number of elements the heterogeneous hash map has for the type with the given
+    /// [`TypeId`].
+    ///
+    /// This method is the runtime-typed counterpart of [`len`], for callers that only know the
+    /// type identifier of a type at runtime. This method returns `Some(length)` if the type with
+    /// the given type identifier exists in the map. This method returns `None` otherwise.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`len`]: HeterogeneousHashMap::len
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::any::TypeId;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.insert(Key::new(0_usize), String::from("foo"));
+    ///
+    /// assert_eq!(het_map.len_by_id(TypeId::of::<i32>()),    Some(2));
+    /// assert_eq!(het_map.len_by_id(TypeId::of::<String>()), Some(1));
+    /// assert_eq!(het_map.len_by_id(TypeId::of::<f64>()),    None);
+    /// ```
+    pub fn len_by_id(&self, type_id: any::TypeId) -> Option<usize> {
+        let map = self.map.get(&type_id)?;
+        let vtable = self
+            .vtables
+            .get(&type_id)
+            .expect("Every stored type must have a registered vtable");
+
+        Some(vtable.len_map(map))
+    }
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Returns the type metadata for a given type in the heterogeneous hash map.
     ///
@@ -1631,13 +2761,305 @@ where
     pub fn metadata_iter(&self) -> TypeMetadataIter<'_> {
         TypeMetadataIter::new(self.registry.iter())
     }
+
+    /// Returns an iterator over the metadata of every type in the heterogeneous hash map that has
+    /// a value stored under the given key.
+    ///
+    /// This method walks the storage of every type registered in the map, so it answers the
+    /// question "what is stored under this key?" for a key shared across several value types.
+    /// The iterator yields the types in an arbitrary order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in average **O(t)** time, where `t` is the number of types in
+    /// the heterogeneous hash map, if the key lookup of every type was registered by inserting its
+    /// values with a method such as [`insert`]. Each type without a registered key lookup is
+    /// searched with a linear scan over its keys, so iterating to completion runs in **O(t + m)**
+    /// time in general, where `m` is the total number of values of the types without a registered
+    /// key lookup.
+    ///
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeMetadata};
+    /// # use core::any::TypeId;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(42_usize), 1_i32);
+    /// het_map.insert(Key::new(42_usize), String::from("foo"));
+    /// het_map.insert(Key::new(7_usize), 2.5_f64);
+    ///
+    /// let mut type_ids: Vec<TypeId> = het_map
+    ///     .types_for_key(&42_usize)
+    ///     .map(|metadata| metadata.type_id())
+    ///     .collect();
+    /// type_ids.sort();
+    /// let mut expected = Vec::from([TypeId::of::<i32>(), TypeId::of::<String>()]);
+    /// expected.sort();
+    ///
+    /// assert_eq!(type_ids, expected);
+    /// assert_eq!(het_map.types_for_key(&7_usize).collect::<Vec<_>>(), [TypeMetadata::of::<f64>()]);
+    /// assert_eq!(het_map.types_for_key(&0_usize).count(), 0);
+    /// ```
+    pub fn types_for_key<'a>(&'a self, key: &'a K) -> impl Iterator<Item = TypeMetadata> + 'a
+    where
+        K: Eq,
+    {
+        self.values_for_key(key).map(|(metadata, _value)| metadata)
+    }
+
+    /// Returns an iterator over the metadata and a type-erased reference to the value of every
+    /// type in the heterogeneous hash map that has a value stored under the given key.
+    ///
+    /// Each type-erased reference can be downcast to its concrete type with
+    /// [`Any::downcast_ref`]. The iterator yields the types in an arbitrary order.
+    ///
+    /// [`Any::downcast_ref`]: core::any::Any::downcast_ref
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in average **O(t)** time, where `t` is the number of types in
+    /// the heterogeneous hash map, if the key lookup of every type was registered by inserting its
+    /// values with a method such as [`insert`]. Each type without a registered key lookup is
+    /// searched with a linear scan over its keys, so iterating to completion runs in **O(t + m)**
+    /// time in general, where `m` is the total number of values of the types without a registered
+    /// key lookup.
+    ///
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeMetadata};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(42_usize), 1_i32);
+    /// het_map.insert(Key::new(42_usize), String::from("foo"));
+    /// het_map.insert(Key::new(7_usize), 2.5_f64);
+    ///
+    /// for (metadata, value) in het_map.values_for_key(&42_usize) {
+    ///     if metadata == TypeMetadata::of::<i32>() {
+    ///         assert_eq!(value.downcast_ref::<i32>(), Some(&1_i32));
+    ///     } else {
+    ///         assert_eq!(metadata, TypeMetadata::of::<String>());
+    ///         assert_eq!(value.downcast_ref::<String>().map(String::as_str), Some("foo"));
+    ///     }
+    /// }
+    /// ```
+    pub fn values_for_key<'a>(&'a self, key: &'a K) -> impl Iterator<Item = (TypeMetadata, &'a dyn any::Any)> + 'a
+    where
+        K: Eq,
+    {
+        self.map.iter().filter_map(move |(type_id, map)| {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let value = vtable.get_value(map, key)?;
+            let metadata = self
+                .registry
+                .get(type_id)
+                .expect("Every stored type must have registered metadata");
+
+            Some((*metadata, value))
+        })
+    }
+
+    /// Returns the number of types in the heterogeneous hash map that have a value stored under
+    /// the given key.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(t)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, if the key lookup of every type was registered by inserting its
+    /// values with a method such as [`insert`]. Each type without a registered key lookup is
+    /// searched with a linear scan over its keys, so this method runs in **O(t + m)** time in
+    /// general, where `m` is the total number of values of the types without a registered key
+    /// lookup.
+    ///
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(42_usize), 1_i32);
+    /// het_map.insert(Key::new(42_usize), String::from("foo"));
+    /// het_map.insert(Key::new(7_usize), 2.5_f64);
+    ///
+    /// assert_eq!(het_map.count_types_for_key(&42_usize), 2);
+    /// assert_eq!(het_map.count_types_for_key(&7_usize),  1);
+    /// assert_eq!(het_map.count_types_for_key(&0_usize),  0);
+    /// ```
+    pub fn count_types_for_key(&self, key: &K) -> usize
+    where
+        K: Eq,
+    {
+        self.types_for_key(key).count()
+    }
+
+    /// Returns the index from each key in the heterogeneous hash map to the signature of the
+    /// types that have a value stored under that key.
+    ///
+    /// The returned [`TypeSignatureIndex`] answers multi-type queries such as
+    /// [`keys_with_all`], [`keys_with_any`], and [`keys_without`] by scanning one bitset per key,
+    /// instead of probing the storage of every type for every key.
+    ///
+    /// The index is opt-in. The first call builds the index from every entry of the map. From
+    /// then on the map keeps the index up to date as types and values are inserted and removed,
+    /// until [`disable_signature_index`] is called. Operations that hand out mutable access to the
+    /// storage of a type, such as [`get_map_mut`] or [`entry`], mark that type as stale instead,
+    /// and this method rescans the storage of the stale types before returning the index.
+    ///
+    /// [`keys_with_all`]: TypeSignatureIndex::keys_with_all
+    /// [`keys_with_any`]: TypeSignatureIndex::keys_with_any
+    /// [`keys_without`]: TypeSignatureIndex::keys_without
+    /// [`disable_signature_index`]: HeterogeneousHashMap::disable_signature_index
+    /// [`get_map_mut`]: HeterogeneousHashMap::get_map_mut
+    /// [`entry`]: HeterogeneousHashMap::entry
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// The first call runs in **O(t + n)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, and `n` is the total number of values stored in the heterogeneous
+    /// hash map. Later calls run in **O(1)** time if no type is stale. Otherwise, they clear the
+    /// bits of the stale types from every signature in a single pass, and then rescan the stale
+    /// types, so they run in **O(k * (t / 64) + m)** time, where `k` is the number of keys in the
+    /// index, and `m` is the total number of values stored in the stale types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    /// het_map.extend((0..10).filter(|i| i % 2 == 0).map(|i| (Key::new(i), i as f64)));
+    /// het_map.extend((0..10).filter(|i| i % 3 == 0).map(|i| (Key::new(i), i.to_string())));
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// let mut keys: Vec<usize> = index.keys_with_all::<(i32, f64, String)>().copied().collect();
+    /// keys.sort();
+    ///
+    /// assert_eq!(index.len(), 10);
+    /// assert_eq!(keys, [0, 6]);
+    ///
+    /// het_map.remove::<f64, _>(&6_usize);
+    /// het_map.insert(Key::new(3_usize), 3.0_f64);
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// let mut keys: Vec<usize> = index.keys_with_all::<(i32, f64, String)>().copied().collect();
+    /// keys.sort();
+    ///
+    /// assert_eq!(keys, [0, 3]);
+    /// ```
+    pub fn signature_index(&mut self) -> &TypeSignatureIndex<K, S, A>
+    where
+        K: Clone + hash::Hash + Eq,
+    {
+        let index = self
+            .signatures
+            .get_or_insert_with(|| TypeSignatureIndex::with_types(self.map.keys().copied(), self.build_hasher.clone()));
+        let stale = index.take_stale();
+        index.clear_types(&stale);
+        for type_id in stale {
+            let (Some(map), Some(vtable)) = (self.map.get(&type_id), self.vtables.get(&type_id)) else {
+                continue;
+            };
+            for storage_index in 0..vtable.len_map(map) {
+                let key = vtable
+                    .get_key_index(map, storage_index)
+                    .and_then(|key| key.downcast_ref::<K>())
+                    .expect("The key must have the key type of the map");
+                index.insert(type_id, key);
+            }
+        }
+
+        index
+    }
+
+    /// Drops the signature index of the heterogeneous hash map, if it exists.
+    ///
+    /// After this call, the map no longer maintains a signature index. The next call to
+    /// [`signature_index`] builds a new index from every entry of the map.
+    ///
+    /// [`signature_index`]: HeterogeneousHashMap::signature_index
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is the number of keys in the signature index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    ///
+    /// assert_eq!(het_map.signature_index().len(), 1);
+    ///
+    /// het_map.disable_signature_index();
+    /// het_map.insert(Key::new(2_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.signature_index().len(), 2);
+    /// ```
+    pub fn disable_signature_index(&mut self) {
+        self.signatures = None;
+    }
+
+    /// Records in the signature index, if it exists, that the given key has a value of the
+    /// type `T`.
+    fn insert_signature<T>(&mut self, key: &K)
+    where
+        T: any::Any,
+    {
+        if let Some(index) = self.signatures.as_mut() {
+            index.insert_type(any::TypeId::of::<T>());
+            index.insert(any::TypeId::of::<T>(), key);
+        }
+    }
+
+    /// Records in the signature index, if it exists, that the given key no longer has a value of
+    /// the type `T`.
+    fn remove_signature<T>(&mut self, key: &K)
+    where
+        T: any::Any,
+    {
+        if let Some(index) = self.signatures.as_mut() {
+            index.remove(any::TypeId::of::<T>(), key);
+        }
+    }
+
+    /// Marks the type with the given [`TypeId`] as stale in the signature index, if it exists.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn mark_signature_stale(&mut self, type_id: any::TypeId) {
+        if let Some(index) = self.signatures.as_mut() {
+            index.mark_stale(type_id);
+        }
+    }
+
+    /// Marks every type as stale in the signature index, if it exists.
+    pub(crate) fn mark_signatures_stale(&mut self) {
+        if let Some(index) = self.signatures.as_mut() {
+            index.mark_all_stale();
+        }
+    }
 }
 
-impl<K, S> HeterogeneousHashMap<K, S>
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
 where
     K: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     /// Determines whether a heterogeneous hash map contains a value of the given type with the given key.
     ///
@@ -1689,13 +3111,55 @@ where
         let type_id = any::TypeId::of::<T>();
         match self.map.get(&type_id) {
             Some(opaque_map) => {
-                let proj_map = opaque_map.as_proj::<Key<K, T>, T, S, alloc::Global>();
+                let proj_map = opaque_map.as_proj::<Key<K, T>, T, S, A>();
                 proj_map.contains_key(key)
             }
             None => false,
         }
     }
 
+    /// Determines whether a heterogeneous hash map contains a value with the given key for the
+    /// type with the given [`TypeId`].
+    ///
+    /// This method is the runtime-typed counterpart of [`contains_key`], for callers that only
+    /// know the type identifier of a type at runtime. This method returns `true` if the type with
+    /// the given type identifier exists in the map, and has a value stored under the key `key`.
+    /// This method returns `false` otherwise.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`contains_key`]: HeterogeneousHashMap::contains_key
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time if the key lookup of the type was registered
+    /// by inserting its values with a method such as [`insert`], and in **O(n)** time otherwise,
+    /// where `n` is the number of values of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::any::TypeId;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.insert(Key::new(0_usize), String::from("foo"));
+    ///
+    /// assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &1_usize));
+    /// assert!(!het_map.contains_key_dyn(TypeId::of::<String>(), &1_usize));
+    /// assert!(!het_map.contains_key_dyn(TypeId::of::<f64>(), &0_usize));
+    /// ```
+    pub fn contains_key_dyn(&self, type_id: any::TypeId, key: &K) -> bool
+    where
+        K: Eq,
+    {
+        self.get_dyn(type_id, key).is_some()
+    }
+
     /// Returns a reference to the value of the given type with the given key, if the type
     /// and key exist in the heterogeneous hash map.
     ///
@@ -1738,24 +3202,81 @@ where
         map.get(key)
     }
 
-    /// Returns references to the key and value of the given type with the given key, if the type
-    /// and key exist in the heterogeneous hash map.
+    /// Returns a type-erased reference to the value with the given key for the type with the
+    /// given [`TypeId`], if it exists.
     ///
-    /// This method returns `Some((&eq_key, &value))`, where `value` has the given type, and an
-    /// equivalent key `eq_key` to the key `key` exists in the map corresponding to the value
-    /// `value`. This method returns `None` if the key-value pair with the given value type and an
-    /// equivalent key to the given key `key` does not exist in the map.
+    /// This method is the runtime-typed counterpart of [`get`], for callers that only know the
+    /// type identifier of a type at runtime. The returned reference can be downcast to the
+    /// concrete type with [`Any::downcast_ref`]. This method returns `None` if the type with the
+    /// given type identifier does not exist in the map, or has no value stored under the key
+    /// `key`.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`get`]: HeterogeneousHashMap::get
+    /// [`Any::downcast_ref`]: core::any::Any::downcast_ref
+    /// [`insert`]: HeterogeneousHashMap::insert
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in **O(1)** time.
+    /// This method runs in average **O(1)** time if the key lookup of the type was registered
+    /// by inserting its values with a method such as [`insert`], and in **O(n)** time otherwise,
+    /// where `n` is the number of values of the type.
     ///
     /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::any::TypeId;
     /// #
-    /// let mut het_map = HeterogeneousHashMap::new();
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.insert(Key::new(0_usize), String::from("foo"));
+    ///
+    /// let value = het_map.get_dyn(TypeId::of::<i32>(), &1_usize).unwrap();
+    ///
+    /// assert_eq!(value.downcast_ref::<i32>(), Some(&2_i32));
+    ///
+    /// let value = het_map.get_dyn(TypeId::of::<String>(), &0_usize).unwrap();
+    ///
+    /// assert_eq!(value.downcast_ref::<String>().map(String::as_str), Some("foo"));
+    ///
+    /// assert!(het_map.get_dyn(TypeId::of::<String>(), &1_usize).is_none());
+    /// assert!(het_map.get_dyn(TypeId::of::<f64>(), &0_usize).is_none());
+    /// ```
+    pub fn get_dyn(&self, type_id: any::TypeId, key: &K) -> Option<&dyn any::Any>
+    where
+        K: Eq,
+    {
+        let map = self.map.get(&type_id)?;
+        let vtable = self
+            .vtables
+            .get(&type_id)
+            .expect("Every stored type must have a registered vtable");
+
+        vtable.get_value(map, key)
+    }
+
+    /// Returns references to the key and value of the given type with the given key, if the type
+    /// and key exist in the heterogeneous hash map.
+    ///
+    /// This method returns `Some((&eq_key, &value))`, where `value` has the given type, and an
+    /// equivalent key `eq_key` to the key `key` exists in the map corresponding to the value
+    /// `value`. This method returns `None` if the key-value pair with the given value type and an
+    /// equivalent key to the given key `key` does not exist in the map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
     ///
     /// assert_eq!(het_map.get_key_value::<i32, _>(&Key::new(0_usize)), None);
     /// assert_eq!(het_map.get_key_value::<i32, _>(&Key::new(1_usize)), None);
@@ -1818,11 +3339,64 @@ where
         Key<K, T>: Borrow<Q>,
         Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut::<T>()?;
+        let map = self.get_map_mut_untracked::<T>()?;
 
         map.get_mut(key)
     }
 
+    /// Returns a type-erased mutable reference to the value with the given key for the type with
+    /// the given [`TypeId`], if it exists.
+    ///
+    /// This method is the runtime-typed counterpart of [`get_mut`], for callers that only know
+    /// the type identifier of a type at runtime. The returned reference can be downcast to the
+    /// concrete type with [`Any::downcast_mut`]. This method returns `None` if the type with the
+    /// given type identifier does not exist in the map, or has no value stored under the key
+    /// `key`.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`get_mut`]: HeterogeneousHashMap::get_mut
+    /// [`Any::downcast_mut`]: core::any::Any::downcast_mut
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time if the key lookup of the type was registered
+    /// by inserting its values with a method such as [`insert`], and in **O(n)** time otherwise,
+    /// where `n` is the number of values of the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::any::TypeId;
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.insert(Key::new(0_usize), String::from("foo"));
+    ///
+    /// if let Some(value) = het_map.get_dyn_mut(TypeId::of::<i32>(), &1_usize) {
+    ///     *value.downcast_mut::<i32>().unwrap() = 20_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&20_i32));
+    /// assert!(het_map.get_dyn_mut(TypeId::of::<f64>(), &0_usize).is_none());
+    /// ```
+    pub fn get_dyn_mut(&mut self, type_id: any::TypeId, key: &K) -> Option<&mut dyn any::Any>
+    where
+        K: Eq,
+    {
+        let map = self.map.get_mut(&type_id)?;
+        let vtable = self
+            .vtables
+            .get(&type_id)
+            .expect("Every stored type must have a registered vtable");
+
+        vtable.get_value_mut(map, key)
+    }
+
     /// Returns a reference to the key and a reference to the value of the given type with
     /// the given key, if the type and key exist in the heterogeneous hash map.
     ///
@@ -1861,7 +3435,7 @@ where
         Key<K, T>: Borrow<Q>,
         Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut::<T>()?;
+        let map = self.get_map_mut_untracked::<T>()?;
 
         map.get_key_value_mut(key)
     }
@@ -1920,47 +3494,135 @@ where
         Key<K, T>: Borrow<Q>,
         Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut::<T>().unwrap();
+        let map = self.get_map_mut_untracked::<T>().unwrap();
 
         map.get_disjoint_mut(ks)
     }
 
-    /// Inserts a new key-value pair of a given type with a given key into the heterogeneous hash
-    /// map.
+    /// Returns a reference to the value stored under the given key for each type in a tuple of
+    /// types.
+    ///
+    /// This method is equivalent to calling [`get`] once for each type in the tuple `Types`, and
+    /// collecting the results into a tuple. For each type in the tuple, the corresponding element
+    /// of the result is `Some(&value)` if the type exists in the map and has a value stored under
+    /// the key `key`, and `None` otherwise. Tuples of up to twelve types are supported.
+    ///
+    /// The key is hashed once for each type in the tuple. The values of each type live in a
+    /// separate index map, which only supports lookups by key and not by a precomputed hash, so
+    /// the hash of the key cannot be shared between the lookups of different types.
+    ///
+    /// [`get`]: HeterogeneousHashMap::get
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in amortized **O(1)** time.
+    /// This method runs in **O(m)** time, where `m` is the number of types in the tuple.
     ///
     /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(f64, f64);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Velocity(f64, f64);
+    ///
     /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(42_usize), Position(0.0, 0.0));
+    /// het_map.insert(Key::new(42_usize), Velocity(1.0, 2.0));
+    /// het_map.insert(Key::new(42_usize), 100_u32);
     ///
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// let (position, velocity, health) = het_map.get_many_types::<(Position, Velocity, u32)>(&42_usize);
     ///
-    /// het_map.insert(Key::new(1_usize), 5_i32);
+    /// assert_eq!(position, Some(&Position(0.0, 0.0)));
+    /// assert_eq!(velocity, Some(&Velocity(1.0, 2.0)));
+    /// assert_eq!(health, Some(&100_u32));
     ///
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// let (position, name) = het_map.get_many_types::<(Position, String)>(&42_usize);
+    ///
+    /// assert_eq!(position, Some(&Position(0.0, 0.0)));
+    /// assert_eq!(name, None);
     /// ```
-    pub fn insert<T>(&mut self, key: Key<K, T>, value: T) -> Option<T>
+    pub fn get_many_types<Types>(&self, key: &K) -> Types::Refs<'_>
     where
-        K: hash::Hash + Eq,
-        T: any::Any,
+        Types: TypeTuple<K, S, A>,
     {
-        let map = self.get_or_insert_map_mut::<T>();
+        Types::get_many_types(self, key)
+    }
 
-        map.insert(key, value)
+    /// Returns a mutable reference to the value stored under the given key for each type in a
+    /// tuple of types.
+    ///
+    /// This method is the mutable counterpart of [`get_many_types`]. Since the values of each type
+    /// live in separate storage, the mutable references to values of different types are
+    /// disjoint, and can be held at the same time. For each type in the tuple, the corresponding
+    /// element of the result is `Some(&mut value)` if the type exists in the map and has a value
+    /// stored under the key `key`, and `None` otherwise. Tuples of up to twelve types are
+    /// supported. Like [`get_many_types`], this method hashes the key once per type in the tuple.
+    ///
+    /// [`get_many_types`]: HeterogeneousHashMap::get_many_types
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuple `Types` contains the same type more than once, to
+    /// preserve soundness.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(m)** time, where `m` is the number of types in the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(f64, f64);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Velocity(f64, f64);
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(42_usize), Position(0.0, 0.0));
+    /// het_map.insert(Key::new(42_usize), Velocity(1.0, 2.0));
+    /// het_map.insert(Key::new(42_usize), 100_u32);
+    ///
+    /// let (position, velocity) = het_map.get_many_types_mut::<(Position, Velocity)>(&42_usize);
+    /// let position = position.unwrap();
+    /// let velocity = velocity.unwrap();
+    /// position.0 += velocity.0;
+    /// position.1 += velocity.1;
+    ///
+    /// assert_eq!(het_map.get::<Position, _>(&Key::new(42_usize)), Some(&Position(1.0, 2.0)));
+    /// ```
+    #[track_caller]
+    pub fn get_many_types_mut<Types>(&mut self, key: &K) -> Types::RefsMut<'_>
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        Types::get_many_types_mut(self, key)
     }
 
-    /// Removes an entry from a heterogeneous hash map of the given type with the given key, if it
-    /// exists.
+    /// Returns mutable references to the type-erased maps storing the values of each of the given
+    /// types, if they exist.
     ///
-    /// This method returns `Some(value)`, where `value` is the value associated with the
-    /// equivalent key to the key `key` if it exists in the map. If an equivalent key to `key`
-    /// does not exist in the map, this method returns `None`.
+    /// This method panics if any of the type identifiers are overlapping, to preserve soundness.
+    #[track_caller]
+    pub(crate) fn get_erased_maps_mut<const N: usize>(
+        &mut self,
+        type_ids: [&any::TypeId; N],
+    ) -> [Option<&mut opaque::index_map::TypeErasedIndexMap>; N] {
+        self.map.get_many_mut(type_ids)
+    }
+
+    /// Returns the storage index of the entry of a given type with the equivalent key to the
+    /// given one, if it exists.
+    ///
+    /// The entries of each type are stored in insertion order, so the storage index of an entry
+    /// is its position in that order among the entries of the same type. This method returns
+    /// `Some(index)` if an equivalent key to the key `key` exists for the type `T`. This method
+    /// returns `None` otherwise, including when the type `T` does not exist in the map.
     ///
     /// # Complexity Characteristics
     ///
@@ -1972,39 +3634,72 @@ where
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    /// assert_eq!(het_map.get_index_of::<i32, _>(&Key::new("foo")), Some(0));
+    /// assert_eq!(het_map.get_index_of::<i32, _>(&Key::new("baz")), Some(2));
+    /// assert_eq!(het_map.get_index_of::<i32, _>(&Key::new("quux")), None);
+    /// assert_eq!(het_map.get_index_of::<u64, _>(&Key::new("foo")), None);
+    /// ```
+    pub fn get_index_of<T, Q>(&self, key: &Q) -> Option<usize>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let map = self.get_map::<T>()?;
+
+        map.get_index_of(key)
+    }
+
+    /// Returns the storage index and a reference to the key-value pair of a given type with the
+    /// equivalent key to the given one, if it exists.
     ///
-    /// het_map.insert(Key::new(1_usize), 3_i32);
-    /// het_map.insert(Key::new(2_usize), 5_i32);
+    /// This method returns `Some((index, &eq_key, &value))`, where `index` is the storage index
+    /// of the entry among the entries of type `T`, `eq_key` is the equivalent key to the key
+    /// `key`, and `value` is the value corresponding to `eq_key`. This method returns `None`
+    /// otherwise, including when the type `T` does not exist in the map.
     ///
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    /// # Complexity Characteristics
     ///
-    /// assert_eq!(het_map.remove::<i32, _>(&Key::new(1_usize)), Some(3_i32));
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
     ///
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
     /// ```
-    pub fn remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.get_full::<i32, _>(&Key::new("bar")), Some((1, &Key::new("bar"), &2_i32)));
+    /// assert_eq!(het_map.get_full::<i32, _>(&Key::new("quux")), None);
+    /// assert_eq!(het_map.get_full::<u64, _>(&Key::new("bar")), None);
+    /// ```
+    pub fn get_full<T, Q>(&self, key: &Q) -> Option<(usize, &Key<K, T>, &T)>
     where
         T: any::Any,
         Key<K, T>: Borrow<Q>,
         Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut::<T>()?;
+        let map = self.get_map::<T>()?;
 
-        map.remove(key)
+        map.get_full(key)
     }
 
-    /// Removes an entry from a heterogeneous hash map of the given type with the given key, if it
-    /// exists.
+    /// Returns a reference to the key-value pair of a given type stored at the given storage
+    /// index, if it exists.
     ///
-    /// This method returns `Some((eq_key, value))`, where `eq_key` is equivalent key to the key
-    /// `key` in the map, and `value` is the value associated with `eq_key`, if an equivalent key
-    /// to the key `key` exists in the map. If an equivalent key to `key` does not exist in the map,
-    /// this method returns `None`.
+    /// This method returns `Some((&key, &value))` if the type `T` exists in the map and
+    /// `index < self.len::<T>()`. This method returns `None` otherwise.
     ///
     /// # Complexity Characteristics
     ///
@@ -2016,412 +3711,259 @@ where
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(3), None);
+    /// assert_eq!(het_map.get_index::<u64>(0), None);
+    /// ```
+    pub fn get_index<T>(&self, index: usize) -> Option<(&Key<K, T>, &T)>
+    where
+        T: any::Any,
+    {
+        let map = self.get_map::<T>()?;
+
+        map.get_index(index)
+    }
+
+    /// Returns a mutable reference to the key-value pair of a given type stored at the given
+    /// storage index, if it exists.
     ///
-    /// het_map.insert(Key::new(1_usize), 3_i32);
-    /// het_map.insert(Key::new(2_usize), 5_i32);
+    /// This method returns `Some((&key, &mut value))` if the type `T` exists in the map and
+    /// `index < self.len::<T>()`. This method returns `None` otherwise.
     ///
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    /// # Complexity Characteristics
     ///
-    /// assert_eq!(het_map.remove_entry::<i32, _>(&Key::new(1_usize)), Some((Key::new(1_usize), 3_i32)));
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
     ///
-    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
-    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
     /// ```
-    pub fn remove_entry<T, Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// if let Some((_, value)) = het_map.get_index_mut::<i32>(1) {
+    ///     *value *= 10_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get_index_mut::<i32>(1), Some((&Key::new("bar"), &mut 20_i32)));
+    /// assert_eq!(het_map.get_index_mut::<i32>(3), None);
+    /// assert_eq!(het_map.get_index_mut::<u64>(0), None);
+    /// ```
+    pub fn get_index_mut<T>(&mut self, index: usize) -> Option<(&Key<K, T>, &mut T)>
     where
         T: any::Any,
-        Key<K, T>: Borrow<Q>,
-        Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut::<T>()?;
+        let map = self.get_map_mut_untracked::<T>()?;
 
-        map.remove_entry(key)
+        map.get_index_mut(index)
     }
 
-
-    /// Returns the entry in the heterogeneous hash map corresponding to the given key, if it
+    /// Returns a reference to the first key-value pair of a given type in storage order, if it
     /// exists.
     ///
-    /// The resulting entry can be queried or manipulated directly, instead of going through the
-    /// heterogeneous hash map to do it.
+    /// This method returns `None` if the type `T` does not exist in the map, or the map contains
+    /// no values of type `T`.
     ///
-    /// If the type `T` exists in the heterogeneous hash map, this method returns `Some(entry)`
-    /// where `entry` is an entry of value type `T`. If the type `T` does not exist in the
-    /// heterogeneous hash map, this method returns `None`.
+    /// # Complexity Characteristics
     ///
-    /// # Examples
+    /// This method runs in **O(1)** time.
     ///
-    /// Accessing an existing type in a heterogeneous hash map.
+    /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type::<u32>();
-    /// het_map.insert(Key::new(1_usize), 2_i32);
-    /// het_map.insert(Key::new(2_usize), 3_i32);
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// het_map.insert_type::<u64>();
     ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    /// #
+    /// assert_eq!(het_map.first::<i32>(), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.first::<u64>(), None);
+    /// assert_eq!(het_map.first::<f64>(), None);
+    /// ```
+    pub fn first<T>(&self) -> Option<(&Key<K, T>, &T)>
+    where
+        T: any::Any,
+    {
+        let map = self.get_map::<T>()?;
+
+        map.first()
+    }
+
+    /// Returns a reference to the last key-value pair of a given type in storage order, if it
+    /// exists.
     ///
-    /// // Accessing existing entries in the heterogeneous hash map.
-    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
-    /// {
-    ///     let maybe_entry = het_map.entry::<i32>(Key::new(1_usize));
-    ///     assert!(maybe_entry.is_some());
-    ///     let entry = maybe_entry.unwrap();
-    ///     assert_eq!(entry.key(), &Key::new(1_usize));
-    ///     assert_eq!(entry.or_default(), &mut 2_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(2));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    /// #
+    /// This method returns `None` if the type `T` does not exist in the map, or the map contains
+    /// no values of type `T`.
     ///
-    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
-    /// {
-    ///     let maybe_entry = het_map.entry::<i32>(Key::new(2_usize));
-    ///     assert!(maybe_entry.is_some());
-    ///     let entry = maybe_entry.unwrap();
-    ///     assert_eq!(entry.key(), &Key::new(2_usize));
-    ///     assert_eq!(entry.or_default(), &mut 3_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(2));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    ///
-    /// // Creating a new entry if the type exists with `or_default`.
-    /// assert!(!het_map.contains_key::<i32, _>(&3_usize));
-    /// {
-    ///     let maybe_entry = het_map.entry::<i32>(Key::new(3_usize));
-    ///     assert!(maybe_entry.is_some());
-    ///     let entry = maybe_entry.unwrap();
-    ///     assert_eq!(entry.key(), &Key::new(3_usize));
-    ///     assert_eq!(entry.or_default(), &mut i32::default());
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&3_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(3));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    ///
-    /// // Creating a new entry if the type exists with `or_insert`.
-    /// assert!(!het_map.contains_key::<i32, _>(&4_usize));
-    /// {
-    ///     let maybe_entry = het_map.entry::<i32>(Key::new(4_usize));
-    ///     assert!(maybe_entry.is_some());
-    ///     let entry = maybe_entry.unwrap();
-    ///     assert_eq!(entry.key(), &Key::new(4_usize));
-    ///     assert_eq!(entry.or_insert(5_i32), &mut 5_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&4_usize));
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(4));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
+    /// # Complexity Characteristics
     ///
-    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
-    /// assert_eq!(het_map.get::<i32, _>(&4_usize), Some(&5_i32));
-    /// ```
+    /// This method runs in **O(1)** time.
     ///
-    /// Attempting to get values for a type that does not exist in the heterogeneous hash map.
+    /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type::<u32>();
-    /// het_map.insert(Key::new(1_usize), 2_i32);
-    /// het_map.insert(Key::new(2_usize), 3_i32);
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
-    ///
-    /// assert!(!het_map.contains_type::<f32>());
-    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
-    ///
-    /// assert!(het_map.entry::<f32>(Key::new(1_usize)).is_none());
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// het_map.insert_type::<u64>();
     ///
-    /// assert!(!het_map.contains_type::<f32>());
-    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    /// assert_eq!(het_map.last::<i32>(), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.last::<u64>(), None);
+    /// assert_eq!(het_map.last::<f64>(), None);
     /// ```
-    #[inline]
-    pub fn entry<T>(&mut self, key: Key<K, T>) -> Option<Entry<'_, K, T>>
+    pub fn last<T>(&self) -> Option<(&Key<K, T>, &T)>
     where
-        K: hash::Hash + Eq,
         T: any::Any,
     {
-        let map = self.get_map_mut::<T>()?;
+        let map = self.get_map::<T>()?;
 
-        Some(map.entry(key))
+        map.last()
     }
 
-    /// Returns the entry in the heterogeneous hash map corresponding to the given key.
+    /// Returns a slice of all the entries of a given type in the heterogeneous hash map, in
+    /// storage order, if that type exists in the heterogeneous hash map.
     ///
-    /// The resulting entry can be queried or manipulated directly, instead of going through the
-    /// heterogeneous hash map to do it.
+    /// # Complexity Characteristics
     ///
-    /// If the type `T` does not exist in the heterogeneous hash map, this method creates an
-    /// entry in the map for the type `T`, and returns a vacant entry for a value of type `T`.
+    /// This method runs in **O(1)** time.
     ///
     /// # Examples
     ///
-    /// Accessing an existing type in the heterogeneous hash map.
-    ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type::<u32>();
-    /// het_map.insert(Key::new(1_usize), 2_i32);
-    /// het_map.insert(Key::new(2_usize), 3_i32);
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    /// #
-    ///
-    /// // Accessing existing entries in the heterogeneous hash map.
-    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
-    /// {
-    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(1_usize));
-    ///     assert_eq!(entry.key(), &Key::new(1_usize));
-    ///     assert_eq!(entry.or_default(), &mut 2_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(2));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    /// #
-    ///
-    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
-    /// {
-    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(2_usize));
-    ///     assert_eq!(entry.key(), &Key::new(2_usize));
-    ///     assert_eq!(entry.or_default(), &mut 3_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(2));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    ///
-    /// // Creating a new entry if the type exists with `or_default`.
-    /// assert!(!het_map.contains_key::<i32, _>(&3_usize));
-    /// {
-    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(3_usize));
-    ///     assert_eq!(entry.key(), &Key::new(3_usize));
-    ///     assert_eq!(entry.or_default(), &mut i32::default());
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&3_usize));
-    /// #
-    /// # assert_eq!(het_map.len::<i32>(), Some(3));
-    /// # assert_eq!(het_map.len::<u32>(), Some(0));
-    /// # assert_eq!(het_map.len::<f32>(), None);
-    /// #
-    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
-    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
-    ///
-    /// // Creating a new entry if the type exists with `or_insert`.
-    /// assert!(!het_map.contains_key::<i32, _>(&4_usize));
-    /// {
-    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(4_usize));
-    ///     assert_eq!(entry.key(), &Key::new(4_usize));
-    ///     assert_eq!(entry.or_insert(5_i32), &mut 5_i32);
-    /// }
-    /// assert!(het_map.contains_key::<i32, _>(&4_usize));
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(4));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
-    ///
-    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
-    /// assert_eq!(het_map.get::<i32, _>(&4_usize), Some(&5_i32));
-    /// ```
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = het_map.as_slice::<i32>().unwrap();
     ///
-    /// Accessing a type that does not exist without inserting new entries inserts the new type.
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(slice[2], 3_i32);
     ///
+    /// assert!(het_map.as_slice::<u64>().is_none());
     /// ```
-    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
-    /// #
-    /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type::<u32>();
-    /// het_map.insert(Key::new(1_usize), 2_i32);
-    /// het_map.insert(Key::new(2_usize), 3_i32);
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
+    pub fn as_slice<T>(&self) -> Option<&Slice<K, T>>
+    where
+        T: any::Any,
+    {
+        let map = self.get_map::<T>()?;
+
+        Some(map.as_slice())
+    }
+
+    /// Returns a mutable slice of all the entries of a given type in the heterogeneous hash map,
+    /// in storage order, if that type exists in the heterogeneous hash map.
     ///
-    /// assert!(!het_map.contains_type::<f32>());
-    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
-    /// {
-    ///     let _ = het_map.entry_or_insert_type::<f32>(Key::new(1_usize));
-    /// }
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), Some(0));
+    /// # Complexity Characteristics
     ///
-    /// assert!(het_map.contains_type::<f32>());
-    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
-    /// ```
+    /// This method runs in **O(1)** time.
     ///
-    /// Accessing a type that does not exist and inserting new entries for it.
+    /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<i32>();
-    /// het_map.insert_type::<u32>();
-    /// het_map.insert(Key::new(1_usize), 2_i32);
-    /// het_map.insert(Key::new(2_usize), 3_i32);
-    ///
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), None);
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    /// assert!(!het_map.contains_type::<f32>());
-    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
-    /// {
-    ///     let entry = het_map.entry_or_insert_type::<f32>(Key::new(1_usize));
-    ///     entry.or_insert(f32::MAX);
+    /// for value in het_map.as_mut_slice::<i32>().unwrap()[1..].values_mut() {
+    ///     *value *= 10_i32;
     /// }
-    /// assert_eq!(het_map.len::<i32>(), Some(2));
-    /// assert_eq!(het_map.len::<u32>(), Some(0));
-    /// assert_eq!(het_map.len::<f32>(), Some(1));
     ///
-    /// assert!(het_map.contains_type::<f32>());
-    /// assert!(het_map.contains_key::<f32, _>(&1_usize));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new("foo")), Some(&1_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new("bar")), Some(&20_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new("baz")), Some(&30_i32));
     ///
-    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
-    /// assert_eq!(het_map.get::<f32, _>(&1_usize), Some(&f32::MAX));
+    /// assert!(het_map.as_mut_slice::<u64>().is_none());
     /// ```
-    #[inline]
-    pub fn entry_or_insert_type<T>(&mut self, key: Key<K, T>) -> Entry<'_, K, T>
+    pub fn as_mut_slice<T>(&mut self) -> Option<&mut Slice<K, T>>
     where
-        K: hash::Hash + Eq,
         T: any::Any,
     {
-        let map = self.get_or_insert_map_mut::<T>();
+        let map = self.get_map_mut_untracked::<T>()?;
 
-        map.entry(key)
+        Some(map.as_mut_slice())
     }
-}
 
-impl<K, S> HeterogeneousHashMap<K, S>
-where
-    K: any::Any,
-    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
-    S::Hasher: any::Any + hash::Hasher + Send + Sync,
-{
-    /// Returns a reference to the value of the given type with the given key, if it exists
-    /// in the heterogeneous hash map.
+    /// Returns a slice of the entries of a given type in the given range of storage indices, if
+    /// that type exists in the heterogeneous hash map and the range is in bounds.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map, or the starting
+    /// point of the range is greater than the end point, or the end point is greater than
+    /// `self.len::<T>()`.
     ///
     /// # Complexity Characteristics
     ///
     /// This method runs in **O(1)** time.
     ///
-    /// # Panics
-    ///
-    /// This method panics if the given type `T` does not exist in the map, or the type exists,
-    /// but a key equivalent to the key `key` does not exist in the map.
-    ///
     /// # Examples
     ///
     /// ```
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert(Key::new(1_usize), i32::MAX);
-    ///
-    /// assert_eq!(het_map.get_unchecked::<i32, _>(&Key::new(1_usize)), &i32::MAX);
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let slice = het_map.get_range::<i32, _>(1..3).unwrap();
+    ///
+    /// assert_eq!(slice.len(), 2);
+    /// assert_eq!(slice.get_index(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(slice.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    ///
+    /// assert!(het_map.get_range::<i32, _>(3..5).is_none());
+    /// assert!(het_map.get_range::<u64, _>(..).is_none());
     /// ```
-    #[track_caller]
-    pub fn get_unchecked<T, Q>(&self, key: &Q) -> &T
+    pub fn get_range<T, R>(&self, range: R) -> Option<&Slice<K, T>>
     where
         T: any::Any,
-        Key<K, T>: Borrow<Q>,
-        Q: any::Any + hash::Hash + Eq + ?Sized,
+        R: ops::RangeBounds<usize>,
     {
-        let map = self.get_map_unchecked::<T>();
+        let map = self.get_map::<T>()?;
 
-        map.get_unchecked(key)
+        map.get_range(range)
     }
 
-    /// Returns a mutable reference to the value of the given type with the given key, if it exists
-    /// in the heterogeneous hash map.
+    /// Inserts a new key-value pair of a given type with a given key into the heterogeneous hash
+    /// map.
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in **O(1)** time.
-    ///
-    /// # Panics
-    ///
-    /// This method panics if the given type `T` does not exist in the map, or the type exists,
-    /// but a key equivalent to the key `key` does not exist in the map.
+    /// This method runs in amortized **O(1)** time.
     ///
     /// # Examples
     ///
@@ -2429,444 +3971,2417 @@ where
     /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
     /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert(Key::new(1_usize), i32::MAX);
     ///
-    /// assert_eq!(het_map.get_mut_unchecked::<i32, _>(&Key::new(1_usize)), &i32::MAX);
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    ///
+    /// het_map.insert(Key::new(1_usize), 5_i32);
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
     /// ```
-    #[track_caller]
-    pub fn get_mut_unchecked<T, Q>(&mut self, key: &Q) -> &mut T
+    pub fn insert<T>(&mut self, key: Key<K, T>, value: T) -> Option<T>
     where
+        K: hash::Hash + Eq,
         T: any::Any,
-        Key<K, T>: Borrow<Q>,
-        Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        let map = self.get_map_mut_unchecked::<T>();
+        self.insert_signature::<T>(key.id());
+        let map = self.get_or_insert_hashed_map_mut::<T>();
 
-        map.get_mut_unchecked(key)
+        map.insert(key, value)
     }
-}
 
-impl<K, S> HeterogeneousHashMap<K, S>
-where
-    K: any::Any + hash::Hash + Eq,
-    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
-    S::Hasher: any::Any + hash::Hasher + Send + Sync,
-{
-    /// Inserts one or more values of the given type to the heterogeneous hash map from an
-    /// iterable.
+    /// Tries to insert a new key-value pair of a given type with a given key into the
+    /// heterogeneous hash map.
     ///
-    /// If the given type does not exist in the map when this method is called, this method
-    /// inserts the type into the map.
+    /// This method is the fallible counterpart of [`insert`]. If the given type `T` does not
+    /// exist in the map when this method is called, this method inserts the type into the map.
     ///
-    /// # Examples
+    /// This method returns `Ok(Some(old_value))`, where `old_value` is the value previously
+    /// associated with the key `key`, if an equivalent key to `key` exists in the map. This
+    /// method returns `Ok(None)` otherwise.
     ///
-    /// ```
-    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
-    /// #
-    /// let mut het_map = HeterogeneousHashMap::new();
+    /// [`insert`]: HeterogeneousHashMap::insert
     ///
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), None);
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), None);
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), None);
+    /// # Errors
     ///
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), None);
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), None);
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), None);
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure. If an error occurs, the
+    /// key-value pair is not inserted into the map.
     ///
-    /// het_map.extend([
-    ///     (Key::new(0_usize), 1_i32),
-    ///     (Key::new(1_usize), 2_i32),
-    ///     (Key::new(2_usize), 3_i32),
-    /// ]);
+    /// # Complexity Characteristics
     ///
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    /// This method runs in amortized **O(1)** time.
     ///
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), None);
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), None);
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), None);
+    /// # Examples
     ///
-    /// het_map.extend([
-    ///     (Key::new(0_usize), String::from("foo")),
-    ///     (Key::new(3_usize), String::from("bar")),
-    ///     (Key::new(5_usize), String::from("baz")),
-    /// ]);
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
     ///
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
-    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    /// assert_eq!(het_map.try_insert(Key::new(1_usize), 5_i32), Ok(None));
+    /// assert_eq!(het_map.try_insert(Key::new(1_usize), 7_i32), Ok(Some(5_i32)));
     ///
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), Some(&String::from("foo")));
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), Some(&String::from("bar")));
-    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), Some(&String::from("baz")));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&7_i32));
     /// ```
-    #[inline]
-    pub fn extend<I, T>(&mut self, iterable: I)
+    pub fn try_insert<T>(&mut self, key: Key<K, T>, value: T) -> Result<Option<T>, TryReserveError>
     where
+        K: hash::Hash + Eq,
         T: any::Any,
-        I: IntoIterator<Item = (Key<K, T>, T)>,
     {
-        let map = self.get_or_insert_map_mut::<T>();
+        self.try_get_or_insert_map_mut::<T>()?;
+        let map = self.get_hashed_map_mut::<T>().unwrap();
+        if !map.contains_key(&key) {
+            map.try_reserve(1)?;
+        }
+        if let Some(index) = self.signatures.as_mut() {
+            index.try_reserve(1)?;
+        }
+        self.insert_signature::<T>(key.id());
+        let map = self.get_map_mut_untracked::<T>().unwrap();
 
-        map.extend(iterable)
+        Ok(map.insert(key, value))
     }
-}
 
-impl<K, S> HeterogeneousHashMap<K, S>
-where
-    K: any::Any,
-    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
-    S::Hasher: any::Any + hash::Hasher + Send + Sync,
-{
-    /// Returns an iterator over the entries of a given type, if that type exists in
-    /// the heterogeneous hash map.
+    /// Removes an entry from a heterogeneous hash map of the given type with the given key, if it
+    /// exists.
+    ///
+    /// This method returns `Some(value)`, where `value` is the value associated with the
+    /// equivalent key to the key `key` if it exists in the map. If an equivalent key to `key`
+    /// does not exist in the map, this method returns `None`.
+    ///
+    /// This method is equivalent to [`swap_remove`], so it does not preserve the storage order of
+    /// the remaining entries of type `T`. Use [`shift_remove`] to preserve the storage order
+    /// instead.
+    ///
+    /// [`swap_remove`]: HeterogeneousHashMap::swap_remove
+    /// [`shift_remove`]: HeterogeneousHashMap::shift_remove
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
-    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    /// struct Equipment(String);
-    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    /// struct Consumable(String);
+    /// let mut het_map = HeterogeneousHashMap::new();
     ///
-    /// let equipment = [
-    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
-    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
-    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
-    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
-    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
-    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
-    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
-    /// ];
-    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<Equipment>();
-    /// het_map.insert_type::<Consumable>();
-    /// het_map.extend(equipment.clone());
-    /// let expected = {
-    ///     let mut _expected = Vec::from_iter(equipment.clone());
-    ///     _expected.sort();
-    ///     _expected
-    /// };
-    /// let result = {
-    ///     let mut _result: Vec<(Key<&str, Equipment>, Equipment)> = het_map
-    ///         .iter::<Equipment>()
-    ///         .unwrap()
-    ///         .map(|(k, v)| (k.clone(), v.clone()))
-    ///         .collect();
-    ///     _result.sort();
-    ///     _result
-    /// };
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(2_usize)));
     ///
-    /// assert_eq!(result, expected);
+    /// het_map.insert(Key::new(1_usize), 3_i32);
+    /// het_map.insert(Key::new(2_usize), 5_i32);
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    ///
+    /// assert_eq!(het_map.remove::<i32, _>(&Key::new(1_usize)), Some(3_i32));
+    ///
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
     /// ```
-    pub fn iter<T>(&self) -> Option<Iter<'_, K, T>>
+    pub fn remove<T, Q>(&mut self, key: &Q) -> Option<T>
     where
         T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        self.get_map::<T>().map(HomogeneousHashMap::iter)
+        self.remove_entry::<T, Q>(key).map(|(_, value)| value)
     }
 
-    /// Returns a mutable iterator over the entries of a given type, if that type exists in
-    /// the heterogeneous hash map.
+    /// Removes the value with the given key for the type with the given [`TypeId`] from a
+    /// heterogeneous hash map, and returns it boxed, if it exists.
+    ///
+    /// This method is the runtime-typed counterpart of [`remove`], for callers that only know the
+    /// type identifier of a type at runtime. The returned box can be downcast to the concrete
+    /// type with [`Box::downcast`]. This method returns `None` if the type with the given type
+    /// identifier does not exist in the map, or has no value stored under the key `key`. The type
+    /// remains in the map even if its last value is removed.
+    ///
+    /// Like [`remove`], this method does not preserve the storage order of the remaining entries
+    /// of the type.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    /// [`remove`]: HeterogeneousHashMap::remove
+    /// [`Box::downcast`]: alloc_crate::boxed::Box::downcast
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time if the key lookup of the type was registered
+    /// by inserting its values with a method such as [`insert`], and in **O(n)** time otherwise,
+    /// where `n` is the number of values of the type.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
-    /// # use core::borrow::Borrow;
-    /// #
-    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    /// struct Equipment(String);
-    /// #
-    /// # impl Borrow<String> for Equipment {
-    /// #     fn borrow(&self) -> &String {
-    /// #         &self.0
-    /// #     }
-    /// # }
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::any::TypeId;
     /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    /// ]);
+    /// het_map.insert(Key::new(0_usize), String::from("foo"));
     ///
-    /// let equipment = [
-    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
-    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
-    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
-    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
-    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
-    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
-    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
-    /// ];
-    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<Equipment>();
-    /// het_map.extend(equipment.clone());
-    ///
-    /// // Endgame setup. Who needs Richter anyway?
-    /// assert_eq!(het_map.get::<Equipment, _>(&"right_hand"),  Some(&Equipment(String::from("Alucard Sword"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"left_hand"),   Some(&Equipment(String::from("Alucard Shield"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"body"),        Some(&Equipment(String::from("Alucard Mail"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"cloak"),       Some(&Equipment(String::from("Twilight Cloak"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"head"),        Some(&Equipment(String::from("Dragon Helm"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_1"), Some(&Equipment(String::from("Ring Of Varda"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_2"), Some(&Equipment(String::from("Ring Of Varda"))));
-    ///
-    /// // Activate walking simulator mode.
-    /// for (key, value) in het_map.iter_mut::<Equipment>().unwrap() {
-    ///     if (key == &Key::new("right_hand")) || (key == &Key::new("left_hand")) {
-    ///         *value = Equipment(String::from("Crissaegrim"));
-    ///     }
-    /// }
+    /// let value = het_map.remove_dyn(TypeId::of::<String>(), &0_usize).unwrap();
     ///
-    /// // Walking simulator mode activated. DPS too high for actual gameplay.
-    /// assert_eq!(het_map.get::<Equipment, _>(&"right_hand"),  Some(&Equipment(String::from("Crissaegrim"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"left_hand"),   Some(&Equipment(String::from("Crissaegrim"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"body"),        Some(&Equipment(String::from("Alucard Mail"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"cloak"),       Some(&Equipment(String::from("Twilight Cloak"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"head"),        Some(&Equipment(String::from("Dragon Helm"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_1"), Some(&Equipment(String::from("Ring Of Varda"))));
-    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_2"), Some(&Equipment(String::from("Ring Of Varda"))));
+    /// assert_eq!(value.downcast::<String>().ok().as_deref().map(String::as_str), Some("foo"));
+    /// assert_eq!(het_map.len::<String>(), Some(0));
+    /// assert!(het_map.remove_dyn(TypeId::of::<String>(), &0_usize).is_none());
+    /// assert!(het_map.remove_dyn(TypeId::of::<f64>(), &0_usize).is_none());
     /// ```
-    pub fn iter_mut<T>(&mut self) -> Option<IterMut<'_, K, T>>
+    pub fn remove_dyn(&mut self, type_id: any::TypeId, key: &K) -> Option<Box<dyn any::Any>>
     where
-        T: any::Any,
+        K: Eq,
     {
-        self.get_map_mut::<T>().map(HomogeneousHashMap::iter_mut)
+        let map = self.map.get_mut(&type_id)?;
+        let vtable = self
+            .vtables
+            .get(&type_id)
+            .expect("Every stored type must have a registered vtable");
+        let value = vtable.remove_value(map, key)?;
+        if let Some(index) = self.signatures.as_mut() {
+            index.remove(type_id, key);
+        }
+
+        Some(value)
     }
 
-    /// Returns a key iterator over the entries of a given type, if that type exists in
-    /// the heterogeneous hash map.
+    /// Removes the value stored under the given key from the storage of every type in the
+    /// heterogeneous hash map.
+    ///
+    /// This method works through the type-erased storage of each type, so it removes the key
+    /// from every registered type, including types the caller does not name. This method returns
+    /// the number of values removed, which is the number of types that had a value stored under
+    /// the key `key`. Every type remains in the map even if its last value is removed.
+    ///
+    /// Like [`remove`], this method does not preserve the storage order of the remaining entries
+    /// of each type.
+    ///
+    /// [`remove`]: HeterogeneousHashMap::remove
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(t)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, if the key lookup of every type was registered by inserting its
+    /// values with a method such as [`insert`]. Each type without a registered key lookup is
+    /// searched with a linear scan over its keys, so this method runs in **O(t + m)** time in
+    /// general, where `m` is the total number of values of the types without a registered key
+    /// lookup.
+    ///
+    /// [`insert`]: HeterogeneousHashMap::insert
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
-    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    /// struct Equipment(String);
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([(Key::new(1_usize), 1_i32), (Key::new(2_usize), 2_i32)]);
+    /// het_map.extend([(Key::new(1_usize), 1.5_f64), (Key::new(3_usize), 3.5_f64)]);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
     ///
-    /// let equipment = [
-    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
-    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
-    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
-    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
-    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
-    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
+    /// assert_eq!(het_map.remove_key_everywhere(&1_usize), 3);
+    /// assert_eq!(het_map.count_types_for_key(&1_usize), 0);
+    /// assert_eq!(het_map.len::<i32>(),    Some(1));
+    /// assert_eq!(het_map.len::<f64>(),    Some(1));
+    /// assert_eq!(het_map.len::<String>(), Some(0));
+    ///
+    /// assert_eq!(het_map.remove_key_everywhere(&1_usize), 0);
+    /// ```
+    pub fn remove_key_everywhere(&mut self, key: &K) -> usize
+    where
+        K: Eq,
+    {
+        let mut removed = 0;
+        for (type_id, map) in self.map.iter_mut() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            if vtable.remove_key(map, key) {
+                if let Some(index) = self.signatures.as_mut() {
+                    index.remove(*type_id, key);
+                }
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Removes the values stored under each of the given keys from the storage of every type in
+    /// the heterogeneous hash map.
+    ///
+    /// This method is equivalent to calling [`remove_key_everywhere`] once for each key in
+    /// `keys`. This method returns the total number of values removed.
+    ///
+    /// [`remove_key_everywhere`]: HeterogeneousHashMap::remove_key_everywhere
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(k * t)** time, where `k` is the number of keys in `keys`,
+    /// and `t` is the number of types in the heterogeneous hash map, if the key lookup of every
+    /// type was registered by inserting its values with a method such as [`insert`]. Each type
+    /// without a registered key lookup is searched with a linear scan over its keys for every key,
+    /// so this method runs in **O(k * (t + m))** time in general, where `m` is the total number of
+    /// values of the types without a registered key lookup.
+    ///
+    /// [`insert`]: HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([(Key::new(1_usize), 1_i32), (Key::new(2_usize), 2_i32)]);
+    /// het_map.extend([(Key::new(1_usize), 1.5_f64), (Key::new(3_usize), 3.5_f64)]);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// assert_eq!(het_map.remove_keys_everywhere([1_usize, 3_usize]), 4);
+    /// assert_eq!(het_map.len::<i32>(),    Some(1));
+    /// assert_eq!(het_map.len::<f64>(),    Some(0));
+    /// assert_eq!(het_map.len::<String>(), Some(0));
+    /// ```
+    pub fn remove_keys_everywhere<I>(&mut self, keys: I) -> usize
+    where
+        K: Eq,
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        keys.into_iter().map(|key| self.remove_key_everywhere(key.borrow())).sum()
+    }
+
+    /// Retains only the entries whose keys satisfy the given predicate, in the storage of every
+    /// type in the heterogeneous hash map.
+    ///
+    /// This method removes every entry, of every type, whose key `k` makes `keep(&k)` return
+    /// `false`. The predicate is called once per stored entry, so a key shared across several
+    /// types is passed to the predicate once for each type. This method preserves the storage
+    /// order of the remaining entries of each type. Every type remains in the map even if its
+    /// last value is removed.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is the total number of values stored in the
+    /// heterogeneous hash map, plus the number of types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend([(Key::new(1_usize), 1_i32), (Key::new(2_usize), 2_i32)]);
+    /// het_map.extend([(Key::new(1_usize), 1.5_f64), (Key::new(3_usize), 3.5_f64)]);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// het_map.retain_keys(|key| *key != 1_usize);
+    ///
+    /// assert_eq!(het_map.count_types_for_key(&1_usize), 0);
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&2_i32));
+    /// assert_eq!(het_map.get::<f64, _>(&Key::new(3_usize)), Some(&3.5_f64));
+    /// assert_eq!(het_map.len::<String>(), Some(0));
+    /// ```
+    pub fn retain_keys<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let signatures = &mut self.signatures;
+        for (type_id, map) in self.map.iter_mut() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let mut keep_erased = |key: &dyn any::Any| {
+                let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+                let kept = keep(key);
+                if let Some(index) = signatures.as_mut().filter(|_| !kept) {
+                    index.remove(*type_id, key);
+                }
+
+                kept
+            };
+            vtable.retain_keys(map, &mut keep_erased);
+        }
+    }
+
+    /// Removes an entry from a heterogeneous hash map of the given type with the given key, if it
+    /// exists.
+    ///
+    /// This method returns `Some((eq_key, value))`, where `eq_key` is equivalent key to the key
+    /// `key` in the map, and `value` is the value associated with `eq_key`, if an equivalent key
+    /// to the key `key` exists in the map. If an equivalent key to `key` does not exist in the map,
+    /// this method returns `None`.
+    ///
+    /// This method is equivalent to [`swap_remove_entry`], so it does not preserve the storage
+    /// order of the remaining entries of type `T`. Use [`shift_remove_entry`] to preserve the
+    /// storage order instead.
+    ///
+    /// [`swap_remove_entry`]: HeterogeneousHashMap::swap_remove_entry
+    /// [`shift_remove_entry`]: HeterogeneousHashMap::shift_remove_entry
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    ///
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    ///
+    /// het_map.insert(Key::new(1_usize), 3_i32);
+    /// het_map.insert(Key::new(2_usize), 5_i32);
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    ///
+    /// assert_eq!(het_map.remove_entry::<i32, _>(&Key::new(1_usize)), Some((Key::new(1_usize), 3_i32)));
+    ///
+    /// assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    /// assert!(het_map.contains_key::<i32, _>(&Key::new(2_usize)));
+    /// ```
+    pub fn remove_entry<T, Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.remove_entry(key)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// swapping it with the last entry of that type, if it exists.
+    ///
+    /// This method returns `Some(value)`, where `value` is the value associated with the
+    /// equivalent key to the key `key`, if it exists in the map. This method returns `None`
+    /// otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by moving the last entry of type `T` into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.swap_remove::<i32, _>(&Key::new("bar")), Some(2_i32));
+    /// assert_eq!(het_map.swap_remove::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.swap_remove::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn swap_remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.swap_remove_entry::<T, Q>(key).map(|(_, value)| value)
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// swapping it with the last entry of that type, if it exists.
+    ///
+    /// This method returns `Some((eq_key, value))`, where `eq_key` is the equivalent key to the
+    /// key `key`, and `value` is the value associated with `eq_key`, if it exists in the map. This
+    /// method returns `None` otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by moving the last entry of type `T` into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.swap_remove_entry::<i32, _>(&Key::new("bar")), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(het_map.swap_remove_entry::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.swap_remove_entry::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn swap_remove_entry<T, Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.swap_remove_entry(key)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// swapping it with the last entry of that type, if it exists.
+    ///
+    /// This method returns `Some((index, eq_key, value))`, where `index` is the storage index the
+    /// entry had among the entries of type `T`, `eq_key` is the equivalent key to the key `key`,
+    /// and `value` is the value associated with `eq_key`, if it exists in the map. This method
+    /// returns `None` otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by moving the last entry of type `T` into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.swap_remove_full::<i32, _>(&Key::new("bar")), Some((1, Key::new("bar"), 2_i32)));
+    /// assert_eq!(het_map.swap_remove_full::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.swap_remove_full::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn swap_remove_full<T, Q>(&mut self, key: &Q) -> Option<(usize, Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let (index, key, value) = self.get_map_mut_untracked::<T>()?.swap_remove_full(key)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((index, key, value))
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// shifting every following entry of that type, if it exists.
+    ///
+    /// This method returns `Some(value)`, where `value` is the value associated with the
+    /// equivalent key to the key `key`, if it exists in the map. This method returns `None`
+    /// otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by shifting every following entry of type `T` down by one
+    /// position, so it preserves the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.shift_remove::<i32, _>(&Key::new("bar")), Some(2_i32));
+    /// assert_eq!(het_map.shift_remove::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.shift_remove::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    pub fn shift_remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.shift_remove_entry::<T, Q>(key).map(|(_, value)| value)
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// shifting every following entry of that type, if it exists.
+    ///
+    /// This method returns `Some((eq_key, value))`, where `eq_key` is the equivalent key to the
+    /// key `key`, and `value` is the value associated with `eq_key`, if it exists in the map. This
+    /// method returns `None` otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by shifting every following entry of type `T` down by one
+    /// position, so it preserves the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.shift_remove_entry::<i32, _>(&Key::new("bar")), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(het_map.shift_remove_entry::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.shift_remove_entry::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    pub fn shift_remove_entry<T, Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.shift_remove_entry(key)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+    /// Removes an entry of the given type with the given key from a heterogeneous hash map by
+    /// shifting every following entry of that type, if it exists.
+    ///
+    /// This method returns `Some((index, eq_key, value))`, where `index` is the storage index the
+    /// entry had among the entries of type `T`, `eq_key` is the equivalent key to the key `key`,
+    /// and `value` is the value associated with `eq_key`, if it exists in the map. This method
+    /// returns `None` otherwise, including when the type `T` does not exist in the map.
+    ///
+    /// This method removes the entry by shifting every following entry of type `T` down by one
+    /// position, so it preserves the storage order of the remaining entries of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.shift_remove_full::<i32, _>(&Key::new("bar")), Some((1, Key::new("bar"), 2_i32)));
+    /// assert_eq!(het_map.shift_remove_full::<i32, _>(&Key::new("bar")), None);
+    /// assert_eq!(het_map.shift_remove_full::<u64, _>(&Key::new("foo")), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    pub fn shift_remove_full<T, Q>(&mut self, key: &Q) -> Option<(usize, Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let (index, key, value) = self.get_map_mut_untracked::<T>()?.shift_remove_full(key)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((index, key, value))
+    }
+
+    /// Removes the last key-value pair of a given type in storage order from the heterogeneous
+    /// hash map, and returns it, if it exists.
+    ///
+    /// This method returns `None` if the type `T` does not exist in the map, or the map contains
+    /// no values of type `T`. This method preserves the storage order of the remaining entries
+    /// of type `T`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.pop::<i32>(), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(het_map.pop::<i32>(), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(het_map.pop::<i32>(), None);
+    /// assert_eq!(het_map.pop::<u64>(), None);
+    ///
+    /// // The type remains in the map after all of its values are removed.
+    /// assert!(het_map.contains_type::<i32>());
+    /// ```
+    pub fn pop<T>(&mut self) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.pop()?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+    /// Removes the key-value pair of a given type stored at the given storage index from the
+    /// heterogeneous hash map, and returns it, if it exists.
+    ///
+    /// This method moves the last entry of type `T` into the vacated position, so it does not
+    /// preserve the storage order of the remaining entries of type `T`. This method returns
+    /// `None` if the type `T` does not exist in the map, or `index >= self.len::<T>()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.swap_remove_index::<i32>(0), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(het_map.swap_remove_index::<i32>(5), None);
+    /// assert_eq!(het_map.swap_remove_index::<u64>(0), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("bar"), &2_i32)));
+    /// ```
+    pub fn swap_remove_index<T>(&mut self, index: usize) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.swap_remove_index(index)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+    /// Removes the key-value pair of a given type stored at the given storage index from the
+    /// heterogeneous hash map, and returns it, if it exists.
+    ///
+    /// This method shifts every entry of type `T` after the removed one down by one position, so
+    /// it preserves the storage order of the remaining entries of type `T`. This method returns
+    /// `None` if the type `T` does not exist in the map, or `index >= self.len::<T>()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.shift_remove_index::<i32>(0), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(het_map.shift_remove_index::<i32>(5), None);
+    /// assert_eq!(het_map.shift_remove_index::<u64>(0), None);
+    ///
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    pub fn shift_remove_index<T>(&mut self, index: usize) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        let (key, value) = self.get_map_mut_untracked::<T>()?.shift_remove_index(index)?;
+        self.remove_signature::<T>(key.id());
+
+        Some((key, value))
+    }
+
+
+    /// Returns the entry in the heterogeneous hash map corresponding to the given key, if it
+    /// exists.
+    ///
+    /// The resulting entry can be queried or manipulated directly, instead of going through the
+    /// heterogeneous hash map to do it.
+    ///
+    /// If the type `T` exists in the heterogeneous hash map, this method returns `Some(entry)`
+    /// where `entry` is an entry of value type `T`. If the type `T` does not exist in the
+    /// heterogeneous hash map, this method returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// Accessing an existing type in a heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u32>();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    /// #
+    ///
+    /// // Accessing existing entries in the heterogeneous hash map.
+    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
+    /// {
+    ///     let maybe_entry = het_map.entry::<i32>(Key::new(1_usize));
+    ///     assert!(maybe_entry.is_some());
+    ///     let entry = maybe_entry.unwrap();
+    ///     assert_eq!(entry.key(), &Key::new(1_usize));
+    ///     assert_eq!(entry.or_default(), &mut 2_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(2));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    /// #
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
+    /// {
+    ///     let maybe_entry = het_map.entry::<i32>(Key::new(2_usize));
+    ///     assert!(maybe_entry.is_some());
+    ///     let entry = maybe_entry.unwrap();
+    ///     assert_eq!(entry.key(), &Key::new(2_usize));
+    ///     assert_eq!(entry.or_default(), &mut 3_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(2));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    ///
+    /// // Creating a new entry if the type exists with `or_default`.
+    /// assert!(!het_map.contains_key::<i32, _>(&3_usize));
+    /// {
+    ///     let maybe_entry = het_map.entry::<i32>(Key::new(3_usize));
+    ///     assert!(maybe_entry.is_some());
+    ///     let entry = maybe_entry.unwrap();
+    ///     assert_eq!(entry.key(), &Key::new(3_usize));
+    ///     assert_eq!(entry.or_default(), &mut i32::default());
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&3_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(3));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    ///
+    /// // Creating a new entry if the type exists with `or_insert`.
+    /// assert!(!het_map.contains_key::<i32, _>(&4_usize));
+    /// {
+    ///     let maybe_entry = het_map.entry::<i32>(Key::new(4_usize));
+    ///     assert!(maybe_entry.is_some());
+    ///     let entry = maybe_entry.unwrap();
+    ///     assert_eq!(entry.key(), &Key::new(4_usize));
+    ///     assert_eq!(entry.or_insert(5_i32), &mut 5_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&4_usize));
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(4));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
+    /// assert_eq!(het_map.get::<i32, _>(&4_usize), Some(&5_i32));
+    /// ```
+    ///
+    /// Attempting to get values for a type that does not exist in the heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u32>();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert!(!het_map.contains_type::<f32>());
+    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    ///
+    /// assert!(het_map.entry::<f32>(Key::new(1_usize)).is_none());
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert!(!het_map.contains_type::<f32>());
+    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    /// ```
+    #[inline]
+    pub fn entry<T>(&mut self, key: Key<K, T>) -> Option<Entry<'_, K, T, A>>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        self.mark_signature_stale(any::TypeId::of::<T>());
+        let map = self.get_hashed_map_mut::<T>()?;
+
+        Some(map.entry(key))
+    }
+
+    /// Returns the entry in the heterogeneous hash map corresponding to the given key.
+    ///
+    /// The resulting entry can be queried or manipulated directly, instead of going through the
+    /// heterogeneous hash map to do it.
+    ///
+    /// If the type `T` does not exist in the heterogeneous hash map, this method creates an
+    /// entry in the map for the type `T`, and returns a vacant entry for a value of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// Accessing an existing type in the heterogeneous hash map.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u32>();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    /// #
+    ///
+    /// // Accessing existing entries in the heterogeneous hash map.
+    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
+    /// {
+    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(1_usize));
+    ///     assert_eq!(entry.key(), &Key::new(1_usize));
+    ///     assert_eq!(entry.or_default(), &mut 2_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&1_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(2));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    /// #
+    ///
+    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
+    /// {
+    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(2_usize));
+    ///     assert_eq!(entry.key(), &Key::new(2_usize));
+    ///     assert_eq!(entry.or_default(), &mut 3_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&2_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(2));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), None);
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    ///
+    /// // Creating a new entry if the type exists with `or_default`.
+    /// assert!(!het_map.contains_key::<i32, _>(&3_usize));
+    /// {
+    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(3_usize));
+    ///     assert_eq!(entry.key(), &Key::new(3_usize));
+    ///     assert_eq!(entry.or_default(), &mut i32::default());
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&3_usize));
+    /// #
+    /// # assert_eq!(het_map.len::<i32>(), Some(3));
+    /// # assert_eq!(het_map.len::<u32>(), Some(0));
+    /// # assert_eq!(het_map.len::<f32>(), None);
+    /// #
+    /// # assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// # assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
+    /// # assert_eq!(het_map.get::<i32, _>(&4_usize), None);
+    ///
+    /// // Creating a new entry if the type exists with `or_insert`.
+    /// assert!(!het_map.contains_key::<i32, _>(&4_usize));
+    /// {
+    ///     let entry = het_map.entry_or_insert_type::<i32>(Key::new(4_usize));
+    ///     assert_eq!(entry.key(), &Key::new(4_usize));
+    ///     assert_eq!(entry.or_insert(5_i32), &mut 5_i32);
+    /// }
+    /// assert!(het_map.contains_key::<i32, _>(&4_usize));
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(4));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&i32::default()));
+    /// assert_eq!(het_map.get::<i32, _>(&4_usize), Some(&5_i32));
+    /// ```
+    ///
+    /// Accessing a type that does not exist without inserting new entries inserts the new type.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u32>();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert!(!het_map.contains_type::<f32>());
+    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    /// {
+    ///     let _ = het_map.entry_or_insert_type::<f32>(Key::new(1_usize));
+    /// }
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), Some(0));
+    ///
+    /// assert!(het_map.contains_type::<f32>());
+    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    /// ```
+    ///
+    /// Accessing a type that does not exist and inserting new entries for it.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    /// het_map.insert_type::<u32>();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), None);
+    ///
+    /// assert!(!het_map.contains_type::<f32>());
+    /// assert!(!het_map.contains_key::<f32, _>(&1_usize));
+    /// {
+    ///     let entry = het_map.entry_or_insert_type::<f32>(Key::new(1_usize));
+    ///     entry.or_insert(f32::MAX);
+    /// }
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<u32>(), Some(0));
+    /// assert_eq!(het_map.len::<f32>(), Some(1));
+    ///
+    /// assert!(het_map.contains_type::<f32>());
+    /// assert!(het_map.contains_key::<f32, _>(&1_usize));
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<f32, _>(&1_usize), Some(&f32::MAX));
+    /// ```
+    #[inline]
+    pub fn entry_or_insert_type<T>(&mut self, key: Key<K, T>) -> Entry<'_, K, T, A>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        self.mark_signature_stale(any::TypeId::of::<T>());
+        let map = self.get_or_insert_hashed_map_mut::<T>();
+
+        map.entry(key)
+    }
+}
+
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Returns a reference to the value of the given type with the given key, if it exists
+    /// in the heterogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the given type `T` does not exist in the map, or the type exists,
+    /// but a key equivalent to the key `key` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), i32::MAX);
+    ///
+    /// assert_eq!(het_map.get_unchecked::<i32, _>(&Key::new(1_usize)), &i32::MAX);
+    /// ```
+    #[track_caller]
+    pub fn get_unchecked<T, Q>(&self, key: &Q) -> &T
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let map = self.get_map_unchecked::<T>();
+
+        map.get_unchecked(key)
+    }
+
+    /// Returns a mutable reference to the value of the given type with the given key, if it exists
+    /// in the heterogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the given type `T` does not exist in the map, or the type exists,
+    /// but a key equivalent to the key `key` does not exist in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), i32::MAX);
+    ///
+    /// assert_eq!(het_map.get_mut_unchecked::<i32, _>(&Key::new(1_usize)), &i32::MAX);
+    /// ```
+    #[track_caller]
+    pub fn get_mut_unchecked<T, Q>(&mut self, key: &Q) -> &mut T
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        let map = self.get_map_mut_unchecked_untracked::<T>();
+
+        map.get_mut_unchecked(key)
+    }
+}
+
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any + hash::Hash + Eq,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Inserts one or more values of the given type to the heterogeneous hash map from an
+    /// iterable.
+    ///
+    /// If the given type does not exist in the map when this method is called, this method
+    /// inserts the type into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), None);
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), None);
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), None);
+    ///
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), None);
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), None);
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), None);
+    ///
+    /// het_map.extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    ///     (Key::new(2_usize), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    ///
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), None);
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), None);
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), None);
+    ///
+    /// het_map.extend([
+    ///     (Key::new(0_usize), String::from("foo")),
+    ///     (Key::new(3_usize), String::from("bar")),
+    ///     (Key::new(5_usize), String::from("baz")),
+    /// ]);
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    ///
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(0_usize)), Some(&String::from("foo")));
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), Some(&String::from("bar")));
+    /// assert_eq!(het_map.get::<String, _>(&Key::new(5_usize)), Some(&String::from("baz")));
+    /// ```
+    #[inline]
+    pub fn extend<I, T>(&mut self, iterable: I)
+    where
+        T: any::Any,
+        I: IntoIterator<Item = (Key<K, T>, T)>,
+    {
+        let type_id = any::TypeId::of::<T>();
+        self.insert_type::<T>();
+        let mut signatures = self.signatures.take();
+        let map = self.get_hashed_map_mut::<T>().unwrap();
+        match signatures.as_mut() {
+            Some(index) => map.extend(iterable.into_iter().inspect(|(key, _)| index.insert(type_id, key.id()))),
+            None => map.extend(iterable),
+        }
+        self.signatures = signatures;
+    }
+
+    /// Tries to insert one or more values of the given type to the heterogeneous hash map from an
+    /// iterable.
+    ///
+    /// This method is the fallible counterpart of [`extend`]. If the given type does not exist in
+    /// the map when this method is called, this method inserts the type into the map.
+    ///
+    /// [`extend`]: HeterogeneousHashMap::extend
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure. If an error occurs, the
+    /// entries yielded by the iterable before the failure remain in the map, and the remaining
+    /// entries are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    ///
+    /// let result = het_map.try_extend([
+    ///     (Key::new(0_usize), 1_i32),
+    ///     (Key::new(1_usize), 2_i32),
+    ///     (Key::new(2_usize), 3_i32),
+    /// ]);
+    ///
+    /// assert!(result.is_ok());
+    /// assert_eq!(het_map.len::<i32>(), Some(3));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    /// ```
+    pub fn try_extend<I, T>(&mut self, iterable: I) -> Result<(), TryReserveError>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        I: IntoIterator<Item = (Key<K, T>, T)>,
+    {
+        let type_id = any::TypeId::of::<T>();
+        self.try_get_or_insert_map_mut::<T>()?;
+        let mut signatures = self.signatures.take();
+        let map = self.get_hashed_map_mut::<T>().unwrap();
+        let iterator = iterable.into_iter();
+        let (lower_bound, _) = iterator.size_hint();
+        let mut result = map.try_reserve(lower_bound);
+        if let Some(index) = signatures.as_mut().filter(|_| result.is_ok()) {
+            result = index.try_reserve(lower_bound);
+        }
+
+        if result.is_ok() {
+            for (key, value) in iterator {
+                if !map.contains_key(&key) {
+                    result = map.try_reserve(1);
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                if let Some(index) = signatures.as_mut() {
+                    result = index.try_reserve(1);
+                    if result.is_err() {
+                        break;
+                    }
+                    index.insert(type_id, key.id());
+                }
+
+                map.insert(key, value);
+            }
+        }
+        self.signatures = signatures;
+
+        result
+    }
+
+    /// Sorts the entries of a given type in the heterogeneous hash map by key.
+    ///
+    /// This method sorts the entries of type `T` in place in their storage order, so subsequent
+    /// iteration and positional access observe the entries of type `T` in key order. The entries
+    /// of every other type are left untouched. The sort is stable. If the type `T` does not exist
+    /// in the map, this method does nothing.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the number of values of type `T`
+    /// stored in the heterogeneous hash map, and `c` is the capacity for the type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new("foo"), 2_u64),
+    ///     (Key::new("bar"), 1_u64),
+    /// ]);
+    ///
+    /// het_map.sort_keys::<i32>();
+    ///
+    /// let keys: Vec<&str> = het_map.keys::<i32>().unwrap().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["bar", "baz", "foo", "quux"]);
+    ///
+    /// // The entries of every other type keep their order.
+    /// let keys: Vec<&str> = het_map.keys::<u64>().unwrap().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["foo", "bar"]);
+    /// ```
+    pub fn sort_keys<T>(&mut self)
+    where
+        K: Ord,
+        T: any::Any,
+    {
+        if let Some(map) = self.get_map_mut_untracked::<T>() {
+            map.sort_keys();
+        }
+    }
+
+    /// Sorts the entries of a given type in the heterogeneous hash map in place using the given
+    /// comparison function.
+    ///
+    /// The comparison function receives two key-value pairs of type `T` to compare. The entries
+    /// of every other type are left untouched. The sort is stable. If the type `T` does not exist
+    /// in the map, this method does nothing.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the number of values of type `T`
+    /// stored in the heterogeneous hash map, and `c` is the capacity for the type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new("foo"), 2_u64),
+    ///     (Key::new("bar"), 1_u64),
+    /// ]);
+    ///
+    /// het_map.sort_by::<i32, _>(|_k1, v1, _k2, v2| v1.cmp(v2));
+    ///
+    /// let values: Vec<i32> = het_map.values::<i32>().unwrap().copied().collect();
+    ///
+    /// assert_eq!(values, [1_i32, 2_i32, 3_i32, 4_i32]);
+    ///
+    /// // The entries of every other type keep their order.
+    /// let values: Vec<u64> = het_map.values::<u64>().unwrap().copied().collect();
+    ///
+    /// assert_eq!(values, [2_u64, 1_u64]);
+    /// ```
+    pub fn sort_by<T, F>(&mut self, cmp: F)
+    where
+        T: any::Any,
+        F: FnMut(&Key<K, T>, &T, &Key<K, T>, &T) -> cmp::Ordering,
+    {
+        if let Some(map) = self.get_map_mut_untracked::<T>() {
+            map.sort_by(cmp);
+        }
+    }
+}
+
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Returns an iterator over the entries of a given type, if that type exists in
+    /// the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Equipment(String);
+    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Consumable(String);
+    ///
+    /// let equipment = [
+    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
+    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
+    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
+    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
+    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
+    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
+    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
+    /// ];
+    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<Equipment>();
+    /// het_map.insert_type::<Consumable>();
+    /// het_map.extend(equipment.clone());
+    /// let expected = {
+    ///     let mut _expected = Vec::from_iter(equipment.clone());
+    ///     _expected.sort();
+    ///     _expected
+    /// };
+    /// let result = {
+    ///     let mut _result: Vec<(Key<&str, Equipment>, Equipment)> = het_map
+    ///         .iter::<Equipment>()
+    ///         .unwrap()
+    ///         .map(|(k, v)| (k.clone(), v.clone()))
+    ///         .collect();
+    ///     _result.sort();
+    ///     _result
+    /// };
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn iter<T>(&self) -> Option<Iter<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.get_map::<T>().map(HomogeneousHashMap::iter)
+    }
+
+    /// Returns a mutable iterator over the entries of a given type, if that type exists in
+    /// the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use core::borrow::Borrow;
+    /// #
+    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Equipment(String);
+    /// #
+    /// # impl Borrow<String> for Equipment {
+    /// #     fn borrow(&self) -> &String {
+    /// #         &self.0
+    /// #     }
+    /// # }
+    /// #
+    ///
+    /// let equipment = [
+    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
+    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
+    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
+    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
+    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
+    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
+    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
+    /// ];
+    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<Equipment>();
+    /// het_map.extend(equipment.clone());
+    ///
+    /// // Endgame setup. Who needs Richter anyway?
+    /// assert_eq!(het_map.get::<Equipment, _>(&"right_hand"),  Some(&Equipment(String::from("Alucard Sword"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"left_hand"),   Some(&Equipment(String::from("Alucard Shield"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"body"),        Some(&Equipment(String::from("Alucard Mail"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"cloak"),       Some(&Equipment(String::from("Twilight Cloak"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"head"),        Some(&Equipment(String::from("Dragon Helm"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_1"), Some(&Equipment(String::from("Ring Of Varda"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_2"), Some(&Equipment(String::from("Ring Of Varda"))));
+    ///
+    /// // Activate walking simulator mode.
+    /// for (key, value) in het_map.iter_mut::<Equipment>().unwrap() {
+    ///     if (key == &Key::new("right_hand")) || (key == &Key::new("left_hand")) {
+    ///         *value = Equipment(String::from("Crissaegrim"));
+    ///     }
+    /// }
+    ///
+    /// // Walking simulator mode activated. DPS too high for actual gameplay.
+    /// assert_eq!(het_map.get::<Equipment, _>(&"right_hand"),  Some(&Equipment(String::from("Crissaegrim"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"left_hand"),   Some(&Equipment(String::from("Crissaegrim"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"body"),        Some(&Equipment(String::from("Alucard Mail"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"cloak"),       Some(&Equipment(String::from("Twilight Cloak"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"head"),        Some(&Equipment(String::from("Dragon Helm"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_1"), Some(&Equipment(String::from("Ring Of Varda"))));
+    /// assert_eq!(het_map.get::<Equipment, _>(&"accessory_2"), Some(&Equipment(String::from("Ring Of Varda"))));
+    /// ```
+    pub fn iter_mut<T>(&mut self) -> Option<IterMut<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.get_map_mut_untracked::<T>().map(HomogeneousHashMap::iter_mut)
+    }
+
+    /// Returns a key iterator over the entries of a given type, if that type exists in
+    /// the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Equipment(String);
+    ///
+    /// let equipment = [
+    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
+    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
+    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
+    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
+    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
+    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
+    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
+    /// ];
+    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<Equipment>();
+    /// het_map.extend(equipment.clone());
+    ///
+    /// let expected = Vec::from([
+    ///     Key::new("accessory_1"),
+    ///     Key::new("accessory_2"),
+    ///     Key::new("body"),
+    ///     Key::new("cloak"),
+    ///     Key::new("head"),
+    ///     Key::new("left_hand"),
+    ///     Key::new("right_hand"),
+    /// ]);
+    /// let result = {
+    ///     let mut _result: Vec<Key<&str, Equipment>> = het_map
+    ///         .keys::<Equipment>()
+    ///         .unwrap()
+    ///         .cloned()
+    ///         .collect();
+    ///     _result.sort();
+    ///     _result
+    /// };
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn keys<T>(&self) -> Option<Keys<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.get_map::<T>().map(HomogeneousHashMap::keys)
+    }
+
+    /// Returns a value iterator over the entries of a given type, if that type exists in
+    /// the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Equipment(String);
+    ///
+    /// let equipment = [
+    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
+    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
+    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
+    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
+    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
+    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
     ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
     /// ];
-    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<Equipment>();
-    /// het_map.extend(equipment.clone());
+    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<Equipment>();
+    /// het_map.extend(equipment.clone());
+    ///
+    /// let expected = {
+    ///     let mut _expected = Vec::from_iter(equipment.iter().map(|(_k, v)| v).cloned());
+    ///     _expected.sort();
+    ///     _expected
+    /// };
+    /// let result = {
+    ///     let mut _result: Vec<Equipment> = het_map
+    ///         .values::<Equipment>()
+    ///         .unwrap()
+    ///         .cloned()
+    ///         .collect();
+    ///     _result.sort();
+    ///     _result
+    /// };
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn values<T>(&self) -> Option<Values<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.get_map::<T>().map(HomogeneousHashMap::values)
+    }
+
+    /// Returns a mutable value iterator over the entries of a given type, if that type exists in
+    /// the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// Comparing two different ways of determining which numbers in a collection of integers
+    /// are prime numbers.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// #[derive(Debug)]
+    /// struct PrimeFactors {
+    ///     value: i32,
+    ///     total_prime_factor_count: u32,
+    /// }
+    ///
+    /// impl PrimeFactors {
+    ///     fn new(value: i32, total_prime_factor_count: u32) -> Self {
+    ///         Self { value, total_prime_factor_count }
+    ///     }
+    /// }
+    ///
+    /// // Determines whether an integer is a prime.
+    /// fn is_prime(n: i32) -> bool {
+    ///     if n <= 1 { return false; }
+    ///     if n == 2 { return true; }
+    ///     if n % 2 == 0 { return false; }
+    ///     let sqrt_n = f64::floor(f64::sqrt(n as f64)) as i32;
+    ///     for i in (3..=sqrt_n).step_by(2) {
+    ///         if n % i == 0 { return false; }
+    ///     }
+    ///
+    ///     true
+    /// }
+    /// #
+    /// # assert!(is_prime(2_i32));
+    /// # assert!(is_prime(3_i32));
+    /// # assert!(!is_prime(4_i32));
+    /// # assert!(is_prime(5_i32));
+    /// # assert!(!is_prime(6_i32));
+    /// # assert!(is_prime(7_i32));
+    /// # assert!(!is_prime(8_i32));
+    /// # assert!(!is_prime(9_i32));
+    /// # assert!(!is_prime(10_i32));
+    /// # assert!(is_prime(11_i32));
+    /// # assert!(!is_prime(12_i32));
+    /// # assert!(is_prime(13_i32));
+    /// #
+    ///
+    /// // Counts the total number of prime factors for a given integer. Every integer has a
+    /// // unique prime factorization. The total number of prime factors is the length of this
+    /// // prime factorization.
+    /// fn count_total_prime_factors(value: i32) -> u32 {
+    ///     let mut n = value;
+    ///     let mut count = 0;
+    ///     while n % 2 == 0 {
+    ///         count += 1;
+    ///         n /= 2;
+    ///     }
+    ///     let mut i = 3;
+    ///     while i * i <= n {
+    ///         while n % i == 0 {
+    ///             count += 1;
+    ///             n /= i;
+    ///         }
+    ///         i += 2;
+    ///     }
+    ///     if n > 2 {
+    ///         count += 1;
+    ///     }
+    ///
+    ///     count
+    /// }
+    /// #
+    /// # assert_eq!(count_total_prime_factors(2_i32),  1_u32);
+    /// # assert_eq!(count_total_prime_factors(3_i32),  1_u32);
+    /// # assert_eq!(count_total_prime_factors(4_i32),  2_u32);
+    /// # assert_eq!(count_total_prime_factors(5_i32),  1_u32);
+    /// # assert_eq!(count_total_prime_factors(6_i32),  2_u32);
+    /// # assert_eq!(count_total_prime_factors(7_i32),  1_u32);
+    /// # assert_eq!(count_total_prime_factors(8_i32),  3_u32);
+    /// # assert_eq!(count_total_prime_factors(9_i32),  2_u32);
+    /// # assert_eq!(count_total_prime_factors(10_i32), 2_u32);
+    /// # assert_eq!(count_total_prime_factors(11_i32), 1_u32);
+    /// # assert_eq!(count_total_prime_factors(12_i32), 3_u32);
+    /// # assert_eq!(count_total_prime_factors(13_i32), 1_u32);
+    /// #
+    /// # for i in 2_i32..=1024_i32 {
+    /// #     if count_total_prime_factors(i) == 1 {
+    /// #         assert!(is_prime(i));
+    /// #     }
+    /// # }
+    /// #
+    /// # for i in 2_i32..=1024_i32 {
+    /// #     if is_prime(i) {
+    /// #         assert_eq!(count_total_prime_factors(i), 1);
+    /// #     }
+    /// # }
+    /// #
+    ///
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.extend(
+    ///     (1_usize..1024_usize)
+    ///         .map(Key::new)
+    ///         .zip((2_i32..=1024_i32).map(|v| PrimeFactors::new(v, 1_u32)))
+    /// );
+    ///
+    /// for factor in het_map.values::<PrimeFactors>().unwrap() {
+    ///     assert_eq!(factor.total_prime_factor_count, 1_u32);
+    /// }
+    ///
+    /// // Count the total number of (not necessarily unique) prime factors for every integer value.
+    /// for factor in het_map.values_mut::<PrimeFactors>().unwrap() {
+    ///     factor.total_prime_factor_count = count_total_prime_factors(factor.value);
+    /// }
+    ///
+    /// // Verify that every value for which `count_total_prime_factors` returns 1 for total prime
+    /// // factors is a prime number.
+    /// for factor in het_map.values::<PrimeFactors>().unwrap().filter(|f| f.total_prime_factor_count == 1) {
+    ///     assert!(is_prime(factor.value));
+    /// }
+    ///
+    /// // Verify that every value for which `count_total_prime_factors` does not return 1 for
+    /// // total prime factors is a prime number.
+    /// for factor in het_map.values::<PrimeFactors>().unwrap().filter(|f| f.total_prime_factor_count > 1) {
+    ///     assert!(!is_prime(factor.value));
+    /// }
+    /// ```
+    pub fn values_mut<T>(&mut self) -> Option<ValuesMut<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.get_map_mut_untracked::<T>().map(HomogeneousHashMap::values_mut)
+    }
+
+    /// Returns an iterator over the keys that have a value of every type in a tuple of types,
+    /// together with references to those values.
+    ///
+    /// For the tuple of types `(T1, T2, ..., Tn)`, the iterator yields an item
+    /// `(&key, &value1, &value2, ..., &valuen)` for every key that has a value of each type `Ti`.
+    /// The iteration is driven by the map of the type with the fewest values, and probes the maps
+    /// of the other types for each of its keys, so the keys are yielded in the storage order of
+    /// that map. If any type in the tuple does not exist in the map, the iterator is empty.
+    /// Tuples of up to twelve types are supported.
+    ///
+    /// Every type in the tuple is required. To also read the types that only some of the joined
+    /// keys have, use [`join_with`].
+    ///
+    /// [`join_with`]: HeterogeneousHashMap::join_with
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(m * n)** time, where `m` is the number of types in the
+    /// tuple, and `n` is the smallest number of values of any type in the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(i32, i32);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Velocity(i32, i32);
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Position(0, 0)),
+    ///     (Key::new(2_usize), Position(5, 5)),
+    ///     (Key::new(3_usize), Position(9, 9)),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Velocity(1, 2)),
+    ///     (Key::new(3_usize), Velocity(-1, 0)),
+    /// ]);
+    ///
+    /// let mut moving: Vec<(usize, i32)> = het_map
+    ///     .join::<(Position, Velocity)>()
+    ///     .map(|(key, position, velocity)| (*key, position.0 + velocity.0))
+    ///     .collect();
+    /// moving.sort();
+    ///
+    /// assert_eq!(moving, [(1, 1), (3, 8)]);
+    /// assert_eq!(het_map.join::<(Position, String)>().count(), 0);
+    /// ```
+    pub fn join<Types>(&self) -> impl Iterator<Item = Types::JoinItem<'_>> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        Types::join(self)
+    }
+
+    /// Returns an iterator over the keys that have a value of every type in a tuple of required
+    /// types, together with references to those values, and optional references to the values of
+    /// a tuple of optional types.
+    ///
+    /// For the tuple of required types `(T1, ..., Tn)` and the tuple of optional types
+    /// `(U1, ..., Um)`, the iterator yields an item
+    /// `((&key, &value1, ..., &valuen), (Option<&optional1>, ..., Option<&optionalm>))` for every
+    /// key that has a value of each type `Ti`, as in [`join`]. Each optional reference is `Some`
+    /// if the key has a value of the type `Uj`, and `None` otherwise. The optional types never
+    /// filter out a key, and an optional type that does not exist in the map yields `None` for
+    /// every key.
+    ///
+    /// [`join`]: HeterogeneousHashMap::join
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O((m + p) * n)** time, where `m` is the number of
+    /// required types, `p` is the number of optional types, and `n` is the smallest number of
+    /// values of any required type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(i32, i32);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Velocity(i32, i32);
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Position(0, 0)),
+    ///     (Key::new(2_usize), Position(5, 5)),
+    ///     (Key::new(3_usize), Position(9, 9)),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Velocity(1, 2)),
+    ///     (Key::new(3_usize), Velocity(-1, 0)),
+    /// ]);
+    ///
+    /// let mut positions: Vec<(usize, i32)> = het_map
+    ///     .join_with::<(Position,), (Velocity,)>()
+    ///     .map(|((key, position), (velocity,))| (*key, position.0 + velocity.map_or(0, |velocity| velocity.0)))
+    ///     .collect();
+    /// positions.sort();
+    ///
+    /// assert_eq!(positions, [(1, 1), (2, 5), (3, 8)]);
+    /// assert_eq!(het_map.join_with::<(Position,), (String,)>().count(), 3);
+    /// assert_eq!(het_map.join_with::<(String,), (Position,)>().count(), 0);
+    /// ```
+    pub fn join_with<Types, Optional>(&self) -> impl Iterator<Item = (Types::JoinItem<'_>, Optional::Refs<'_>)> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+        Optional: TypeTuple<K, S, A>,
+    {
+        Types::join_with::<Optional>(self)
+    }
+
+    /// Returns an iterator over the keys that have a value of every type in a tuple of types,
+    /// together with mutable references to those values.
+    ///
+    /// This method is the mutable counterpart of [`join`]. For the tuple of types
+    /// `(T1, T2, ..., Tn)`, the iterator yields an item
+    /// `(&key, &mut value1, &mut value2, ..., &mut valuen)` for every key that has a value of
+    /// each type `Ti`. The matching keys are found by driving from the map of the type with the
+    /// fewest values, and probing the maps of the other types. If any type in the tuple does not
+    /// exist in the map, the iterator is empty. Tuples of up to twelve types are supported.
+    ///
+    /// Unlike [`join`], the returned iterator does not stream its items. When the first item is
+    /// requested, it finds every matching key, and splits the mutable borrows of the maps of the
+    /// types in the tuple into disjoint references to the matching entries, which allocates a
+    /// buffer of storage indices and a buffer of entry references per type in the tuple.
+    ///
+    /// [`join`]: HeterogeneousHashMap::join
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuple `Types` contains the same type more than once, to
+    /// preserve soundness.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(m * n * log(n))** time, and allocates **O(m * n)** memory, where
+    /// `m` is the number of types in the tuple, and `n` is the smallest number of values of any
+    /// type in the tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct Position(i32, i32);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Velocity(i32, i32);
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Position(0, 0)),
+    ///     (Key::new(2_usize), Position(5, 5)),
+    ///     (Key::new(3_usize), Position(9, 9)),
+    /// ]);
+    /// het_map.extend([
+    ///     (Key::new(1_usize), Velocity(1, 2)),
+    ///     (Key::new(3_usize), Velocity(-1, 0)),
+    /// ]);
+    ///
+    /// for (_key, position, velocity) in het_map.join_mut::<(Position, Velocity)>() {
+    ///     position.0 += velocity.0;
+    ///     position.1 += velocity.1;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<Position, _>(&Key::new(1_usize)), Some(&Position(1, 2)));
+    /// assert_eq!(het_map.get::<Position, _>(&Key::new(2_usize)), Some(&Position(5, 5)));
+    /// assert_eq!(het_map.get::<Position, _>(&Key::new(3_usize)), Some(&Position(8, 9)));
+    /// ```
+    #[track_caller]
+    pub fn join_mut<Types>(&mut self) -> impl Iterator<Item = Types::JoinItemMut<'_>> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        Types::join_mut(self)
+    }
+
+    /// Returns an iterator over every entry of every type in the heterogeneous hash map, without
+    /// naming the types.
+    ///
+    /// The iterator yields the metadata of the type of each value, the key of the entry, and a
+    /// type-erased reference to the value, which can be downcast to its concrete type with
+    /// [`Any::downcast_ref`]. The entries of each type are yielded in storage order, and the
+    /// types are visited in an arbitrary order.
+    ///
+    /// [`Any::downcast_ref`]: core::any::Any::downcast_ref
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(t + n)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, and `n` is the total number of values stored in the heterogeneous
+    /// hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeMetadata};
+    /// # use core::any::type_name;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// let mut entries: Vec<(String, usize)> = het_map
+    ///     .iter_erased()
+    ///     .map(|(metadata, key, _value)| (String::from(metadata.type_name()), *key))
+    ///     .collect();
+    /// entries.sort();
+    /// let mut expected = [
+    ///     (String::from(type_name::<String>()), 1),
+    ///     (String::from(type_name::<i32>()), 1),
+    ///     (String::from(type_name::<i32>()), 3),
+    /// ];
+    /// expected.sort();
+    ///
+    /// assert_eq!(entries, expected);
+    ///
+    /// let sum: i32 = het_map
+    ///     .iter_erased()
+    ///     .filter_map(|(_metadata, _key, value)| value.downcast_ref::<i32>())
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter_erased(&self) -> impl Iterator<Item = (TypeMetadata, &K, &dyn any::Any)> + '_ {
+        self.type_storages().flat_map(|storage| {
+            let metadata = storage.metadata();
+
+            storage.iter().map(move |(key, value)| (metadata, key, value))
+        })
+    }
+
+    /// Returns an iterator over every entry of every type in the heterogeneous hash map, with
+    /// type-erased mutable references to the values, without naming the types.
     ///
-    /// let expected = Vec::from([
-    ///     Key::new("accessory_1"),
-    ///     Key::new("accessory_2"),
-    ///     Key::new("body"),
-    ///     Key::new("cloak"),
-    ///     Key::new("head"),
-    ///     Key::new("left_hand"),
-    ///     Key::new("right_hand"),
-    /// ]);
-    /// let result = {
-    ///     let mut _result: Vec<Key<&str, Equipment>> = het_map
-    ///         .keys::<Equipment>()
-    ///         .unwrap()
-    ///         .cloned()
-    ///         .collect();
-    ///     _result.sort();
-    ///     _result
-    /// };
+    /// The iterator yields the metadata of the type of each value, the key of the entry, and a
+    /// type-erased mutable reference to the value, which can be downcast to its concrete type
+    /// with [`Any::downcast_mut`]. The entries of each type are yielded in storage order, and the
+    /// types are visited in an arbitrary order.
+    ///
+    /// [`Any::downcast_mut`]: core::any::Any::downcast_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(t + n)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, and `n` is the total number of values stored in the heterogeneous
+    /// hash map.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(result, expected);
     /// ```
-    pub fn keys<T>(&self) -> Option<Keys<'_, K, T>>
-    where
-        T: any::Any,
-    {
-        self.get_map::<T>().map(HomogeneousHashMap::keys)
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// for (_metadata, key, value) in het_map.iter_erased_mut() {
+    ///     if let Some(value) = value.downcast_mut::<i32>() {
+    ///         *value += *key as i32;
+    ///     } else if let Some(value) = value.downcast_mut::<String>() {
+    ///         value.push_str("bar");
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&7_i32));
+    /// assert_eq!(het_map.get::<String, _>(&1_usize).map(String::as_str), Some("foobar"));
+    /// ```
+    pub fn iter_erased_mut(&mut self) -> impl Iterator<Item = (TypeMetadata, &K, &mut dyn any::Any)> + '_ {
+        self.type_storages_mut().flat_map(|storage| {
+            let metadata = storage.metadata();
+
+            storage.into_iter_mut().map(move |(key, value)| (metadata, key, value))
+        })
     }
 
-    /// Returns a value iterator over the entries of a given type, if that type exists in
-    /// the heterogeneous hash map.
+    /// Calls a visitor with the type-erased storage of every type in the heterogeneous hash map.
+    ///
+    /// The visitor receives one [`TypeStorage`] per type, in an arbitrary order. Each storage
+    /// dispatches through the type-specific operations captured when its type was inserted into
+    /// the map, so the visitor can walk the whole map without a compile-time list of its types,
+    /// and can recover the homogeneous hash map of a type it knows with
+    /// [`TypeStorage::downcast_ref`]. Every closure taking a [`TypeStorage`] is a
+    /// [`TypeVisitor`].
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(t)** time, not counting the visitor, where `t` is the number of
+    /// types in the heterogeneous hash map.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// # use core::any::type_name;
     /// #
-    /// # #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-    /// struct Equipment(String);
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    /// het_map.insert_type::<f64>();
     ///
-    /// let equipment = [
-    ///     (Key::new("right_hand"),  Equipment(String::from("Alucard Sword"))),
-    ///     (Key::new("left_hand"),   Equipment(String::from("Alucard Shield"))),
-    ///     (Key::new("body"),        Equipment(String::from("Alucard Mail"))),
-    ///     (Key::new("cloak"),       Equipment(String::from("Twilight Cloak"))),
-    ///     (Key::new("head"),        Equipment(String::from("Dragon Helm"))),
-    ///     (Key::new("accessory_1"), Equipment(String::from("Ring Of Varda"))),
-    ///     (Key::new("accessory_2"), Equipment(String::from("Ring Of Varda"))),
-    /// ];
-    /// let mut het_map: HeterogeneousHashMap<&str> = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<Equipment>();
-    /// het_map.extend(equipment.clone());
+    /// let mut report = Vec::new();
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     report.push((String::from(storage.metadata().type_name()), storage.len()));
+    /// });
+    /// report.sort();
     ///
-    /// let expected = {
-    ///     let mut _expected = Vec::from_iter(equipment.iter().map(|(_k, v)| v).cloned());
-    ///     _expected.sort();
-    ///     _expected
-    /// };
-    /// let result = {
-    ///     let mut _result: Vec<Equipment> = het_map
-    ///         .values::<Equipment>()
-    ///         .unwrap()
-    ///         .cloned()
-    ///         .collect();
-    ///     _result.sort();
-    ///     _result
-    /// };
+    /// let mut expected = [
+    ///     (String::from(type_name::<String>()), 1),
+    ///     (String::from(type_name::<f64>()), 0),
+    ///     (String::from(type_name::<i32>()), 2),
+    /// ];
+    /// expected.sort();
     ///
-    /// assert_eq!(result, expected);
+    /// assert_eq!(report, expected);
     /// ```
-    pub fn values<T>(&self) -> Option<Values<'_, K, T>>
+    pub fn for_each_type<V>(&self, visitor: &mut V)
     where
-        T: any::Any,
+        V: TypeVisitor<K, S, A> + ?Sized,
     {
-        self.get_map::<T>().map(HomogeneousHashMap::values)
+        for storage in self.type_storages() {
+            visitor.visit(storage);
+        }
     }
 
-    /// Returns a mutable value iterator over the entries of a given type, if that type exists in
-    /// the heterogeneous hash map.
+    /// Calls a visitor with the mutable type-erased storage of every type in the heterogeneous
+    /// hash map.
+    ///
+    /// The visitor receives one [`TypeStorageMut`] per type, in an arbitrary order. This is the
+    /// mutable counterpart of [`for_each_type`]. Every closure taking a [`TypeStorageMut`] is a
+    /// [`TypeVisitorMut`].
+    ///
+    /// [`for_each_type`]: HeterogeneousHashMap::for_each_type
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(t)** time, not counting the visitor, where `t` is the number of
+    /// types in the heterogeneous hash map.
     ///
     /// # Examples
     ///
-    /// Comparing two different ways of determining which numbers in a collection of integers
-    /// are prime numbers.
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     if let Some(map) = storage.downcast_mut::<i32>() {
+    ///         map.retain(|key, _value| *key.id() > 1);
+    ///     }
+    /// });
     ///
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// assert_eq!(het_map.len::<String>(), Some(1));
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    pub fn for_each_type_mut<V>(&mut self, visitor: &mut V)
+    where
+        V: TypeVisitorMut<K, S, A> + ?Sized,
+    {
+        self.mark_signatures_stale();
+        for storage in self.type_storages_mut() {
+            visitor.visit_mut(storage);
+        }
+    }
+
+    /// Returns an iterator over the values of every type registered as the type `U` in the
+    /// heterogeneous hash map, viewed as `U`.
+    ///
+    /// The type `U` is typically a trait object type, and the values of a type are included when
+    /// the type was registered as `U` with [`register_as`] or [`register_as_mut`]. The values of
+    /// each type are yielded in storage order, and the types are visited in an arbitrary order.
+    ///
+    /// [`register_as`]: HeterogeneousHashMap::register_as
+    /// [`register_as_mut`]: HeterogeneousHashMap::register_as_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(t + n)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, and `n` is the total number of values of the types registered as
+    /// `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
-    /// #[derive(Debug)]
-    /// struct PrimeFactors {
-    ///     value: i32,
-    ///     total_prime_factor_count: u32,
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
     /// }
     ///
-    /// impl PrimeFactors {
-    ///     fn new(value: i32, total_prime_factor_count: u32) -> Self {
-    ///         Self { value, total_prime_factor_count }
+    /// struct Square(f64);
+    /// struct Rectangle(f64, f64);
+    ///
+    /// impl Shape for Square {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.0
     ///     }
     /// }
     ///
-    /// // Determines whether an integer is a prime.
-    /// fn is_prime(n: i32) -> bool {
-    ///     if n <= 1 { return false; }
-    ///     if n == 2 { return true; }
-    ///     if n % 2 == 0 { return false; }
-    ///     let sqrt_n = f64::floor(f64::sqrt(n as f64)) as i32;
-    ///     for i in (3..=sqrt_n).step_by(2) {
-    ///         if n % i == 0 { return false; }
+    /// impl Shape for Rectangle {
+    ///     fn area(&self) -> f64 {
+    ///         self.0 * self.1
     ///     }
-    ///
-    ///     true
     /// }
+    ///
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.register_as::<Square, dyn Shape>(|value| value);
+    /// het_map.register_as::<Rectangle, dyn Shape>(|value| value);
+    /// het_map.insert(Key::new(1_usize), Square(2.0));
+    /// het_map.insert(Key::new(2_usize), Square(3.0));
+    /// het_map.insert(Key::new(3_usize), Rectangle(2.0, 5.0));
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// let total_area: f64 = het_map.iter_as::<dyn Shape>().map(|(_key, shape)| shape.area()).sum();
+    ///
+    /// assert_eq!(total_area, 23.0);
+    /// assert_eq!(het_map.iter_as::<dyn Shape>().count(), 3);
+    /// ```
+    pub fn iter_as<U>(&self) -> impl Iterator<Item = (&K, &U)> + '_
+    where
+        U: ?Sized + 'static,
+    {
+        self.type_storages()
+            .filter_map(|storage| {
+                let view = trait_view::get_view::<U, S>(&self.views, storage.metadata().type_id())?;
+
+                Some(storage.iter().map(move |(key, value)| (key, view.upcast(value))))
+            })
+            .flatten()
+    }
+
+    /// Returns an iterator over the values of every type registered mutably as the type `U` in
+    /// the heterogeneous hash map, viewed as mutable references to `U`.
+    ///
+    /// The values of a type are included when the type was registered as `U` with
+    /// [`register_as_mut`]. The types registered only with [`register_as`] are skipped. The
+    /// values of each type are yielded in storage order, and the types are visited in an
+    /// arbitrary order.
+    ///
+    /// [`register_as`]: HeterogeneousHashMap::register_as
+    /// [`register_as_mut`]: HeterogeneousHashMap::register_as_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(t + n)** time, where `t` is the number of types in the
+    /// heterogeneous hash map, and `n` is the total number of values of the types registered
+    /// mutably as `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
     /// #
-    /// # assert!(is_prime(2_i32));
-    /// # assert!(is_prime(3_i32));
-    /// # assert!(!is_prime(4_i32));
-    /// # assert!(is_prime(5_i32));
-    /// # assert!(!is_prime(6_i32));
-    /// # assert!(is_prime(7_i32));
-    /// # assert!(!is_prime(8_i32));
-    /// # assert!(!is_prime(9_i32));
-    /// # assert!(!is_prime(10_i32));
-    /// # assert!(is_prime(11_i32));
-    /// # assert!(!is_prime(12_i32));
-    /// # assert!(is_prime(13_i32));
-    /// #
+    /// trait Tick {
+    ///     fn tick(&mut self);
+    /// }
     ///
-    /// // Counts the total number of prime factors for a given integer. Every integer has a
-    /// // unique prime factorization. The total number of prime factors is the length of this
-    /// // prime factorization.
-    /// fn count_total_prime_factors(value: i32) -> u32 {
-    ///     let mut n = value;
-    ///     let mut count = 0;
-    ///     while n % 2 == 0 {
-    ///         count += 1;
-    ///         n /= 2;
-    ///     }
-    ///     let mut i = 3;
-    ///     while i * i <= n {
-    ///         while n % i == 0 {
-    ///             count += 1;
-    ///             n /= i;
-    ///         }
-    ///         i += 2;
-    ///     }
-    ///     if n > 2 {
-    ///         count += 1;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Timer(u32);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Countdown(u32);
+    ///
+    /// impl Tick for Timer {
+    ///     fn tick(&mut self) {
+    ///         self.0 += 1;
     ///     }
+    /// }
     ///
-    ///     count
+    /// impl Tick for Countdown {
+    ///     fn tick(&mut self) {
+    ///         self.0 = self.0.saturating_sub(1);
+    ///     }
     /// }
-    /// #
-    /// # assert_eq!(count_total_prime_factors(2_i32),  1_u32);
-    /// # assert_eq!(count_total_prime_factors(3_i32),  1_u32);
-    /// # assert_eq!(count_total_prime_factors(4_i32),  2_u32);
-    /// # assert_eq!(count_total_prime_factors(5_i32),  1_u32);
-    /// # assert_eq!(count_total_prime_factors(6_i32),  2_u32);
-    /// # assert_eq!(count_total_prime_factors(7_i32),  1_u32);
-    /// # assert_eq!(count_total_prime_factors(8_i32),  3_u32);
-    /// # assert_eq!(count_total_prime_factors(9_i32),  2_u32);
-    /// # assert_eq!(count_total_prime_factors(10_i32), 2_u32);
-    /// # assert_eq!(count_total_prime_factors(11_i32), 1_u32);
-    /// # assert_eq!(count_total_prime_factors(12_i32), 3_u32);
-    /// # assert_eq!(count_total_prime_factors(13_i32), 1_u32);
-    /// #
-    /// # for i in 2_i32..=1024_i32 {
-    /// #     if count_total_prime_factors(i) == 1 {
-    /// #         assert!(is_prime(i));
-    /// #     }
-    /// # }
-    /// #
-    /// # for i in 2_i32..=1024_i32 {
-    /// #     if is_prime(i) {
-    /// #         assert_eq!(count_total_prime_factors(i), 1);
-    /// #     }
-    /// # }
-    /// #
     ///
-    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
-    /// het_map.extend(
-    ///     (1_usize..1024_usize)
-    ///         .map(Key::new)
-    ///         .zip((2_i32..=1024_i32).map(|v| PrimeFactors::new(v, 1_u32)))
-    /// );
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.register_as_mut::<Timer, dyn Tick>(|value| value, |value| value);
+    /// het_map.register_as_mut::<Countdown, dyn Tick>(|value| value, |value| value);
+    /// het_map.insert(Key::new(1_usize), Timer(0));
+    /// het_map.insert(Key::new(2_usize), Countdown(3));
     ///
-    /// for factor in het_map.values::<PrimeFactors>().unwrap() {
-    ///     assert_eq!(factor.total_prime_factor_count, 1_u32);
+    /// for (_key, value) in het_map.iter_as_mut::<dyn Tick>() {
+    ///     value.tick();
     /// }
     ///
-    /// // Count the total number of (not necessarily unique) prime factors for every integer value.
-    /// for factor in het_map.values_mut::<PrimeFactors>().unwrap() {
-    ///     factor.total_prime_factor_count = count_total_prime_factors(factor.value);
-    /// }
+    /// assert_eq!(het_map.get::<Timer, _>(&1_usize), Some(&Timer(1)));
+    /// assert_eq!(het_map.get::<Countdown, _>(&2_usize), Some(&Countdown(2)));
+    /// ```
+    pub fn iter_as_mut<U>(&mut self) -> impl Iterator<Item = (&K, &mut U)> + '_
+    where
+        U: ?Sized + 'static,
+    {
+        let vtables = &self.vtables;
+        let views = &self.views;
+
+        self.map
+            .iter_mut()
+            .filter_map(move |(type_id, map)| {
+                let view = trait_view::get_view::<U, S>(views, *type_id).filter(|view| view.has_mut())?;
+                let vtable = vtables.get(type_id).expect("Every stored type must have a registered vtable");
+
+                Some(vtable.iter_map_mut(map).map(move |(key, value)| {
+                    let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+                    let value = view.upcast_mut(value).expect("Every mutable view must have a mutable upcast");
+
+                    (key, value)
+                }))
+            })
+            .flatten()
+    }
+
+    /// Returns an iterator over the values stored under the given key of every type registered as
+    /// the type `U` in the heterogeneous hash map, viewed as `U`.
+    ///
+    /// The values of a type are included when the type was registered as `U` with
+    /// [`register_as`] or [`register_as_mut`]. The iterator yields the values in an arbitrary
+    /// order.
+    ///
+    /// [`register_as`]: HeterogeneousHashMap::register_as
+    /// [`register_as_mut`]: HeterogeneousHashMap::register_as_mut
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(t)** time, where `t` is the number of types in the
+    /// heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use core::fmt::Display;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.register_as::<i32, dyn Display>(|value| value);
+    /// het_map.register_as::<f64, dyn Display>(|value| value);
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), 3.5_f64);
+    /// het_map.insert(Key::new(2_usize), 4_i32);
+    /// het_map.insert(Key::new(1_usize), 5_u8);
     ///
-    /// // Verify that every value for which `count_total_prime_factors` returns 1 for total prime
-    /// // factors is a prime number.
-    /// for factor in het_map.values::<PrimeFactors>().unwrap().filter(|f| f.total_prime_factor_count == 1) {
-    ///     assert!(is_prime(factor.value));
-    /// }
+    /// let mut formatted: Vec<String> = het_map.get_as::<dyn Display>(&1_usize).map(|value| value.to_string()).collect();
+    /// formatted.sort();
     ///
-    /// // Verify that every value for which `count_total_prime_factors` does not return 1 for
-    /// // total prime factors is a prime number.
-    /// for factor in het_map.values::<PrimeFactors>().unwrap().filter(|f| f.total_prime_factor_count > 1) {
-    ///     assert!(!is_prime(factor.value));
-    /// }
+    /// assert_eq!(formatted, ["2", "3.5"]);
+    /// assert_eq!(het_map.get_as::<dyn Display>(&3_usize).count(), 0);
     /// ```
-    pub fn values_mut<T>(&mut self) -> Option<ValuesMut<'_, K, T>>
+    pub fn get_as<'a, U>(&'a self, key: &'a K) -> impl Iterator<Item = &'a U> + 'a
     where
-        T: any::Any,
+        K: Eq,
+        U: ?Sized + 'static,
     {
-        self.get_map_mut::<T>().map(HomogeneousHashMap::values_mut)
+        self.type_storages().filter_map(move |storage| {
+            let view = trait_view::get_view::<U, S>(&self.views, storage.metadata().type_id())?;
+            let value = storage.get(key)?;
+
+            Some(view.upcast(value))
+        })
+    }
+
+    /// Returns an iterator over the type-erased storage of every type in the heterogeneous hash
+    /// map.
+    pub(crate) fn type_storages(&self) -> impl Iterator<Item = TypeStorage<'_, K, S, A>> {
+        self.map.iter().map(|(type_id, map)| {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let metadata = self
+                .registry
+                .get(type_id)
+                .expect("Every stored type must have registered metadata");
+
+            TypeStorage::new(*metadata, map, vtable)
+        })
+    }
+
+    /// Returns an iterator over the mutable type-erased storage of every type in the
+    /// heterogeneous hash map.
+    pub(crate) fn type_storages_mut(&mut self) -> impl Iterator<Item = TypeStorageMut<'_, K, S, A>> {
+        let vtables = &self.vtables;
+        let registry = &self.registry;
+
+        self.map.iter_mut().map(move |(type_id, map)| {
+            let vtable = vtables.get(type_id).expect("Every stored type must have a registered vtable");
+            let metadata = registry
+                .get(type_id)
+                .expect("Every stored type must have registered metadata");
+
+            TypeStorageMut::new(*metadata, map, vtable)
+        })
     }
 
     /// Returns a draining iterator over the entries of a given type, if that type exists in
@@ -2909,13 +6424,205 @@ where
     /// assert_eq!(het_map.len::<u32>(), Some(3));
     /// assert_eq!(het_map.len::<f32>(), None);
     /// ```
-    pub fn drain<T>(&mut self) -> Option<Drain<'_, K, T>>
+    pub fn drain<T>(&mut self) -> Option<Drain<'_, K, T, A>>
     where
         T: any::Any,
     {
         self.get_map_mut::<T>().map(HomogeneousHashMap::drain)
     }
 
+    /// Returns a draining iterator over the entries of a given type in the given range of
+    /// storage indices, if that type exists in the heterogeneous hash map.
+    ///
+    /// This method removes the entries of type `T` in the range `range` from the map, and
+    /// preserves the storage order of the remaining entries of type `T`. The entries of every
+    /// other type are left untouched. The type `T` remains in the map even if every value of
+    /// type `T` is removed.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the type `T` exists in the map, and the starting point of the range
+    /// is greater than the end point, or the end point is greater than `self.len::<T>()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, HomogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// // Process the two oldest entries.
+    /// let drained: Vec<(Key<&str, i32>, i32)> = het_map.drain_range::<i32, _>(..2).unwrap().collect();
+    ///
+    /// assert_eq!(drained, [(Key::new("foo"), 1_i32), (Key::new("bar"), 2_i32)]);
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("baz"), &3_i32)));
+    ///
+    /// assert!(het_map.drain_range::<u64, _>(..).is_none());
+    /// ```
+    #[track_caller]
+    pub fn drain_range<T, R>(&mut self, range: R) -> Option<Drain<'_, K, T, A>>
+    where
+        T: any::Any,
+        R: ops::RangeBounds<usize>,
+    {
+        let map = self.get_map_mut::<T>()?;
+
+        Some(map.drain_range(range))
+    }
+
+    /// Shortens the storage for a given type in the heterogeneous hash map to the given length,
+    /// keeping the first `len` entries of type `T` in storage order and dropping the rest.
+    ///
+    /// This method does nothing if the type `T` does not exist in the map, or `len` is greater
+    /// than or equal to `self.len::<T>()`. The entries of every other type are left untouched.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, HomogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// het_map.truncate::<i32>(1);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new("foo"), &1_i32)));
+    /// ```
+    pub fn truncate<T>(&mut self, len: usize)
+    where
+        T: any::Any,
+    {
+        if let Some(map) = self.get_map_mut::<T>() {
+            map.truncate(len);
+        }
+    }
+
+    /// Splits the storage for a given type in the heterogeneous hash map into two at the given
+    /// storage index, if that type exists in the heterogeneous hash map.
+    ///
+    /// This method returns `Some(map)`, where `map` is a newly allocated homogeneous hash map
+    /// containing the entries of type `T` in the storage index range `[at, len)`, and leaves the
+    /// entries of type `T` in the range `[0, at)` in the heterogeneous hash map. This method
+    /// returns `None` if the type `T` does not exist in the map.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the type `T` exists in the map, and `at > self.len::<T>()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the number of
+    /// values of type `T` stored in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, HomogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// let newest: HomogeneousHashMap<&str, i32> = het_map.split_off::<i32>(3).unwrap();
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(3));
+    /// assert_eq!(newest.len(), 1);
+    /// assert_eq!(newest.get_index(0), Some((&Key::new("quux"), &4_i32)));
+    ///
+    /// assert!(het_map.split_off::<u64>(0).is_none());
+    /// ```
+    #[track_caller]
+    pub fn split_off<T>(&mut self, at: usize) -> Option<HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        let map = self.get_map_mut::<T>()?;
+
+        Some(map.split_off(at))
+    }
+
+    /// Moves every entry from a homogeneous hash map into the storage for the same type in the
+    /// heterogeneous hash map, leaving the other map empty.
+    ///
+    /// If the given type `T` does not exist in the map when this method is called, this method
+    /// inserts the type into the map. The new keys are appended at the end of the storage for the
+    /// type `T` in the order they had in `other`, and the values of keys that already exist in
+    /// the map are replaced in place.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(m)** time, where `m` is the length of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, HomogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// let mut other: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("bar"),  20_i32),
+    ///     (Key::new("quuz"), 5_i32),
+    /// ]);
+    ///
+    /// het_map.append::<i32, _>(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(het_map.len::<i32>(), Some(5));
+    /// assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new("bar"), &20_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(4), Some((&Key::new("quuz"), &5_i32)));
+    /// ```
+    pub fn append<T, S2>(&mut self, other: &mut HomogeneousHashMap<K, T, S2, A>)
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        S2: any::Any + hash::BuildHasher + Send + Sync,
+        S2::Hasher: any::Any + hash::Hasher + Send + Sync,
+    {
+        let type_id = any::TypeId::of::<T>();
+        self.insert_type::<T>();
+        if let Some(index) = self.signatures.as_mut() {
+            for key in other.keys() {
+                index.insert(type_id, key.id());
+            }
+        }
+        let map = self.get_hashed_map_mut::<T>().unwrap();
+
+        map.append(other);
+    }
+
     /// Returns an extracting iterator over the entries of a given type, if that type exists in
     /// the heterogeneous hash map.
     ///
@@ -2971,7 +6678,7 @@ where
     ///     assert!(!is_prime(i));
     /// }
     /// ```
-    pub fn extract_if<T, F>(&mut self, keep: F) -> Option<ExtractIf<'_, K, T, F>>
+    pub fn extract_if<T, F>(&mut self, keep: F) -> Option<ExtractIf<'_, K, T, F, A>>
     where
         T: any::Any,
         F: FnMut(&Key<K, T>, &mut T) -> bool,
@@ -2980,21 +6687,320 @@ where
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Attempts to clone a heterogeneous hash map.
+    ///
+    /// This method clones the values of every type stored in the heterogeneous hash map using the
+    /// clone operation recorded for each type by [`insert_cloneable_type`]. The cloned map
+    /// contains the same types, with the same type metadata, and the same entries in the same
+    /// order for each type.
+    ///
+    /// This method returns an error if the map contains a type that was registered without a
+    /// clone operation. The error reports the metadata of the offending type.
+    ///
+    /// [`insert_cloneable_type`]: HeterogeneousHashMap::insert_cloneable_type
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n + m)** time, where `n` is an affine function of the number of
+    /// types in the heterogeneous hash map, and `m` is an affine function of the total number of
+    /// values in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// Cloning a heterogeneous hash map whose types are all cloneable.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_cloneable_type::<i32>();
+    /// het_map.insert_cloneable_type::<String>();
+    /// het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+    /// het_map.insert::<String>(Key::new(0_usize), String::from("foo"));
+    ///
+    /// let cloned_het_map = het_map.try_clone().unwrap();
+    ///
+    /// assert_eq!(cloned_het_map.len_types(), 2);
+    /// assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    /// assert_eq!(cloned_het_map.get::<String, _>(&Key::new(0_usize)), Some(&String::from("foo")));
+    /// ```
+    ///
+    /// Cloning a heterogeneous hash map containing a type without a clone operation.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use core::any;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_cloneable_type::<i32>();
+    /// het_map.insert_type::<String>();
+    /// het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+    /// het_map.insert::<String>(Key::new(0_usize), String::from("foo"));
+    ///
+    /// let result = het_map.try_clone();
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// let error = result.err().unwrap();
+    ///
+    /// assert_eq!(error.type_name(), any::type_name::<String>());
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, TryCloneError> {
+        let mut map = hash_map::HashMap::with_capacity_and_hasher(self.map.len(), self.build_hasher.clone());
+        for (type_id, type_map) in self.map.iter() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let cloned_type_map = match vtable.clone_map(type_map) {
+                Some(cloned_type_map) => cloned_type_map,
+                None => {
+                    let metadata = self
+                        .registry
+                        .get(type_id)
+                        .expect("Every stored type must have registered metadata");
+
+                    return Err(TryCloneError::new(*metadata));
+                }
+            };
+
+            map.insert(*type_id, cloned_type_map);
+        }
+
+        Ok(Self {
+            map,
+            registry: self.registry.clone(),
+            vtables: self.vtables.clone(),
+            views: self.views.clone(),
+            signatures: self.signatures.clone(),
+            build_hasher: self.build_hasher.clone(),
+            alloc: self.alloc.clone(),
+            _marker: marker::PhantomData,
+        })
+    }
+
+    /// Attempts to compare two heterogeneous hash maps for equality.
+    ///
+    /// Two heterogeneous hash maps are equal when they contain the same types, with the same type
+    /// metadata, and the entries of each type compare equal in the sense of
+    /// [`HomogeneousHashMap`] equality. The values of each type are compared using the equality
+    /// operation recorded for that type by [`insert_comparable_type`] in either map.
+    ///
+    /// This method behaves as follows:
+    ///
+    /// * If the two maps contain different sets of types, this method returns `Ok(false)`.
+    /// * If the two maps contain the same types, but some type was registered without an equality
+    ///   operation in both maps, this method returns an error reporting the metadata of the
+    ///   offending type.
+    /// * Otherwise, this method returns `Ok(true)` if the entries of every type compare equal, and
+    ///   `Ok(false)` if they do not.
+    ///
+    /// [`insert_comparable_type`]: HeterogeneousHashMap::insert_comparable_type
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n + m)** time, where `n` is an affine function of the number of
+    /// types in the heterogeneous hash map, and `m` is an affine function of the total number of
+    /// values in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// Comparing heterogeneous hash maps whose types are all comparable.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// let mut het_map1 = HeterogeneousHashMap::new();
+    /// het_map1.insert_comparable_type::<i32>();
+    /// het_map1.insert_comparable_type::<String>();
+    /// het_map1.insert::<i32>(Key::new(0_usize), 1_i32);
+    /// het_map1.insert::<String>(Key::new(0_usize), String::from("foo"));
+    ///
+    /// let mut het_map2 = HeterogeneousHashMap::new();
+    /// het_map2.insert_comparable_type::<String>();
+    /// het_map2.insert_comparable_type::<i32>();
+    /// het_map2.insert::<String>(Key::new(0_usize), String::from("foo"));
+    /// het_map2.insert::<i32>(Key::new(0_usize), 1_i32);
+    ///
+    /// assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
+    ///
+    /// het_map2.insert::<i32>(Key::new(0_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+    /// ```
+    ///
+    /// Comparing heterogeneous hash maps containing a type without an equality operation.
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use core::any;
+    /// #
+    /// let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map1.insert_comparable_type::<i32>();
+    /// het_map1.insert_type::<String>();
+    ///
+    /// let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map2.insert_comparable_type::<i32>();
+    /// het_map2.insert_type::<String>();
+    ///
+    /// let result = het_map1.try_eq(&het_map2);
+    ///
+    /// assert!(result.is_err());
+    ///
+    /// let error = result.unwrap_err();
+    ///
+    /// assert_eq!(error.type_name(), any::type_name::<String>());
+    /// ```
+    pub fn try_eq(&self, other: &Self) -> Result<bool, TryEqError> {
+        if self.registry.len() != other.registry.len() {
+            return Ok(false);
+        }
+
+        for (type_id, metadata) in self.registry.iter() {
+            if other.registry.get(type_id) != Some(metadata) {
+                return Ok(false);
+            }
+        }
+
+        for (type_id, metadata) in self.registry.iter() {
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let other_vtable = other
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+
+            if !vtable.has_eq() && !other_vtable.has_eq() {
+                return Err(TryEqError::new(*metadata));
+            }
+        }
+
+        for (type_id, type_map) in self.map.iter() {
+            let other_type_map = other.map.get(type_id).expect("Every registered type must have a stored map");
+            let vtable = self
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+            let other_vtable = other
+                .vtables
+                .get(type_id)
+                .expect("Every stored type must have a registered vtable");
+
+            let is_equal = vtable
+                .eq_map(type_map, other_type_map)
+                .or_else(|| other_vtable.eq_map(type_map, other_type_map))
+                .expect("Every stored type must have a registered equality operation");
+
+            if !is_equal {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<K, S, A> Clone for HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Clones a heterogeneous hash map.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the map contains a type that was registered without a clone
+    /// operation. Use [`HeterogeneousHashMap::try_clone`] to handle this case without panicking.
+    fn clone(&self) -> Self {
+        match self.try_clone() {
+            Ok(het_map) => het_map,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<K, S, A> PartialEq for HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Compares two heterogeneous hash maps for equality.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if both maps contain the same types, but some type was registered
+    /// without an equality operation. Use [`HeterogeneousHashMap::try_eq`] to handle this case
+    /// without panicking.
+    fn eq(&self, other: &Self) -> bool {
+        match self.try_eq(other) {
+            Ok(is_equal) => is_equal,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+/// Heterogeneous hash map equality is an equivalence relation as long as the equality operation
+/// of every type registered with [`HeterogeneousHashMap::insert_comparable_type`] is one.
+impl<K, S, A> Eq for HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+}
+
 struct TypeEntry<'a> {
     type_id: &'a any::TypeId,
     metadata: &'a TypeMetadata,
-    length: usize,
+    map: &'a opaque::index_map::TypeErasedIndexMap,
+    vtable: &'a TypeVTable,
 }
 
-impl<S> fmt::Debug for HeterogeneousHashMap<S>
+impl fmt::Debug for TypeEntry<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = formatter.debug_struct("TypeEntry");
+        debug_struct
+            .field("type_id", self.type_id)
+            .field("metadata", self.metadata)
+            .field("length", &self.map.len());
+
+        if let Some(values) = self.vtable.debug_map(self.map) {
+            debug_struct.field("values", &values);
+        }
+
+        debug_struct.finish()
+    }
+}
+
+impl<K, S, A> fmt::Debug for HeterogeneousHashMap<K, S, A>
 where
+    K: any::Any + fmt::Debug,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let entries: Vec<TypeEntry> = self
+    /// Formats the heterogeneous hash map.
+    ///
+    /// Every type stored in the map is formatted with its type identifier, its type metadata,
+    /// and the number of values of that type. The types registered with
+    /// [`HeterogeneousHashMap::insert_debug_type`] also have every key and value of that type
+    /// formatted, in insertion order. The types are formatted in order of their type names.
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries: Vec<TypeEntry> = self
             .map
             .iter()
             .map(|(type_id, map)| {
@@ -3002,16 +7008,26 @@ where
                     .registry
                     .get(type_id)
                     .expect("Every stored type must have registered metadata");
+                let vtable = self
+                    .vtables
+                    .get(type_id)
+                    .expect("Every stored type must have a registered vtable");
 
                 TypeEntry {
                     type_id,
                     metadata,
-                    length: map.len(),
+                    map,
+                    vtable,
                 }
             })
             .collect();
 
-        f.debug_struct("HeterogeneousHashMap").field("entries", &entries).finish()
+        entries.sort_by(|entry1, entry2| entry1.metadata.type_name().cmp(entry2.metadata.type_name()));
+
+        formatter
+            .debug_struct("HeterogeneousHashMap")
+            .field("entries", &entries)
+            .finish()
     }
 }
 
@@ -3021,13 +7037,13 @@ mod test_internals {
     use std::boxed::Box;
     use std::string::String;
 
-    fn match_map_type_ids(het_map: &HeterogeneousHashMap<hash::RandomState>) {
+    fn match_map_type_ids(het_map: &HeterogeneousHashMap<usize>) {
         for (type_id, map) in het_map.map.iter() {
             assert_eq!(map.value_type_id(), *type_id);
         }
     }
 
-    fn match_registry_type_ids(het_map: &HeterogeneousHashMap<hash::RandomState>) {
+    fn match_registry_type_ids(het_map: &HeterogeneousHashMap<usize>) {
         for (type_id, metadata) in het_map.registry.iter() {
             assert_eq!(metadata.type_id(), *type_id);
         }
@@ -3,10 +3,7 @@ use crate::entry::{
     OccupiedEntry,
     VacantEntry,
 };
-use crate::error::{
-    TryReserveError,
-    TryReserveErrorKind,
-};
+use crate::error::TryReserveError;
 use crate::iterator::{
     Drain,
     ExtractIf,
@@ -20,9 +17,11 @@ use crate::iterator::{
     ValuesMut,
 };
 use crate::key::Key;
+use crate::slice::Slice;
 
 use core::any;
 use core::borrow::Borrow;
+use core::cmp;
 use core::fmt;
 use core::ops;
 
@@ -32,6 +31,12 @@ use std::hash;
 #[cfg(not(feature = "std"))]
 use core::hash;
 
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
 /// A hash map containing all values of a given type in a heterogeneous hash map.
 ///
 /// This type acts similarly to a standard library [`HashMap`]. This type of hash map can also
@@ -93,55 +98,132 @@ use core::hash;
 /// assert_eq!(result, expected);
 /// ```
 #[cfg(feature = "std")]
-pub struct HomogeneousHashMap<K, T, S = hash::RandomState>
+pub struct HomogeneousHashMap<K, T, S = hash::RandomState, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S>,
+    inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A>,
 }
 
 #[cfg(not(feature = "std"))]
-pub struct HomogeneousHashMap<K, T, S>
+pub struct HomogeneousHashMap<K, T, S, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S>,
+    inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A>,
 }
 
-impl<K, T, S> HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new homogeneous hash map.
     #[inline]
     #[must_use]
-    pub(crate) const fn from_inner(inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S>) -> Self {
+    pub(crate) const fn from_inner(inner: opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A>) -> Self {
         Self { inner }
     }
 
     /// Constructs a new homogeneous hash map.
     #[inline]
-    pub(crate) const fn from_inner_ref(map: &opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S>) -> &Self {
-        unsafe { &*(map as *const opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S> as *const Self) }
+    pub(crate) const fn from_inner_ref(map: &opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A>) -> &Self {
+        unsafe { &*(map as *const opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A> as *const Self) }
     }
 
     /// Constructs a new homogeneous hash map.
     #[inline]
-    pub(crate) const fn from_inner_ref_mut(map: &mut opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S>) -> &mut Self {
-        unsafe { &mut *(map as *const opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S> as *mut Self) }
+    pub(crate) const fn from_inner_ref_mut(map: &mut opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A>) -> &mut Self {
+        unsafe { &mut *(map as *const opaque::index_map::TypeProjectedIndexMap<Key<K, T>, T, S, A> as *mut Self) }
+    }
+}
+
+impl<K, T, S, A> HomogeneousHashMap<K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    /// Constructs a new homogeneous hash map with the given hash builder and the given memory
+    /// allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HomogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let map: HomogeneousHashMap<usize, i32, RandomState, Global> = HomogeneousHashMap::with_hasher_in(RandomState::new(), Global);
+    ///
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_hasher_in(build_hasher: S, alloc: A) -> Self {
+        Self {
+            inner: opaque::index_map::TypeProjectedIndexMap::with_hasher_in(build_hasher, alloc),
+        }
+    }
+
+    /// Constructs a new homogeneous hash map with at least the given capacity with the given hash
+    /// builder and the given memory allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, i32, RandomState, Global> = HomogeneousHashMap::with_capacity_and_hasher_in(
+    ///     3,
+    ///     RandomState::new(),
+    ///     Global,
+    /// );
+    ///
+    /// assert_eq!(map.len(), 0);
+    /// assert!(map.capacity() >= 3);
+    /// let old_capacity = map.capacity();
+    ///
+    /// map.insert(Key::new(0_usize), 1_i32);
+    /// map.insert(Key::new(1_usize), 2_i32);
+    /// map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(map.len(), 3);
+    /// assert!(map.capacity() >= old_capacity);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity_and_hasher_in(capacity: usize, build_hasher: S, alloc: A) -> Self {
+        Self {
+            inner: opaque::index_map::TypeProjectedIndexMap::with_capacity_and_hasher_in(capacity, build_hasher, alloc),
+        }
     }
 }
 
-impl<K, T, S> HomogeneousHashMap<K, T, S>
+impl<K, T, S> HomogeneousHashMap<K, T, S, alloc::Global>
 where
     K: any::Any,
     T: any::Any,
@@ -163,9 +245,7 @@ where
     #[inline]
     #[must_use]
     pub fn with_hasher(build_hasher: S) -> Self {
-        Self {
-            inner: opaque::index_map::TypeProjectedIndexMap::with_hasher(build_hasher),
-        }
+        Self::with_hasher_in(build_hasher, alloc::Global)
     }
 
     /// Constructs a new homogeneous hash map with at least the given capacity with the given hash
@@ -193,14 +273,76 @@ where
     #[inline]
     #[must_use]
     pub fn with_capacity_and_hasher(capacity: usize, build_hasher: S) -> Self {
-        Self {
-            inner: opaque::index_map::TypeProjectedIndexMap::with_capacity_and_hasher(capacity, build_hasher),
-        }
+        Self::with_capacity_and_hasher_in(capacity, build_hasher, alloc::Global)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, T, A> HomogeneousHashMap<K, T, hash::RandomState, A>
+where
+    K: any::Any,
+    T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    /// Constructs a new homogeneous hash map with the given memory allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HomogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let map: HomogeneousHashMap<usize, i32, RandomState, Global> = HomogeneousHashMap::new_in(Global);
+    ///
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_hasher_in(hash::RandomState::new(), alloc)
+    }
+
+    /// Constructs a new homogeneous hash map with at least the given capacity with the given
+    /// memory allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, i32, RandomState, Global> = HomogeneousHashMap::with_capacity_in(3, Global);
+    ///
+    /// assert_eq!(map.len(), 0);
+    /// assert!(map.capacity() >= 3);
+    /// let old_capacity = map.capacity();
+    ///
+    /// map.insert(Key::new(0_usize), 1_i32);
+    /// map.insert(Key::new(1_usize), 2_i32);
+    /// map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// assert_eq!(map.len(), 3);
+    /// assert!(map.capacity() >= old_capacity);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::with_capacity_and_hasher_in(capacity, hash::RandomState::new(), alloc)
     }
 }
 
 #[cfg(feature = "std")]
-impl<K, T> HomogeneousHashMap<K, T, hash::RandomState>
+impl<K, T> HomogeneousHashMap<K, T, hash::RandomState, alloc::Global>
 where
     K: any::Any,
     T: any::Any,
@@ -249,12 +391,13 @@ where
     }
 }
 
-impl<K, T, S> HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Returns the capacity of the homogeneous hash map.
     ///
@@ -365,14 +508,36 @@ where
     pub fn hasher(&self) -> &S {
         self.inner.hasher().get_build_hasher()
     }
+
+    /// Returns a reference to the memory allocator of the homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::HomogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let map: HomogeneousHashMap<usize, i32, RandomState, Global> = HomogeneousHashMap::new_in(Global);
+    /// let alloc: &Global = map.allocator();
+    /// ```
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
 }
 
-impl<K, T, S> HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Determines whether a homogeneous hash map contains an equivalent key to the given key.
     ///
@@ -734,103 +899,86 @@ where
         self.inner.get_disjoint_mut(ks)
     }
 
-    /// Inserts a new entry into the homogeneous hash map.
-    ///
-    /// This method behaves as follows:
+    /// Returns the storage index of the entry with the equivalent key to the given one, if it
+    /// exists.
     ///
-    /// * If the equivalent key already exists in the homogeneous hash map, this method replaces
-    ///   the old value with the new value in the map, and returns the old value as
-    ///   `Some(old_value)`.
-    /// * If the entry with the equivalent key does not exist in the map, it is inserted into the
-    ///   homogeneous hash map and the method returns `None`.
+    /// The entries of a homogeneous hash map are stored in insertion order, so the storage index
+    /// of an entry is its position in that order. This method returns `Some(index)` if an
+    /// equivalent key to the key `key` exists in the homogeneous hash map. This method returns
+    /// `None` otherwise.
     ///
     /// # Complexity Characteristics
     ///
-    /// This method runs in amortized **O(1)** time.
+    /// This method runs in **O(1)** time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
     /// #
-    /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<f64>();
-    /// {
-    ///     let map = het_map.get_map::<f64>().unwrap();
-    ///
-    ///     assert!(map.is_empty());
-    ///     assert_eq!(map.len(), 0);
-    ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map_mut::<f64>().unwrap();
-    ///     assert_eq!(map.insert(Key::new(1_usize), 2_f64), None);
-    ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 1);
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
-    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map_mut::<f64>().unwrap();
-    ///     assert_eq!(map.insert(Key::new(2_usize), 3_f64), None);
+    /// assert_eq!(map.get_index_of(&Key::new("foo")), Some(0));
+    /// assert_eq!(map.get_index_of(&Key::new("bar")), Some(1));
+    /// assert_eq!(map.get_index_of(&Key::new("baz")), Some(2));
+    /// assert_eq!(map.get_index_of(&Key::new("quux")), None);
+    /// ```
+    #[inline]
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.get_index_of(key)
+    }
+
+    /// Returns the storage index and a reference to the key-value pair with the equivalent key
+    /// to the given one, if it exists.
     ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 2);
+    /// If an equivalent key to the key `key` exists in the homogeneous hash map, this method
+    /// returns `Some((index, &eq_key, &value))`, where `index` is the storage index of the entry,
+    /// `eq_key` is the equivalent key to the key `key`, and `value` is the value corresponding to
+    /// `eq_key`. If an equivalent key to the key `key` does not exist in the homogeneous hash
+    /// map, this method returns `None`.
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
-    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
-    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map_mut::<f64>().unwrap();
-    ///     assert_eq!(map.insert(Key::new(3_usize), 4_f64), None);
+    /// # Complexity Characteristics
     ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 3);
+    /// This method runs in **O(1)** time.
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
-    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
-    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map::<f64>().unwrap();
+    /// # Examples
     ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 3);
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
-    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
-    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
+    /// assert_eq!(map.get_full(&Key::new("foo")), Some((0, &Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_full(&Key::new("bar")), Some((1, &Key::new("bar"), &2_i32)));
+    /// assert_eq!(map.get_full(&Key::new("baz")), Some((2, &Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_full(&Key::new("quux")), None);
     /// ```
     #[inline]
-    pub fn insert(&mut self, key: Key<K, T>, value: T) -> Option<T>
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &Key<K, T>, &T)>
     where
-        K: hash::Hash + Eq,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
     {
-        self.inner.insert(key, value)
+        self.inner.get_full(key)
     }
 
-    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map.
-    ///
-    /// This method behaves as follows:
+    /// Returns a reference to the key-value pair stored at the given storage index, if it
+    /// exists.
     ///
-    /// * If the equivalent key already exists in the homogeneous hash map, this method removes the
-    ///   entry from the homogeneous hash map and returns `Some(value)`, where `value` is the value
-    ///   corresponding to the equivalent key to `key`.
-    /// * If the entry with the equivalent key does not exist in the map, this method does nothing
-    ///   and returns `None`.
+    /// This method returns `Some((&key, &value))` if `index < self.len()`. This method returns
+    /// `None` otherwise.
     ///
     /// # Complexity Characteristics
     ///
@@ -839,46 +987,361 @@ where
     /// # Examples
     ///
     /// ```
-    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
     /// #
-    /// let mut het_map = HeterogeneousHashMap::new();
-    /// het_map.insert_type::<f64>();
-    /// {
-    ///     let map = het_map.get_map::<f64>().unwrap();
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
     ///
-    ///     assert!(map.is_empty());
-    ///     assert_eq!(map.len(), 0);
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(3), None);
+    /// ```
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&Key<K, T>, &T)> {
+        self.inner.get_index(index)
+    }
+
+    /// Returns a mutable reference to the key-value pair stored at the given storage index, if
+    /// it exists.
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map_mut::<f64>().unwrap();
-    ///     map.insert(Key::new(1_usize), 2_f64);
-    ///     map.insert(Key::new(2_usize), 3_f64);
-    ///     map.insert(Key::new(3_usize), 4_f64);
+    /// This method returns `Some((&key, &mut value))` if `index < self.len()`. This method
+    /// returns `None` otherwise.
     ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 3);
+    /// # Complexity Characteristics
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
-    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
-    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
-    /// }
-    /// {
-    ///     let map = het_map.get_map_mut::<f64>().unwrap();
-    ///     assert_eq!(map.remove(&Key::new(1_usize)), Some(2_f64));
+    /// This method runs in **O(1)** time.
     ///
-    ///     assert!(!map.is_empty());
-    ///     assert_eq!(map.len(), 2);
+    /// # Examples
     ///
-    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
-    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
-    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
-    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// if let Some((_, value)) = map.get_index_mut(1) {
+    ///     *value *= 10_i32;
+    /// }
+    ///
+    /// assert_eq!(map.get_index_mut(1), Some((&Key::new("bar"), &mut 20_i32)));
+    /// assert_eq!(map.get_index_mut(3), None);
+    /// ```
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&Key<K, T>, &mut T)> {
+        self.inner.get_index_mut(index)
+    }
+
+    /// Returns a reference to the first key-value pair in storage order, if it exists.
+    ///
+    /// This method returns `None` if the homogeneous hash map is empty.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.first(), Some((&Key::new("foo"), &1_i32)));
+    ///
+    /// let empty_map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::new();
+    ///
+    /// assert_eq!(empty_map.first(), None);
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<(&Key<K, T>, &T)> {
+        self.inner.first()
+    }
+
+    /// Returns a reference to the last key-value pair in storage order, if it exists.
+    ///
+    /// This method returns `None` if the homogeneous hash map is empty.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.last(), Some((&Key::new("baz"), &3_i32)));
+    ///
+    /// let empty_map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::new();
+    ///
+    /// assert_eq!(empty_map.last(), None);
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<(&Key<K, T>, &T)> {
+        self.inner.last()
+    }
+
+    /// Returns a slice of all the entries of the homogeneous hash map, in storage order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.as_slice();
+    ///
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(slice[1], 2_i32);
+    /// assert_eq!(slice[1..].first(), Some((&Key::new("bar"), &2_i32)));
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &Slice<K, T> {
+        Slice::from_inner_ref(self.inner.as_slice())
+    }
+
+    /// Returns a mutable slice of all the entries of the homogeneous hash map, in storage order.
+    ///
+    /// The keys of a mutable slice cannot be modified, since doing so could break the hashing
+    /// invariants of the map. Only the values are accessible mutably.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.as_mut_slice();
+    /// slice[0] = 10_i32;
+    ///
+    /// assert_eq!(map.get(&Key::new("foo")), Some(&10_i32));
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut Slice<K, T> {
+        Slice::from_inner_ref_mut(self.inner.as_mut_slice())
+    }
+
+    /// Returns a slice of the entries of the homogeneous hash map in the given range of storage
+    /// indices, if the range is in bounds.
+    ///
+    /// This method returns `None` if the starting point of the range is greater than the end
+    /// point, or the end point is greater than the length of the homogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// let slice = map.get_range(1..3).unwrap();
+    ///
+    /// assert_eq!(slice.len(), 2);
+    /// assert_eq!(slice.get_index(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(slice.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    ///
+    /// assert!(map.get_range(3..5).is_none());
+    /// ```
+    #[inline]
+    pub fn get_range<R>(&self, range: R) -> Option<&Slice<K, T>>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        self.inner.get_range(range).map(Slice::from_inner_ref)
+    }
+
+    /// Inserts a new entry into the homogeneous hash map.
+    ///
+    /// This method behaves as follows:
+    ///
+    /// * If the equivalent key already exists in the homogeneous hash map, this method replaces
+    ///   the old value with the new value in the map, and returns the old value as
+    ///   `Some(old_value)`.
+    /// * If the entry with the equivalent key does not exist in the map, it is inserted into the
+    ///   homogeneous hash map and the method returns `None`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<f64>();
+    /// {
+    ///     let map = het_map.get_map::<f64>().unwrap();
+    ///
+    ///     assert!(map.is_empty());
+    ///     assert_eq!(map.len(), 0);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map_mut::<f64>().unwrap();
+    ///     assert_eq!(map.insert(Key::new(1_usize), 2_f64), None);
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 1);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
+    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map_mut::<f64>().unwrap();
+    ///     assert_eq!(map.insert(Key::new(2_usize), 3_f64), None);
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 2);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
+    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
+    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map_mut::<f64>().unwrap();
+    ///     assert_eq!(map.insert(Key::new(3_usize), 4_f64), None);
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 3);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
+    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
+    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map::<f64>().unwrap();
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 3);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
+    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
+    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, key: Key<K, T>, value: T) -> Option<T>
+    where
+        K: hash::Hash + Eq,
+    {
+        self.inner.insert(key, value)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map.
+    ///
+    /// This method behaves as follows:
+    ///
+    /// * If the equivalent key already exists in the homogeneous hash map, this method removes the
+    ///   entry from the homogeneous hash map and returns `Some(value)`, where `value` is the value
+    ///   corresponding to the equivalent key to `key`.
+    /// * If the entry with the equivalent key does not exist in the map, this method does nothing
+    ///   and returns `None`.
+    ///
+    /// This method is equivalent to [`swap_remove`], so it does not preserve the storage order of
+    /// the remaining entries. Use [`shift_remove`] to preserve the storage order instead.
+    ///
+    /// [`swap_remove`]: HomogeneousHashMap::swap_remove
+    /// [`shift_remove`]: HomogeneousHashMap::shift_remove
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<f64>();
+    /// {
+    ///     let map = het_map.get_map::<f64>().unwrap();
+    ///
+    ///     assert!(map.is_empty());
+    ///     assert_eq!(map.len(), 0);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(2_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(3_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map_mut::<f64>().unwrap();
+    ///     map.insert(Key::new(1_usize), 2_f64);
+    ///     map.insert(Key::new(2_usize), 3_f64);
+    ///     map.insert(Key::new(3_usize), 4_f64);
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 3);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), Some(&2_f64));
+    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
+    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
+    /// }
+    /// {
+    ///     let map = het_map.get_map_mut::<f64>().unwrap();
+    ///     assert_eq!(map.remove(&Key::new(1_usize)), Some(2_f64));
+    ///
+    ///     assert!(!map.is_empty());
+    ///     assert_eq!(map.len(), 2);
+    ///
+    ///     assert_eq!(map.get(&Key::new(1_usize)), None);
+    ///     assert_eq!(map.get(&Key::new(2_usize)), Some(&3_f64));
+    ///     assert_eq!(map.get(&Key::new(3_usize)), Some(&4_f64));
+    ///     assert_eq!(map.get(&Key::new(4_usize)), None);
     /// }
     /// {
     ///     let map = het_map.get_map_mut::<f64>().unwrap();
@@ -925,6 +1388,13 @@ where
     /// * If the entry with the equivalent key does not exist in the map, this method does nothing
     ///   and returns `None`.
     ///
+    /// This method is equivalent to [`swap_remove_entry`], so it does not preserve the storage
+    /// order of the remaining entries. Use [`shift_remove_entry`] to preserve the storage order
+    /// instead.
+    ///
+    /// [`swap_remove_entry`]: HomogeneousHashMap::swap_remove_entry
+    /// [`shift_remove_entry`]: HomogeneousHashMap::shift_remove_entry
+    ///
     /// # Complexity Characteristics
     ///
     /// This method runs in **O(1)** time.
@@ -992,19 +1462,370 @@ where
     ///     assert!(map.is_empty());
     ///     assert_eq!(map.len(), 0);
     ///
-    ///     assert_eq!(map.get_key_value(&Key::new(1_usize)), None);
-    ///     assert_eq!(map.get_key_value(&Key::new(2_usize)), None);
-    ///     assert_eq!(map.get_key_value(&Key::new(3_usize)), None);
-    ///     assert_eq!(map.get_key_value(&Key::new(4_usize)), None);
-    /// }
+    ///     assert_eq!(map.get_key_value(&Key::new(1_usize)), None);
+    ///     assert_eq!(map.get_key_value(&Key::new(2_usize)), None);
+    ///     assert_eq!(map.get_key_value(&Key::new(3_usize)), None);
+    ///     assert_eq!(map.get_key_value(&Key::new(4_usize)), None);
+    /// }
+    /// ```
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove_entry(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// swapping it with the last entry.
+    ///
+    /// This method returns `Some(value)`, where `value` is the value corresponding to the
+    /// equivalent key to `key`, if it exists. This method returns `None` otherwise.
+    ///
+    /// This method removes the entry by moving the last entry of the map into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.swap_remove(&Key::new("bar")), Some(2_i32));
+    /// assert_eq!(map.swap_remove(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    #[inline]
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// swapping it with the last entry.
+    ///
+    /// This method returns `Some((eq_key, value))`, where `eq_key` is the equivalent key to `key`
+    /// for the entry, and `value` is the value corresponding to `eq_key`, if it exists. This
+    /// method returns `None` otherwise.
+    ///
+    /// This method removes the entry by moving the last entry of the map into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.swap_remove_entry(&Key::new("bar")), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(map.swap_remove_entry(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    #[inline]
+    pub fn swap_remove_entry<Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove_entry(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// swapping it with the last entry.
+    ///
+    /// This method returns `Some((index, eq_key, value))`, where `index` is the storage index the
+    /// entry had, `eq_key` is the equivalent key to `key` for the entry, and `value` is the
+    /// value corresponding to `eq_key`, if it exists. This method returns `None` otherwise.
+    ///
+    /// This method removes the entry by moving the last entry of the map into the vacated
+    /// position, so it does not preserve the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.swap_remove_full(&Key::new("bar")), Some((1, Key::new("bar"), 2_i32)));
+    /// assert_eq!(map.swap_remove_full(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &3_i32)));
+    /// ```
+    #[inline]
+    pub fn swap_remove_full<Q>(&mut self, key: &Q) -> Option<(usize, Key<K, T>, T)>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove_full(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// shifting every following entry.
+    ///
+    /// This method returns `Some(value)`, where `value` is the value corresponding to the
+    /// equivalent key to `key`, if it exists. This method returns `None` otherwise.
+    ///
+    /// This method removes the entry by shifting every following entry down by one position, so
+    /// it preserves the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.shift_remove(&Key::new("bar")), Some(2_i32));
+    /// assert_eq!(map.shift_remove(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    #[inline]
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.shift_remove(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// shifting every following entry.
+    ///
+    /// This method returns `Some((eq_key, value))`, where `eq_key` is the equivalent key to `key`
+    /// for the entry, and `value` is the value corresponding to `eq_key`, if it exists. This
+    /// method returns `None` otherwise.
+    ///
+    /// This method removes the entry by shifting every following entry down by one position, so
+    /// it preserves the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.shift_remove_entry(&Key::new("bar")), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(map.shift_remove_entry(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    #[inline]
+    pub fn shift_remove_entry<Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.shift_remove_entry(key)
+    }
+
+    /// Removes an entry with an equivalent key to the given key from the homogeneous hash map by
+    /// shifting every following entry.
+    ///
+    /// This method returns `Some((index, eq_key, value))`, where `index` is the storage index the
+    /// entry had, `eq_key` is the equivalent key to `key` for the entry, and `value` is the
+    /// value corresponding to `eq_key`, if it exists. This method returns `None` otherwise.
+    ///
+    /// This method removes the entry by shifting every following entry down by one position, so
+    /// it preserves the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.shift_remove_full(&Key::new("bar")), Some((1, Key::new("bar"), 2_i32)));
+    /// assert_eq!(map.shift_remove_full(&Key::new("bar")), None);
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    #[inline]
+    pub fn shift_remove_full<Q>(&mut self, key: &Q) -> Option<(usize, Key<K, T>, T)>
+    where
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.shift_remove_full(key)
+    }
+
+    /// Removes the last key-value pair in storage order from the homogeneous hash map, and
+    /// returns it, if it exists.
+    ///
+    /// This method returns `None` if the homogeneous hash map is empty. This method preserves
+    /// the storage order of the remaining entries.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.pop(), Some((Key::new("bar"), 2_i32)));
+    /// assert_eq!(map.pop(), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(map.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<(Key<K, T>, T)> {
+        self.inner.pop()
+    }
+
+    /// Removes the key-value pair stored at the given storage index from the homogeneous hash
+    /// map, and returns it, if it exists.
+    ///
+    /// This method moves the last entry of the map into the vacated position, so it does not
+    /// preserve the storage order of the remaining entries. This method returns `None` if
+    /// `index >= self.len()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.swap_remove_index(0), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(map.swap_remove_index(5), None);
+    ///
+    /// // The last entry took the place of the removed one.
+    /// assert_eq!(map.get_index(0), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("bar"), &2_i32)));
+    /// ```
+    #[inline]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(Key<K, T>, T)> {
+        self.inner.swap_remove_index(index)
+    }
+
+    /// Removes the key-value pair stored at the given storage index from the homogeneous hash
+    /// map, and returns it, if it exists.
+    ///
+    /// This method shifts every entry after the removed one down by one position, so it preserves
+    /// the storage order of the remaining entries. This method returns `None` if
+    /// `index >= self.len()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.shift_remove_index(0), Some((Key::new("foo"), 1_i32)));
+    /// assert_eq!(map.shift_remove_index(5), None);
+    ///
+    /// // The remaining entries keep their relative order.
+    /// assert_eq!(map.get_index(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("baz"), &3_i32)));
     /// ```
     #[inline]
-    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
-    where
-        Key<K, T>: Borrow<Q>,
-        Q: any::Any + hash::Hash + Eq + ?Sized,
-    {
-        self.inner.swap_remove_entry(key)
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(Key<K, T>, T)> {
+        self.inner.shift_remove_index(index)
     }
 
     /// Returns the entry in the homogeneous hash map corresponding to the given key.
@@ -1046,7 +1867,7 @@ where
     /// assert_eq!(map.len(), 5);
     /// ```
     #[inline]
-    pub fn entry(&mut self, key: Key<K, T>) -> Entry<'_, K, T>
+    pub fn entry(&mut self, key: Key<K, T>) -> Entry<'_, K, T, A>
     where
         K: hash::Hash + Eq,
     {
@@ -1230,10 +2051,55 @@ where
     /// }
     /// assert_eq!(het_map.len::<String>(), Some(0));
     /// ```
-    pub fn drain(&mut self) -> Drain<'_, K, T> {
+    pub fn drain(&mut self) -> Drain<'_, K, T, A> {
         Drain::new(self.inner.drain(..))
     }
 
+    /// Returns a draining iterator over the entries of the homogeneous hash map in the given
+    /// range of storage indices.
+    ///
+    /// This method removes the entries in the range `range` from the map, and shifts the
+    /// remaining entries down to fill the gap, so it preserves the storage order of the
+    /// remaining entries. If the iterator is dropped before being fully consumed, it drops the
+    /// remaining removed elements. The returned iterator keeps a mutable borrow on the
+    /// homogeneous hash map to optimize its implementation.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the starting point of the range is greater than the end point, or
+    /// the end point is greater than the length of the homogeneous hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// let drained: Vec<(Key<&str, i32>, i32)> = map.drain_range(..2).collect();
+    ///
+    /// assert_eq!(drained, [(Key::new("foo"), 1_i32), (Key::new("bar"), 2_i32)]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get_index(0), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    #[track_caller]
+    pub fn drain_range<R>(&mut self, range: R) -> Drain<'_, K, T, A>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        Drain::new(self.inner.drain(range))
+    }
+
     /// Creates an iterator which uses a closure to determine if an element should be removed.
     ///
     /// If the iterator is dropped before being fully consumed, it drops the remaining removed
@@ -1268,7 +2134,7 @@ where
     ///
     /// assert_eq!(result, expected);
     /// ```
-    pub fn extract_if<F>(&mut self, keep: F) -> ExtractIf<'_, K, T, F>
+    pub fn extract_if<F>(&mut self, keep: F) -> ExtractIf<'_, K, T, F, A>
     where
         F: FnMut(&Key<K, T>, &mut T) -> bool,
     {
@@ -1319,7 +2185,7 @@ where
     ///
     /// assert_eq!(result, expected);
     /// ```
-    pub fn into_keys(self) -> IntoKeys<K, T> {
+    pub fn into_keys(self) -> IntoKeys<K, T, A> {
         IntoKeys::new(self.inner.into_keys())
     }
 
@@ -1367,7 +2233,7 @@ where
     ///
     /// assert_eq!(result, expected);
     /// ```
-    pub fn into_values(self) -> IntoValues<K, T> {
+    pub fn into_values(self) -> IntoValues<K, T, A> {
         IntoValues::new(self.inner.into_values())
     }
 
@@ -1409,6 +2275,136 @@ where
         self.inner.clear();
     }
 
+    /// Shortens the homogeneous hash map to the given length, keeping the first `len` entries
+    /// in storage order and dropping the rest.
+    ///
+    /// This method does nothing if `len` is greater than or equal to the length of the map. This
+    /// method does not change the capacity of the map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// map.truncate(2);
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(map.get(&Key::new("baz")), None);
+    ///
+    /// map.truncate(10);
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    /// Splits the homogeneous hash map into two at the given storage index.
+    ///
+    /// This method returns a newly allocated homogeneous hash map containing the entries in the
+    /// storage index range `[at, len)`, and leaves the entries in the range `[0, at)` in this
+    /// map. Both maps preserve the storage order of their entries, and the new map uses a clone
+    /// of this map's hash builder and memory allocator.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `at > self.len()`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// let other = map.split_off(1);
+    ///
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    ///
+    /// assert_eq!(other.len(), 3);
+    /// assert_eq!(other.get_index(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(other.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(other.get_index(2), Some((&Key::new("quux"), &4_i32)));
+    /// ```
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        S: Clone,
+        A: Clone,
+    {
+        Self::from_inner(self.inner.split_off(at))
+    }
+
+    /// Moves every entry from another homogeneous hash map into this one, leaving the other map
+    /// empty.
+    ///
+    /// This method behaves like repeatedly calling [`insert`] with the entries of `other` in
+    /// storage order: the new keys are appended at the end of this map in the order they had in
+    /// `other`, and the values of keys that already exist in this map are replaced in place.
+    ///
+    /// [`insert`]: HomogeneousHashMap::insert
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in amortized **O(m)** time, where `m` is the length of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  1_i32),
+    ///     (Key::new("bar"),  2_i32),
+    ///     (Key::new("baz"),  3_i32),
+    ///     (Key::new("quux"), 4_i32),
+    /// ]);
+    /// let mut other: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("bar"),   20_i32),
+    ///     (Key::new("quuz"),  5_i32),
+    /// ]);
+    ///
+    /// map.append(&mut other);
+    ///
+    /// assert!(other.is_empty());
+    /// assert_eq!(map.len(), 5);
+    /// assert_eq!(map.get_index(1), Some((&Key::new("bar"), &20_i32)));
+    /// assert_eq!(map.get_index(4), Some((&Key::new("quuz"), &5_i32)));
+    /// ```
+    #[inline]
+    pub fn append<S2>(&mut self, other: &mut HomogeneousHashMap<K, T, S2, A>)
+    where
+        K: hash::Hash + Eq,
+        S2: any::Any + hash::BuildHasher + Send + Sync,
+        S2::Hasher: any::Any + hash::Hasher + Send + Sync,
+    {
+        self.inner.append(&mut other.inner);
+    }
+
     /// Retains only the key-value pairs specified by the predicate.
     ///
     /// This method removes all entries `e` for which `keep(&e)` returns `false`. This method
@@ -1464,6 +2460,248 @@ where
         self.inner.retain(keep)
     }
 
+    /// Sorts the entries of the homogeneous hash map by key.
+    ///
+    /// This method sorts the entries in place in their storage order, so subsequent iteration
+    /// and positional access observe the entries in key order. The sort is stable.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the length of the homogeneous
+    /// hash map, and `c` is the capacity of the homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.sort_keys();
+    ///
+    /// let keys: Vec<&str> = map.keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["bar", "baz", "foo", "quux"]);
+    /// ```
+    #[inline]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.inner.sort_keys()
+    }
+
+    /// Sorts the entries of the homogeneous hash map in place using the given comparison
+    /// function.
+    ///
+    /// The comparison function receives two key-value pairs to compare. The sort is stable.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the length of the homogeneous
+    /// hash map, and `c` is the capacity of the homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.sort_by(|_k1, v1, _k2, v2| v1.cmp(v2));
+    ///
+    /// let values: Vec<i32> = map.values().copied().collect();
+    ///
+    /// assert_eq!(values, [1_i32, 2_i32, 3_i32, 4_i32]);
+    /// ```
+    #[inline]
+    pub fn sort_by<F>(&mut self, cmp: F)
+    where
+        F: FnMut(&Key<K, T>, &T, &Key<K, T>, &T) -> cmp::Ordering,
+    {
+        self.inner.sort_by(cmp)
+    }
+
+    /// Sorts the entries of the homogeneous hash map in place using the given comparison
+    /// function, without preserving the order of equal entries.
+    ///
+    /// The comparison function receives two key-value pairs to compare. The sort is unstable.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the length of the homogeneous
+    /// hash map, and `c` is the capacity of the homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.sort_unstable_by(|_k1, v1, _k2, v2| v2.cmp(v1));
+    ///
+    /// let values: Vec<i32> = map.values().copied().collect();
+    ///
+    /// assert_eq!(values, [4_i32, 3_i32, 2_i32, 1_i32]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self, cmp: F)
+    where
+        F: FnMut(&Key<K, T>, &T, &Key<K, T>, &T) -> cmp::Ordering,
+    {
+        self.inner.sort_unstable_by(cmp)
+    }
+
+    /// Sorts the entries of the homogeneous hash map in place using a sort key extraction
+    /// function.
+    ///
+    /// This method calls the sort key extraction function at most once per entry, and caches the
+    /// resulting sort keys, so it is preferable to [`sort_by`] when computing the sort key is
+    /// expensive. The sort is stable.
+    ///
+    /// [`sort_by`]: HomogeneousHashMap::sort_by
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n log n + c)** time, where `n` is the length of the homogeneous
+    /// hash map, and `c` is the capacity of the homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.sort_by_cached_key(|key, _value| key.id().len());
+    ///
+    /// let keys: Vec<&str> = map.keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["foo", "bar", "baz", "quux"]);
+    /// ```
+    #[inline]
+    pub fn sort_by_cached_key<U, F>(&mut self, sort_key: F)
+    where
+        U: Ord,
+        F: FnMut(&Key<K, T>, &T) -> U,
+    {
+        self.inner.sort_by_cached_key(sort_key)
+    }
+
+    /// Reverses the storage order of the entries of the homogeneous hash map in place.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the length of the
+    /// homogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.reverse();
+    ///
+    /// let keys: Vec<&str> = map.keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["baz", "quux", "bar", "foo"]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.inner.reverse()
+    }
+
+    /// Moves the entry at the storage index `from` to the storage index `to`, shifting the
+    /// entries in between to preserve their relative order.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `from` or `to` are out of bounds.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is an affine function of the distance
+    /// between `from` and `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.move_index(0, 3);
+    ///
+    /// let keys: Vec<&str> = map.keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["bar", "quux", "baz", "foo"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.inner.move_index(from, to)
+    }
+
+    /// Swaps the positions of the entries at the storage indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `a` or `b` are out of bounds.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"),  3_i32),
+    ///     (Key::new("bar"),  1_i32),
+    ///     (Key::new("quux"), 4_i32),
+    ///     (Key::new("baz"),  2_i32),
+    /// ]);
+    /// map.swap_indices(0, 3);
+    ///
+    /// let keys: Vec<&str> = map.keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["baz", "bar", "quux", "foo"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.inner.swap_indices(a, b)
+    }
+
     /// Attempts to reserve capacity for **at least** `additional` more elements to be inserted
     /// in the given homogeneous hash map.
     ///
@@ -1567,14 +2805,7 @@ where
     /// ```
     #[inline]
     pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        fn from_opaque_try_reserve_error(error: opaque::error::TryReserveError) -> TryReserveError {
-            TryReserveError::from(match error.kind() {
-                opaque::error::TryReserveErrorKind::CapacityOverflow => TryReserveErrorKind::CapacityOverflow,
-                opaque::error::TryReserveErrorKind::AllocError { layout } => TryReserveErrorKind::AllocError { layout },
-            })
-        }
-
-        self.inner.try_reserve(additional).map_err(from_opaque_try_reserve_error)
+        self.inner.try_reserve(additional).map_err(TryReserveError::from_opaque)
     }
 
     /// Shrinks the capacity of the homogeneous hash map as much as possible.
@@ -1657,45 +2888,49 @@ where
     }
 }
 
-impl<K, T, S> PartialEq for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> PartialEq for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq,
     T: any::Any + PartialEq,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn eq(&self, other: &Self) -> bool {
         PartialEq::eq(&self.inner, &other.inner)
     }
 }
 
-impl<K, T, S> Eq for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> Eq for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq,
     T: any::Any + Eq,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<K, T, S> fmt::Debug for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> fmt::Debug for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.debug_map().entries(self.iter()).finish()
     }
 }
 
-impl<K, T, S> ops::Index<&Key<K, T>> for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> ops::Index<&Key<K, T>> for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Output = T;
 
@@ -1704,12 +2939,13 @@ where
     }
 }
 
-impl<K, T, S> Extend<(Key<K, T>, T)> for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> Extend<(Key<K, T>, T)> for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn extend<I>(&mut self, iterable: I)
     where
@@ -1719,12 +2955,13 @@ where
     }
 }
 
-impl<'a, K, T, S> Extend<(&'a Key<K, T>, &'a T)> for HomogeneousHashMap<K, T, S>
+impl<'a, K, T, S, A> Extend<(&'a Key<K, T>, &'a T)> for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq + Copy,
     T: any::Any + Copy,
     S: any::Any + hash::BuildHasher + Send + Sync,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn extend<I>(&mut self, iterable: I)
     where
@@ -1734,24 +2971,26 @@ where
     }
 }
 
-impl<K, T, S> FromIterator<(Key<K, T>, T)> for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> FromIterator<(Key<K, T>, T)> for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + hash::Hash + Eq,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Default,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Default,
 {
     fn from_iter<I>(iterable: I) -> Self
     where
         I: IntoIterator<Item = (Key<K, T>, T)>,
     {
-        let mut map = HomogeneousHashMap::with_hasher(S::default());
+        let mut map = HomogeneousHashMap::with_hasher_in(S::default(), A::default());
         map.extend(iterable);
 
         map
     }
 }
 
+#[cfg(feature = "std")]
 impl<K, T, const N: usize> From<[(Key<K, T>, T); N]> for HomogeneousHashMap<K, T, hash::RandomState>
 where
     K: any::Any + hash::Hash + Eq,
@@ -1762,12 +3001,13 @@ where
     }
 }
 
-impl<K, T, S> Clone for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> Clone for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any + Clone,
     T: any::Any + Clone,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
 {
     fn clone(&self) -> Self {
         let cloned_inner = self.inner.clone();
@@ -1776,39 +3016,42 @@ where
     }
 }
 
-impl<K, T, S> Default for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> Default for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Default,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Default,
 {
     fn default() -> Self {
-        HomogeneousHashMap::with_hasher(S::default())
+        HomogeneousHashMap::with_hasher_in(S::default(), A::default())
     }
 }
 
-impl<K, T, S> IntoIterator for HomogeneousHashMap<K, T, S>
+impl<K, T, S, A> IntoIterator for HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (Key<K, T>, T);
-    type IntoIter = IntoIter<Key<K, T>, T>;
+    type IntoIter = IntoIter<Key<K, T>, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter::new(self.inner.into_iter())
     }
 }
 
-impl<'a, K, T, S> IntoIterator for &'a HomogeneousHashMap<K, T, S>
+impl<'a, K, T, S, A> IntoIterator for &'a HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (&'a Key<K, T>, &'a T);
     type IntoIter = Iter<'a, K, T>;
@@ -1818,12 +3061,13 @@ where
     }
 }
 
-impl<'a, K, T, S> IntoIterator for &'a mut HomogeneousHashMap<K, T, S>
+impl<'a, K, T, S, A> IntoIterator for &'a mut HomogeneousHashMap<K, T, S, A>
 where
     K: any::Any,
     T: any::Any,
     S: any::Any + hash::BuildHasher + Send + Sync + Clone,
     S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (&'a Key<K, T>, &'a mut T);
     type IntoIter = IterMut<'a, K, T>;
@@ -5,6 +5,12 @@ use core::any;
 use core::fmt;
 use core::iter;
 
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
 use hashbrown::hash_map;
 
 /// An immutable iterator over the entries of a hash map.
@@ -454,30 +460,33 @@ impl<'a, K, T> Default for ValuesMut<'a, K, T> {
 /// assert_eq!(result.len(), expected.len());
 /// assert_eq!(result, expected);
 /// ```
-pub struct Drain<'a, K, T>
+pub struct Drain<'a, K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    iter: opaque::index_map::map::Drain<'a, Key<K, T>, T>,
+    iter: opaque::index_map::map::Drain<'a, Key<K, T>, T, A>,
 }
 
-impl<'a, K, T> Drain<'a, K, T>
+impl<'a, K, T, A> Drain<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new draining iterator.
     #[inline]
-    pub(crate) const fn new(iter: opaque::index_map::map::Drain<'a, Key<K, T>, T>) -> Self {
+    pub(crate) const fn new(iter: opaque::index_map::map::Drain<'a, Key<K, T>, T, A>) -> Self {
         Self { iter }
     }
 }
 
-impl<'a, K, T> Iterator for Drain<'a, K, T>
+impl<'a, K, T, A> Iterator for Drain<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (Key<K, T>, T);
 
@@ -486,10 +495,11 @@ where
     }
 }
 
-impl<'a, K, T> DoubleEndedIterator for Drain<'a, K, T>
+impl<'a, K, T, A> DoubleEndedIterator for Drain<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -500,27 +510,30 @@ where
     }
 }
 
-impl<'a, K, T> ExactSizeIterator for Drain<'a, K, T>
+impl<'a, K, T, A> ExactSizeIterator for Drain<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, K, T> iter::FusedIterator for Drain<'a, K, T>
+impl<'a, K, T, A> iter::FusedIterator for Drain<'a, K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<'a, K, T> fmt::Debug for Drain<'a, K, T>
+impl<'a, K, T, A> fmt::Debug for Drain<'a, K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.iter, formatter)
@@ -590,33 +603,36 @@ where
 ///     assert!(is_prime(*i));
 /// }
 /// ```
-pub struct ExtractIf<'a, K, T, F>
+pub struct ExtractIf<'a, K, T, F, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
     F: FnMut(&Key<K, T>, &mut T) -> bool,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    iter: opaque::index_map::map::ExtractIf<'a, Key<K, T>, T, F>,
+    iter: opaque::index_map::map::ExtractIf<'a, Key<K, T>, T, F, A>,
 }
 
-impl<'a, K, T, F> ExtractIf<'a, K, T, F>
+impl<'a, K, T, F, A> ExtractIf<'a, K, T, F, A>
 where
     K: any::Any,
     T: any::Any,
     F: FnMut(&Key<K, T>, &mut T) -> bool,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new extracting iterator.
     #[inline]
-    pub(crate) const fn new(iter: opaque::index_map::map::ExtractIf<'a, Key<K, T>, T, F>) -> ExtractIf<'a, K, T, F> {
+    pub(crate) const fn new(iter: opaque::index_map::map::ExtractIf<'a, Key<K, T>, T, F, A>) -> ExtractIf<'a, K, T, F, A> {
         ExtractIf { iter }
     }
 }
 
-impl<'a, K, T, F> Iterator for ExtractIf<'a, K, T, F>
+impl<'a, K, T, F, A> Iterator for ExtractIf<'a, K, T, F, A>
 where
     K: any::Any,
     T: any::Any,
     F: FnMut(&Key<K, T>, &mut T) -> bool,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (Key<K, T>, T);
 
@@ -629,19 +645,21 @@ where
     }
 }
 
-impl<K, T, F> iter::FusedIterator for ExtractIf<'_, K, T, F>
+impl<K, T, F, A> iter::FusedIterator for ExtractIf<'_, K, T, F, A>
 where
     K: any::Any,
     T: any::Any,
     F: FnMut(&Key<K, T>, &mut T) -> bool,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<'a, K, T, F> fmt::Debug for ExtractIf<'a, K, T, F>
+impl<'a, K, T, F, A> fmt::Debug for ExtractIf<'a, K, T, F, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
     F: FnMut(&Key<K, T>, &mut T) -> bool,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.debug_struct("ExtractIf").finish_non_exhaustive()
@@ -741,30 +759,33 @@ impl<'a> Default for TypeMetadataIter<'a> {
 /// assert_eq!(result, expected);
 /// ```
 #[derive(Clone)]
-pub struct IntoIter<K, T>
+pub struct IntoIter<K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    iter: opaque::index_map::map::IntoIter<K, T>,
+    iter: opaque::index_map::map::IntoIter<K, T, A>,
 }
 
-impl<K, T> IntoIter<K, T>
+impl<K, T, A> IntoIter<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs new a moving iterator..
     #[inline]
-    pub(crate) const fn new(iter: opaque::index_map::map::IntoIter<K, T>) -> Self {
+    pub(crate) const fn new(iter: opaque::index_map::map::IntoIter<K, T, A>) -> Self {
         Self { iter }
     }
 }
 
-impl<K, T> Iterator for IntoIter<K, T>
+impl<K, T, A> Iterator for IntoIter<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = (K, T);
 
@@ -773,10 +794,11 @@ where
     }
 }
 
-impl<K, T> DoubleEndedIterator for IntoIter<K, T>
+impl<K, T, A> DoubleEndedIterator for IntoIter<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -787,27 +809,30 @@ where
     }
 }
 
-impl<K, T> ExactSizeIterator for IntoIter<K, T>
+impl<K, T, A> ExactSizeIterator for IntoIter<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K, T> iter::FusedIterator for IntoIter<K, T>
+impl<K, T, A> iter::FusedIterator for IntoIter<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<K, T> fmt::Debug for IntoIter<K, T>
+impl<K, T, A> fmt::Debug for IntoIter<K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any + fmt::Debug,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.iter, formatter)
@@ -850,29 +875,32 @@ where
 ///
 /// assert_eq!(result, expected);
 /// ```
-pub struct IntoKeys<K, T>
+pub struct IntoKeys<K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    iter: opaque::index_map::map::IntoKeys<Key<K, T>, T>,
+    iter: opaque::index_map::map::IntoKeys<Key<K, T>, T, A>,
 }
 
-impl<K, T> IntoKeys<K, T>
+impl<K, T, A> IntoKeys<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new moving key iterator.
-    pub(crate) const fn new(iter: opaque::index_map::map::IntoKeys<Key<K, T>, T>) -> Self {
+    pub(crate) const fn new(iter: opaque::index_map::map::IntoKeys<Key<K, T>, T, A>) -> Self {
         Self { iter }
     }
 }
 
-impl<K, T> Iterator for IntoKeys<K, T>
+impl<K, T, A> Iterator for IntoKeys<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = Key<K, T>;
 
@@ -881,10 +909,11 @@ where
     }
 }
 
-impl<K, T> DoubleEndedIterator for IntoKeys<K, T>
+impl<K, T, A> DoubleEndedIterator for IntoKeys<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -895,27 +924,30 @@ where
     }
 }
 
-impl<K, T> ExactSizeIterator for IntoKeys<K, T>
+impl<K, T, A> ExactSizeIterator for IntoKeys<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K, T> iter::FusedIterator for IntoKeys<K, T>
+impl<K, T, A> iter::FusedIterator for IntoKeys<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<K, T> fmt::Debug for IntoKeys<K, T>
+impl<K, T, A> fmt::Debug for IntoKeys<K, T, A>
 where
     K: any::Any + fmt::Debug,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.iter, formatter)
@@ -958,29 +990,32 @@ where
 ///
 /// assert_eq!(result, expected);
 /// ```
-pub struct IntoValues<K, T>
+pub struct IntoValues<K, T, A = alloc::Global>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
-    iter: opaque::index_map::map::IntoValues<Key<K, T>, T>,
+    iter: opaque::index_map::map::IntoValues<Key<K, T>, T, A>,
 }
 
-impl<K, T> IntoValues<K, T>
+impl<K, T, A> IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     /// Constructs a new moving key iterator.
-    pub(crate) const fn new(iter: opaque::index_map::map::IntoValues<Key<K, T>, T>) -> Self {
+    pub(crate) const fn new(iter: opaque::index_map::map::IntoValues<Key<K, T>, T, A>) -> Self {
         Self { iter }
     }
 }
 
-impl<K, T> Iterator for IntoValues<K, T>
+impl<K, T, A> Iterator for IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     type Item = T;
 
@@ -989,10 +1024,11 @@ where
     }
 }
 
-impl<K, T> DoubleEndedIterator for IntoValues<K, T>
+impl<K, T, A> DoubleEndedIterator for IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
@@ -1003,27 +1039,30 @@ where
     }
 }
 
-impl<K, T> ExactSizeIterator for IntoValues<K, T>
+impl<K, T, A> ExactSizeIterator for IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K, T> iter::FusedIterator for IntoValues<K, T>
+impl<K, T, A> iter::FusedIterator for IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
 }
 
-impl<K, T> fmt::Debug for IntoValues<K, T>
+impl<K, T, A> fmt::Debug for IntoValues<K, T, A>
 where
     K: any::Any,
     T: any::Any + fmt::Debug,
+    A: any::Any + alloc::Allocator + Send + Sync,
 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.iter, formatter)
@@ -8,6 +8,8 @@ extern crate alloc as alloc_crate;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "std")]
+mod concurrent;
 mod entry;
 mod error;
 mod heterogeneous_hash_map;
@@ -15,7 +17,23 @@ mod homogeneous_hash_map;
 mod iterator;
 mod key;
 mod metadata;
+mod signature_index;
+mod slice;
+mod storage;
+mod thread_safe;
+mod trait_view;
+mod type_tuple;
+mod vtable;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "serde")]
+mod registry;
+#[cfg(feature = "serde")]
+mod serde;
 
+#[cfg(feature = "std")]
+pub use crate::concurrent::*;
 pub use crate::entry::*;
 pub use crate::error::*;
 pub use crate::heterogeneous_hash_map::*;
@@ -23,3 +41,13 @@ pub use crate::homogeneous_hash_map::*;
 pub use crate::iterator::*;
 pub use crate::key::Key;
 pub use crate::metadata::*;
+pub use crate::signature_index::*;
+pub use crate::slice::*;
+pub use crate::storage::*;
+pub use crate::thread_safe::*;
+pub use crate::type_tuple::*;
+
+#[cfg(feature = "rayon")]
+pub use crate::rayon::*;
+#[cfg(feature = "serde")]
+pub use crate::registry::*;
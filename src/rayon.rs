@@ -0,0 +1,828 @@
+use crate::heterogeneous_hash_map::HeterogeneousHashMap;
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::iterator::{
+    Iter,
+    IterMut,
+    ValuesMut,
+};
+use crate::key::Key;
+use crate::slice::Slice;
+use crate::storage::{
+    TypeStorage,
+    TypeStorageMut,
+};
+use crate::thread_safe::{
+    SendSync,
+    ThreadSafeHeterogeneousHashMap,
+    ThreadSafety,
+};
+
+use alloc_crate::vec::Vec;
+use core::any;
+use core::fmt;
+use std::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+use rayon::iter::plumbing::{
+    Consumer,
+    Producer,
+    ProducerCallback,
+    UnindexedConsumer,
+    bridge,
+};
+use rayon::iter::{
+    IndexedParallelIterator,
+    IntoParallelIterator,
+    ParallelIterator,
+};
+
+/// A parallel iterator over the entries of a homogeneous hash map.
+///
+/// Parallel iterators are created by the [`HomogeneousHashMap::par_iter`] and
+/// [`HeterogeneousHashMap::par_iter`] methods. The entries are split into contiguous runs in
+/// storage order, and each run is iterated sequentially on a worker thread.
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+/// # use rayon::prelude::*;
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.extend((0..1000).map(|i| (Key::new(i), i as i64)));
+///
+/// let sum: i64 = het_map.par_iter::<i64>().unwrap().map(|(_key, value)| *value).sum();
+///
+/// assert_eq!(sum, 499500);
+/// ```
+pub struct ParIter<'a, K, T> {
+    slice: &'a Slice<K, T>,
+}
+
+impl<'a, K, T> ParIter<'a, K, T> {
+    /// Constructs a new parallel iterator over the entries of a slice.
+    const fn new(slice: &'a Slice<K, T>) -> Self {
+        Self { slice }
+    }
+}
+
+impl<'a, K, T> ParallelIterator for ParIter<'a, K, T>
+where
+    K: Sync,
+    T: Sync,
+{
+    type Item = (&'a Key<K, T>, &'a T);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.slice.len())
+    }
+}
+
+impl<K, T> IndexedParallelIterator for ParIter<'_, K, T>
+where
+    K: Sync,
+    T: Sync,
+{
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(IterProducer { slice: self.slice })
+    }
+}
+
+impl<K, T> Clone for ParIter<'_, K, T> {
+    fn clone(&self) -> Self {
+        Self { slice: self.slice }
+    }
+}
+
+impl<K, T> fmt::Debug for ParIter<'_, K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.slice, formatter)
+    }
+}
+
+/// The producer splitting the entries of a [`ParIter`] between worker threads.
+struct IterProducer<'a, K, T> {
+    slice: &'a Slice<K, T>,
+}
+
+impl<'a, K, T> Producer for IterProducer<'a, K, T>
+where
+    K: Sync,
+    T: Sync,
+{
+    type Item = (&'a Key<K, T>, &'a T);
+    type IntoIter = Iter<'a, K, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at(index);
+
+        (Self { slice: left }, Self { slice: right })
+    }
+}
+
+/// A parallel iterator over the entries of a homogeneous hash map, with mutable references to
+/// the values.
+///
+/// Parallel iterators are created by the [`HomogeneousHashMap::par_iter_mut`] and
+/// [`HeterogeneousHashMap::par_iter_mut`] methods. The entries are split into contiguous runs in
+/// storage order, and each run is iterated sequentially on a worker thread.
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+/// # use rayon::prelude::*;
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.extend((0..1000).map(|i| (Key::new(i), 0_usize)));
+///
+/// het_map.par_iter_mut::<usize>().unwrap().for_each(|(key, value)| *value = *key.id() * 2);
+///
+/// assert_eq!(het_map.get::<usize, _>(&10_usize), Some(&20_usize));
+/// ```
+pub struct ParIterMut<'a, K, T> {
+    slice: &'a mut Slice<K, T>,
+}
+
+impl<'a, K, T> ParIterMut<'a, K, T> {
+    /// Constructs a new parallel iterator over the entries of a mutable slice.
+    const fn new(slice: &'a mut Slice<K, T>) -> Self {
+        Self { slice }
+    }
+}
+
+impl<'a, K, T> ParallelIterator for ParIterMut<'a, K, T>
+where
+    K: Send + Sync,
+    T: Send + Sync,
+{
+    type Item = (&'a Key<K, T>, &'a mut T);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.slice.len())
+    }
+}
+
+impl<K, T> IndexedParallelIterator for ParIterMut<'_, K, T>
+where
+    K: Send + Sync,
+    T: Send + Sync,
+{
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(IterMutProducer { slice: self.slice })
+    }
+}
+
+impl<K, T> fmt::Debug for ParIterMut<'_, K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.slice, formatter)
+    }
+}
+
+/// The producer splitting the entries of a [`ParIterMut`] between worker threads.
+struct IterMutProducer<'a, K, T> {
+    slice: &'a mut Slice<K, T>,
+}
+
+impl<'a, K, T> Producer for IterMutProducer<'a, K, T>
+where
+    K: Send + Sync,
+    T: Send + Sync,
+{
+    type Item = (&'a Key<K, T>, &'a mut T);
+    type IntoIter = IterMut<'a, K, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.iter_mut()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at_mut(index);
+
+        (Self { slice: left }, Self { slice: right })
+    }
+}
+
+/// A parallel iterator over mutable references to the values of a homogeneous hash map.
+///
+/// Parallel iterators are created by the [`HomogeneousHashMap::par_values_mut`] and
+/// [`HeterogeneousHashMap::par_values_mut`] methods. The values are split into contiguous runs
+/// in storage order, and each run is iterated sequentially on a worker thread.
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+/// # use rayon::prelude::*;
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.extend((0..1000).map(|i| (Key::new(i), 1_u64)));
+///
+/// het_map.par_values_mut::<u64>().unwrap().for_each(|value| *value += 1);
+///
+/// assert!(het_map.values::<u64>().unwrap().all(|value| *value == 2));
+/// ```
+pub struct ParValuesMut<'a, K, T> {
+    slice: &'a mut Slice<K, T>,
+}
+
+impl<'a, K, T> ParValuesMut<'a, K, T> {
+    /// Constructs a new parallel iterator over the values of a mutable slice.
+    const fn new(slice: &'a mut Slice<K, T>) -> Self {
+        Self { slice }
+    }
+}
+
+impl<'a, K, T> ParallelIterator for ParValuesMut<'a, K, T>
+where
+    K: Send,
+    T: Send,
+{
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.slice.len())
+    }
+}
+
+impl<K, T> IndexedParallelIterator for ParValuesMut<'_, K, T>
+where
+    K: Send,
+    T: Send,
+{
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(ValuesMutProducer { slice: self.slice })
+    }
+}
+
+impl<K, T> fmt::Debug for ParValuesMut<'_, K, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_list().entries(self.slice.values()).finish()
+    }
+}
+
+/// The producer splitting the values of a [`ParValuesMut`] between worker threads.
+struct ValuesMutProducer<'a, K, T> {
+    slice: &'a mut Slice<K, T>,
+}
+
+impl<'a, K, T> Producer for ValuesMutProducer<'a, K, T>
+where
+    K: Send,
+    T: Send,
+{
+    type Item = &'a mut T;
+    type IntoIter = ValuesMut<'a, K, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.values_mut()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at_mut(index);
+
+        (Self { slice: left }, Self { slice: right })
+    }
+}
+
+impl<K, T, S, A> HomogeneousHashMap<K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    /// Returns a parallel iterator over the entries of the homogeneous hash map.
+    ///
+    /// The entries are split into contiguous runs in storage order, and each run is iterated on a
+    /// worker thread of the current [`rayon`] thread pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, i64> = HomogeneousHashMap::new();
+    /// map.extend((0..1000).map(|i| (Key::new(i), i as i64)));
+    ///
+    /// let evens = map.par_iter().filter(|(key, _value)| *key.id() % 2 == 0).count();
+    ///
+    /// assert_eq!(evens, 500);
+    /// ```
+    pub fn par_iter(&self) -> ParIter<'_, K, T>
+    where
+        K: Sync,
+        T: Sync,
+    {
+        ParIter::new(self.as_slice())
+    }
+
+    /// Returns a parallel iterator over the entries of the homogeneous hash map, with mutable
+    /// references to the values.
+    ///
+    /// The entries are split into contiguous runs in storage order, and each run is iterated on a
+    /// worker thread of the current [`rayon`] thread pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, usize> = HomogeneousHashMap::new();
+    /// map.extend((0..1000).map(|i| (Key::new(i), 0_usize)));
+    ///
+    /// map.par_iter_mut().for_each(|(key, value)| *value = *key.id() + 1);
+    ///
+    /// assert_eq!(map.get(&999_usize), Some(&1000_usize));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, K, T>
+    where
+        K: Send + Sync,
+        T: Send + Sync,
+    {
+        ParIterMut::new(self.as_mut_slice())
+    }
+
+    /// Returns a parallel iterator over mutable references to the values of the homogeneous hash
+    /// map.
+    ///
+    /// The values are split into contiguous runs in storage order, and each run is iterated on a
+    /// worker thread of the current [`rayon`] thread pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, f64> = HomogeneousHashMap::new();
+    /// map.extend((0..1000).map(|i| (Key::new(i), i as f64)));
+    ///
+    /// map.par_values_mut().for_each(|value| *value *= 0.5);
+    ///
+    /// assert_eq!(map.get(&10_usize), Some(&5_f64));
+    /// ```
+    pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, T>
+    where
+        K: Send,
+        T: Send,
+    {
+        ParValuesMut::new(self.as_mut_slice())
+    }
+
+    /// Retains only the entries specified by the predicate, evaluating the predicate in parallel.
+    ///
+    /// This method removes every entry `(key, value)` for which `keep(&key, &mut value)` returns
+    /// `false`. The predicate is evaluated for every entry on the worker threads of the current
+    /// [`rayon`] thread pool, and the entries are then removed sequentially. The remaining entries
+    /// keep their relative storage order, as with [`HomogeneousHashMap::retain`].
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is the number of entries in the map, and
+    /// allocates **O(n)** additional memory for the results of the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Key};
+    /// #
+    /// let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::new();
+    /// map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// map.par_retain(|_key, value| *value % 3 == 0);
+    ///
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 3, 6, 9]);
+    /// ```
+    pub fn par_retain<F>(&mut self, keep: F)
+    where
+        K: Send + Sync,
+        T: Send + Sync,
+        F: Fn(&Key<K, T>, &mut T) -> bool + Send + Sync,
+    {
+        let kept: Vec<bool> = self.par_iter_mut().map(|(key, value)| keep(key, value)).collect();
+        let mut kept = kept.into_iter();
+
+        self.retain(|_key, _value| kept.next().expect("Every entry must have a retention flag"));
+    }
+}
+
+impl<K, S, A> HeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Returns a parallel iterator over the entries of the type `T`, if the type exists in the
+    /// heterogeneous hash map.
+    ///
+    /// See [`HomogeneousHashMap::par_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend((0..1000).map(|i| (Key::new(i), i as i64)));
+    ///
+    /// let max = het_map.par_iter::<i64>().unwrap().map(|(_key, value)| *value).max();
+    ///
+    /// assert_eq!(max, Some(999));
+    /// assert!(het_map.par_iter::<u8>().is_none());
+    /// ```
+    pub fn par_iter<T>(&self) -> Option<ParIter<'_, K, T>>
+    where
+        K: Sync,
+        T: any::Any + Sync,
+    {
+        self.get_map::<T>().map(HomogeneousHashMap::par_iter)
+    }
+
+    /// Returns a parallel iterator over the entries of the type `T`, with mutable references to
+    /// the values, if the type exists in the heterogeneous hash map.
+    ///
+    /// See [`HomogeneousHashMap::par_iter_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend((0..1000).map(|i| (Key::new(i), 0_usize)));
+    ///
+    /// het_map.par_iter_mut::<usize>().unwrap().for_each(|(key, value)| *value = *key.id());
+    ///
+    /// assert_eq!(het_map.get::<usize, _>(&42_usize), Some(&42_usize));
+    /// assert!(het_map.par_iter_mut::<u8>().is_none());
+    /// ```
+    pub fn par_iter_mut<T>(&mut self) -> Option<ParIterMut<'_, K, T>>
+    where
+        K: Send + Sync,
+        T: any::Any + Send + Sync,
+    {
+        self.get_map_mut_untracked::<T>().map(HomogeneousHashMap::par_iter_mut)
+    }
+
+    /// Returns a parallel iterator over mutable references to the values of the type `T`, if the
+    /// type exists in the heterogeneous hash map.
+    ///
+    /// See [`HomogeneousHashMap::par_values_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend((0..1000).map(|i| (Key::new(i), 1_i32)));
+    ///
+    /// het_map.par_values_mut::<i32>().unwrap().for_each(|value| *value = -*value);
+    ///
+    /// assert_eq!(het_map.values::<i32>().unwrap().sum::<i32>(), -1000);
+    /// assert!(het_map.par_values_mut::<u8>().is_none());
+    /// ```
+    pub fn par_values_mut<T>(&mut self) -> Option<ParValuesMut<'_, K, T>>
+    where
+        K: Send,
+        T: any::Any + Send,
+    {
+        self.get_map_mut_untracked::<T>().map(HomogeneousHashMap::par_values_mut)
+    }
+
+    /// Retains only the entries of the type `T` specified by the predicate, evaluating the
+    /// predicate in parallel.
+    ///
+    /// This method returns `Some(count)`, where `count` is the number of entries of the type `T`
+    /// removed, if the type exists in the heterogeneous hash map. This method returns `None`
+    /// otherwise. The entries of other types are untouched. See
+    /// [`HomogeneousHashMap::par_retain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    /// het_map.extend((0..10).map(|i| (Key::new(i), i as u64)));
+    ///
+    /// assert_eq!(het_map.par_retain::<i32, _>(|_key, value| *value < 4), Some(6));
+    /// assert_eq!(het_map.par_retain::<u8, _>(|_key, _value| false), None);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(4));
+    /// assert_eq!(het_map.len::<u64>(), Some(10));
+    /// ```
+    pub fn par_retain<T, F>(&mut self, keep: F) -> Option<usize>
+    where
+        K: Send + Sync,
+        T: any::Any + Send + Sync,
+        F: Fn(&Key<K, T>, &mut T) -> bool + Send + Sync,
+    {
+        let map = self.get_map_mut::<T>()?;
+        let old_len = map.len();
+        map.par_retain(keep);
+
+        Some(old_len - map.len())
+    }
+}
+
+/// A type-erased storage that is sent to a worker thread by a parallel visit of the types in a
+/// heterogeneous hash map.
+///
+/// A type storage erases the value type of its storage, so the compiler cannot prove that it is
+/// safe to send to another thread. This wrapper must only be constructed by the parallel visits of
+/// a [`ThreadSafeHeterogeneousHashMap`].
+struct SendStorage<T>(T);
+
+// SAFETY: A `SendStorage<TypeStorage<'_, K, S, A>>` is only constructed by
+// `SyncHeterogeneousHashMap::par_for_each_type`. Every type registered in a sync heterogeneous
+// hash map satisfies `T: Send + Sync`, which the marker `SendSync` requires of every type that
+// `SyncHeterogeneousHashMap::insert_type` and every other registering method admit, and the
+// bounds below require the key type, the hash builder and the allocator to be `Sync` as well.
+// Sending the shared borrow of the storage to another thread is therefore equivalent to sharing a
+// `&HomogeneousHashMap<K, T, S, A>` between threads, which is sound for `Sync` contents. The map
+// stays borrowed for the whole visit.
+unsafe impl<K, S, A> Send for SendStorage<TypeStorage<'_, K, S, A>>
+where
+    K: any::Any + Sync,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+}
+
+// SAFETY: A `SendStorage<TypeStorageMut<'_, K, S, A>>` is only constructed by
+// `ThreadSafeHeterogeneousHashMap::par_for_each_type_mut`. Every type registered in the wrapper
+// satisfies at least `T: Send`, which every thread safety marker requires of the types that the
+// registering methods admit, and the bounds below require the key type, the hash builder and the
+// allocator to be `Send` as well. Each storage is disjoint from the others and handed to exactly
+// one worker thread, so sending its unique borrow is equivalent to sending a
+// `&mut HomogeneousHashMap<K, T, S, A>`, which is sound for `Send` contents. The map stays
+// mutably borrowed for the whole visit.
+unsafe impl<K, S, A> Send for SendStorage<TypeStorageMut<'_, K, S, A>>
+where
+    K: any::Any + Send,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+}
+
+impl<K, M, S, A> ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any + Send + Sync,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Returns a parallel iterator over the entries of the type `T`, with mutable references to
+    /// the values, if the type exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::par_iter_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..1000).map(|i| (Key::new(i), 0_usize)));
+    ///
+    /// het_map.par_iter_mut::<usize>().unwrap().for_each(|(key, value)| *value = *key.id());
+    ///
+    /// assert_eq!(het_map.get::<usize, _>(&42_usize), Some(&42_usize));
+    /// assert!(het_map.par_iter_mut::<u8>().is_none());
+    /// ```
+    pub fn par_iter_mut<T>(&mut self) -> Option<ParIterMut<'_, K, T>>
+    where
+        T: any::Any + Send + Sync,
+    {
+        self.inner.par_iter_mut::<T>()
+    }
+
+    /// Returns a parallel iterator over mutable references to the values of the type `T`, if the
+    /// type exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::par_values_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// # use rayon::prelude::*;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..1000).map(|i| (Key::new(i), 1_i32)));
+    ///
+    /// het_map.par_values_mut::<i32>().unwrap().for_each(|value| *value = -*value);
+    ///
+    /// assert_eq!(het_map.values::<i32>().unwrap().sum::<i32>(), -1000);
+    /// assert!(het_map.par_values_mut::<u8>().is_none());
+    /// ```
+    pub fn par_values_mut<T>(&mut self) -> Option<ParValuesMut<'_, K, T>>
+    where
+        T: any::Any + Send,
+    {
+        self.inner.par_values_mut::<T>()
+    }
+
+    /// Retains only the entries of the type `T` specified by the predicate, evaluating the
+    /// predicate in parallel.
+    ///
+    /// This method returns `Some(count)`, where `count` is the number of entries of the type `T`
+    /// removed, if the type exists in the thread-safe heterogeneous hash map. See
+    /// [`HeterogeneousHashMap::par_retain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.par_retain::<i32, _>(|_key, value| *value < 4), Some(6));
+    /// assert_eq!(het_map.par_retain::<u8, _>(|_key, _value| false), None);
+    /// assert_eq!(het_map.len::<i32>(), Some(4));
+    /// ```
+    pub fn par_retain<T, F>(&mut self, keep: F) -> Option<usize>
+    where
+        T: any::Any + Send + Sync,
+        F: Fn(&Key<K, T>, &mut T) -> bool + Send + Sync,
+    {
+        self.inner.par_retain::<T, F>(keep)
+    }
+
+    /// Calls the visitor with the mutable type-erased storage of every type in the
+    /// thread-safe heterogeneous hash map, visiting different types in parallel.
+    ///
+    /// The storages of different types are disjoint, so each one is handed to a worker thread of
+    /// the current [`rayon`] thread pool. The order in which the types are visited is
+    /// unspecified. See [`HeterogeneousHashMap::for_each_type_mut`] for the sequential
+    /// counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap, TypeStorageMut};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.get_or_insert_map_mut::<i32>().extend((0..1000).map(|i| (Key::new(i), 1_i32)));
+    /// het_map.get_or_insert_map_mut::<f64>().extend((0..1000).map(|i| (Key::new(i), 1_f64)));
+    ///
+    /// het_map.par_for_each_type_mut(|mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     if let Some(map) = storage.downcast_mut::<i32>() {
+    ///         map.values_mut().for_each(|value| *value *= 2);
+    ///     } else if let Some(map) = storage.downcast_mut::<f64>() {
+    ///         map.values_mut().for_each(|value| *value *= 0.5);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// assert_eq!(het_map.get::<f64, _>(&1_usize), Some(&0.5_f64));
+    /// ```
+    pub fn par_for_each_type_mut<F>(&mut self, visitor: F)
+    where
+        F: Fn(TypeStorageMut<'_, K, S, A>) + Send + Sync,
+    {
+        self.inner.mark_signatures_stale();
+        let storages: Vec<SendStorage<TypeStorageMut<'_, K, S, A>>> = self.inner.type_storages_mut().map(SendStorage).collect();
+
+        storages.into_par_iter().for_each(|SendStorage(storage)| visitor(storage));
+    }
+}
+
+impl<K, S, A> ThreadSafeHeterogeneousHashMap<K, SendSync, S, A>
+where
+    K: any::Any + Send + Sync,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Calls the visitor with the type-erased storage of every type in the sync heterogeneous
+    /// hash map, visiting different types in parallel.
+    ///
+    /// Each storage is handed to a worker thread of the current [`rayon`] thread pool. The order
+    /// in which the types are visited is unspecified. See
+    /// [`HeterogeneousHashMap::for_each_type`] for the sequential counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap, TypeStorage};
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// #
+    /// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    /// het_map.get_or_insert_map_mut::<i32>().extend((0..1000).map(|i| (Key::new(i), 1_i32)));
+    /// het_map.get_or_insert_map_mut::<f64>().extend((0..500).map(|i| (Key::new(i), 1_f64)));
+    ///
+    /// let total = AtomicUsize::new(0);
+    /// het_map.par_for_each_type(|storage: TypeStorage<'_, usize, _, _>| {
+    ///     total.fetch_add(storage.len(), Ordering::Relaxed);
+    /// });
+    ///
+    /// assert_eq!(total.into_inner(), 1500);
+    /// ```
+    pub fn par_for_each_type<F>(&self, visitor: F)
+    where
+        F: Fn(TypeStorage<'_, K, S, A>) + Send + Sync,
+    {
+        let storages: Vec<SendStorage<TypeStorage<'_, K, S, A>>> = self.inner.type_storages().map(SendStorage).collect();
+
+        storages.into_par_iter().for_each(|SendStorage(storage)| visitor(storage));
+    }
+}
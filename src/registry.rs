@@ -0,0 +1,631 @@
+use crate::heterogeneous_hash_map::HeterogeneousHashMap;
+use crate::metadata::TypeMetadata;
+use crate::serde::HomogeneousHashMapExtendSeed;
+
+use alloc_crate::collections::BTreeMap;
+use alloc_crate::string::String;
+use core::any;
+use core::fmt;
+use core::marker;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+use serde::de::{
+    self,
+    DeserializeOwned,
+    DeserializeSeed,
+    Deserializer,
+    MapAccess,
+    Visitor,
+};
+use serde::ser::{
+    self,
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+
+/// The serialization operation for the values of one type stored in a heterogeneous hash map.
+type SerializeFn<K, S, A> = for<'a> fn(&'a HeterogeneousHashMap<K, S, A>) -> Option<&'a dyn erased_serde::Serialize>;
+
+/// The deserialization operation for the values of one type stored in a heterogeneous hash map.
+type DeserializeFn<K, S, A> =
+    for<'de> fn(&mut HeterogeneousHashMap<K, S, A>, &mut dyn erased_serde::Deserializer<'de>) -> Result<(), erased_serde::Error>;
+
+struct TypeRegistration<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    tag: &'static str,
+    metadata: TypeMetadata,
+    serialize: SerializeFn<K, S, A>,
+    deserialize: DeserializeFn<K, S, A>,
+}
+
+/// A registry of stable names for the types stored in a heterogeneous hash map.
+///
+/// A [`HeterogeneousHashMap`] identifies its types by [`TypeId`], which is not stable across
+/// compilations, and it has no way of encoding the values of a type on its own. A type registry
+/// associates each type with a stable string **tag** together with the serialization and
+/// deserialization operations for the values of that type. A heterogeneous hash map is
+/// serialized through a type registry as a map from tags to the entries of each type, and is
+/// deserialized back into the storage of the matching types.
+///
+/// [`TypeId`]: core::any::TypeId
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #
+/// let mut registry: TypeRegistry<String> = TypeRegistry::new();
+/// registry.register::<i32>("i32");
+/// registry.register::<String>("string");
+///
+/// let mut het_map: HeterogeneousHashMap<String> = HeterogeneousHashMap::new();
+/// het_map.insert_type::<i32>();
+/// het_map.insert_type::<String>();
+/// het_map.insert::<i32>(Key::new(String::from("foo")), 1_i32);
+/// het_map.insert::<String>(Key::new(String::from("bar")), String::from("baz"));
+///
+/// let serialized = serde_json::to_string(&registry.serializable(&het_map)).unwrap();
+///
+/// assert_eq!(serialized, r#"{"i32":{"foo":1},"string":{"bar":"baz"}}"#);
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(&serialized);
+/// let deserialized = registry.seed().deserialize(&mut deserializer).unwrap();
+///
+/// assert_eq!(deserialized.len_types(), 2);
+/// assert_eq!(deserialized.get::<i32, _>(&Key::new(String::from("foo"))), Some(&1_i32));
+/// assert_eq!(deserialized.get::<String, _>(&Key::new(String::from("bar"))), Some(&String::from("baz")));
+/// ```
+#[cfg(feature = "std")]
+pub struct TypeRegistry<K, S = hash::RandomState, A = alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    registrations: BTreeMap<any::TypeId, TypeRegistration<K, S, A>>,
+    tags: BTreeMap<&'static str, any::TypeId>,
+    _marker: marker::PhantomData<(K, S, A)>,
+}
+
+#[cfg(not(feature = "std"))]
+pub struct TypeRegistry<K, S, A = alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    registrations: BTreeMap<any::TypeId, TypeRegistration<K, S, A>>,
+    tags: BTreeMap<&'static str, any::TypeId>,
+    _marker: marker::PhantomData<(K, S, A)>,
+}
+
+impl<K, S, A> TypeRegistry<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new empty type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let registry: TypeRegistry<usize> = TypeRegistry::new();
+    ///
+    /// assert!(registry.is_empty());
+    /// assert_eq!(registry.len(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            registrations: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of types registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    ///
+    /// assert_eq!(registry.len(), 0);
+    ///
+    /// registry.register::<i32>("i32");
+    ///
+    /// assert_eq!(registry.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    /// Determines whether the type registry contains no types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    ///
+    /// assert!(registry.is_empty());
+    ///
+    /// registry.register::<i32>("i32");
+    ///
+    /// assert!(!registry.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+
+    /// Registers a type in the type registry under the given tag.
+    ///
+    /// This method returns `true` if neither the type `T` nor the tag exist in the registry. This
+    /// method returns `false` if either the type `T` or the tag already exist in the registry,
+    /// in which case the registry is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    ///
+    /// assert!(registry.register::<i32>("i32"));
+    /// assert!(!registry.register::<i32>("another_i32"));
+    /// assert!(!registry.register::<u32>("i32"));
+    /// assert!(registry.register::<u32>("u32"));
+    /// ```
+    pub fn register<T>(&mut self, tag: &'static str) -> bool
+    where
+        K: hash::Hash + Eq + ser::Serialize + DeserializeOwned,
+        T: any::Any + ser::Serialize + DeserializeOwned,
+    {
+        fn serialize_type<K, T, S, A>(het_map: &HeterogeneousHashMap<K, S, A>) -> Option<&dyn erased_serde::Serialize>
+        where
+            K: any::Any + ser::Serialize,
+            T: any::Any + ser::Serialize,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            het_map.get_map::<T>().map(|map| map as &dyn erased_serde::Serialize)
+        }
+
+        fn deserialize_type<'de, K, T, S, A>(
+            het_map: &mut HeterogeneousHashMap<K, S, A>,
+            deserializer: &mut dyn erased_serde::Deserializer<'de>,
+        ) -> Result<(), erased_serde::Error>
+        where
+            K: any::Any + hash::Hash + Eq + DeserializeOwned,
+            T: any::Any + DeserializeOwned,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            het_map.insert_type::<T>();
+            het_map.set_key_lookup::<T>();
+            let map = het_map.get_or_insert_map_mut::<T>();
+
+            HomogeneousHashMapExtendSeed::new(map).deserialize(deserializer)
+        }
+
+        let type_id = any::TypeId::of::<T>();
+        if self.registrations.contains_key(&type_id) || self.tags.contains_key(tag) {
+            return false;
+        }
+
+        let registration = TypeRegistration {
+            tag,
+            metadata: TypeMetadata::of::<T>(),
+            serialize: serialize_type::<K, T, S, A>,
+            deserialize: deserialize_type::<K, T, S, A>,
+        };
+
+        self.registrations.insert(type_id, registration);
+        self.tags.insert(tag, type_id);
+
+        true
+    }
+
+    /// Determines whether the type `T` is registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// assert!(registry.contains_type::<i32>());
+    /// assert!(!registry.contains_type::<u32>());
+    /// ```
+    pub fn contains_type<T>(&self) -> bool
+    where
+        T: any::Any,
+    {
+        self.registrations.contains_key(&any::TypeId::of::<T>())
+    }
+
+    /// Returns the tag of the type `T`, if the type is registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::TypeRegistry;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// assert_eq!(registry.get_tag::<i32>(), Some("i32"));
+    /// assert_eq!(registry.get_tag::<u32>(), None);
+    /// ```
+    pub fn get_tag<T>(&self) -> Option<&'static str>
+    where
+        T: any::Any,
+    {
+        self.registrations
+            .get(&any::TypeId::of::<T>())
+            .map(|registration| registration.tag)
+    }
+
+    /// Returns the metadata of the type registered under the given tag, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{TypeMetadata, TypeRegistry};
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// assert_eq!(registry.get_metadata_by_tag("i32"), Some(TypeMetadata::of::<i32>()));
+    /// assert_eq!(registry.get_metadata_by_tag("u32"), None);
+    /// ```
+    pub fn get_metadata_by_tag(&self, tag: &str) -> Option<TypeMetadata> {
+        self.tags
+            .get(tag)
+            .and_then(|type_id| self.registrations.get(type_id))
+            .map(|registration| registration.metadata)
+    }
+
+    /// Returns a serializable view of a heterogeneous hash map using this type registry.
+    ///
+    /// The heterogeneous hash map is serialized as a map from the tag of each type to the
+    /// entries of that type, in tag order. The entries of each type are serialized in the same
+    /// way as a [`HomogeneousHashMap`].
+    ///
+    /// Serializing the view fails if the heterogeneous hash map contains a type that is not
+    /// registered in the type registry.
+    ///
+    /// [`HomogeneousHashMap`]: crate::HomogeneousHashMap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HeterogeneousHashMap, TypeRegistry};
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+    ///
+    /// let serialized = serde_json::to_string(&registry.serializable(&het_map)).unwrap();
+    ///
+    /// assert_eq!(serialized, r#"{"i32":{"1":2}}"#);
+    ///
+    /// het_map.insert_type::<u32>();
+    ///
+    /// assert!(serde_json::to_string(&registry.serializable(&het_map)).is_err());
+    /// ```
+    pub fn serializable<'a>(
+        &'a self,
+        het_map: &'a HeterogeneousHashMap<K, S, A>,
+    ) -> SerializableHeterogeneousHashMap<'a, K, S, A> {
+        SerializableHeterogeneousHashMap { registry: self, het_map }
+    }
+
+    /// Returns a deserialization seed that deserializes a heterogeneous hash map using this type
+    /// registry, the given hash builder, and the given memory allocator.
+    ///
+    /// The seed expects the format produced by [`TypeRegistry::serializable`]. Deserializing
+    /// fails if the input contains a tag that is not registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::{Key, TypeRegistry};
+    /// # use serde::de::DeserializeSeed;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let mut registry: TypeRegistry<usize, RandomState, Global> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#"{"i32":{"1":2}}"#);
+    /// let het_map = registry
+    ///     .seed_with_hasher_in(RandomState::new(), Global)
+    ///     .deserialize(&mut deserializer)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    /// ```
+    pub fn seed_with_hasher_in(&self, build_hasher: S, alloc: A) -> HeterogeneousHashMapSeed<'_, K, S, A> {
+        HeterogeneousHashMapSeed {
+            registry: self,
+            build_hasher,
+            alloc,
+        }
+    }
+
+    /// Returns a deserialization seed that deserializes a heterogeneous hash map using this type
+    /// registry and the given hash builder.
+    ///
+    /// The seed expects the format produced by [`TypeRegistry::serializable`]. Deserializing
+    /// fails if the input contains a tag that is not registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, TypeRegistry};
+    /// # use serde::de::DeserializeSeed;
+    /// # use std::hash::RandomState;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#"{"i32":{"1":2}}"#);
+    /// let het_map = registry.seed_with_hasher(RandomState::new()).deserialize(&mut deserializer).unwrap();
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    /// ```
+    pub fn seed_with_hasher(&self, build_hasher: S) -> HeterogeneousHashMapSeed<'_, K, S, A>
+    where
+        A: Default,
+    {
+        self.seed_with_hasher_in(build_hasher, A::default())
+    }
+
+    /// Returns a deserialization seed that deserializes a heterogeneous hash map using this type
+    /// registry and the default hash builder.
+    ///
+    /// The seed expects the format produced by [`TypeRegistry::serializable`]. Deserializing
+    /// fails if the input contains a tag that is not registered in the type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, TypeRegistry};
+    /// # use serde::de::DeserializeSeed;
+    /// #
+    /// let mut registry: TypeRegistry<usize> = TypeRegistry::new();
+    /// registry.register::<i32>("i32");
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#"{"i32":{"1":2}}"#);
+    /// let result = registry.seed().deserialize(&mut deserializer);
+    ///
+    /// assert!(result.is_ok());
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str(r#"{"u32":{"1":2}}"#);
+    /// let result = registry.seed().deserialize(&mut deserializer);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn seed(&self) -> HeterogeneousHashMapSeed<'_, K, S, A>
+    where
+        S: Default,
+        A: Default,
+    {
+        self.seed_with_hasher_in(S::default(), A::default())
+    }
+}
+
+impl<K, S, A> Default for TypeRegistry<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, S, A> fmt::Debug for TypeRegistry<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_map()
+            .entries(
+                self.registrations
+                    .values()
+                    .map(|registration| (registration.tag, &registration.metadata)),
+            )
+            .finish()
+    }
+}
+
+/// A serializable view of a heterogeneous hash map through a type registry.
+///
+/// This type is constructed by [`TypeRegistry::serializable`].
+pub struct SerializableHeterogeneousHashMap<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    registry: &'a TypeRegistry<K, S, A>,
+    het_map: &'a HeterogeneousHashMap<K, S, A>,
+}
+
+impl<K, S, A> Serialize for SerializableHeterogeneousHashMap<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        for (type_id, metadata) in self.het_map.metadata_iter() {
+            if !self.registry.registrations.contains_key(type_id) {
+                return Err(ser::Error::custom(format_args!(
+                    "the type `{}` is not registered in the type registry",
+                    metadata.type_name()
+                )));
+            }
+        }
+
+        let mut state = serializer.serialize_map(Some(self.het_map.len_types()))?;
+        for (tag, type_id) in self.registry.tags.iter() {
+            let registration = &self.registry.registrations[type_id];
+            if let Some(values) = (registration.serialize)(self.het_map) {
+                state.serialize_entry(tag, values)?;
+            }
+        }
+
+        state.end()
+    }
+}
+
+/// A deserialization seed for a heterogeneous hash map through a type registry.
+///
+/// This type is constructed by [`TypeRegistry::seed`], [`TypeRegistry::seed_with_hasher`], and
+/// [`TypeRegistry::seed_with_hasher_in`].
+pub struct HeterogeneousHashMapSeed<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    registry: &'a TypeRegistry<K, S, A>,
+    build_hasher: S,
+    alloc: A,
+}
+
+impl<'de, K, S, A> DeserializeSeed<'de> for HeterogeneousHashMapSeed<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Value = HeterogeneousHashMap<K, S, A>;
+
+    fn deserialize<De>(self, deserializer: De) -> Result<Self::Value, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, K, S, A> Visitor<'de> for HeterogeneousHashMapSeed<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Value = HeterogeneousHashMap<K, S, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map from type tags to maps of entries")
+    }
+
+    fn visit_map<Map>(self, mut map: Map) -> Result<Self::Value, Map::Error>
+    where
+        Map: MapAccess<'de>,
+    {
+        let mut het_map = HeterogeneousHashMap::with_hasher_in(self.build_hasher, self.alloc);
+        while let Some(tag) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .tags
+                .get(tag.as_str())
+                .and_then(|type_id| self.registry.registrations.get(type_id))
+                .ok_or_else(|| de::Error::custom(format_args!("unknown type tag `{}`", tag)))?;
+            let seed = TypeSeed {
+                het_map: &mut het_map,
+                registration,
+            };
+
+            map.next_value_seed(seed)?;
+        }
+
+        Ok(het_map)
+    }
+}
+
+struct TypeSeed<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    het_map: &'a mut HeterogeneousHashMap<K, S, A>,
+    registration: &'a TypeRegistration<K, S, A>,
+}
+
+impl<'de, K, S, A> DeserializeSeed<'de> for TypeSeed<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Value = ();
+
+    fn deserialize<De>(self, deserializer: De) -> Result<Self::Value, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        let mut erased_deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+
+        (self.registration.deserialize)(self.het_map, &mut erased_deserializer).map_err(de::Error::custom)
+    }
+}
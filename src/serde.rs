@@ -0,0 +1,214 @@
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::key::Key;
+
+use core::any;
+use core::cmp;
+use core::fmt;
+use core::marker;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+use serde::de::{
+    Deserialize,
+    DeserializeSeed,
+    Deserializer,
+    MapAccess,
+    Visitor,
+};
+use serde::ser::{
+    Serialize,
+    Serializer,
+};
+
+/// The maximum number of entries to preallocate when deserializing a homogeneous hash map from
+/// an untrusted size hint.
+const MAX_PREALLOCATED_ENTRIES: usize = 4096;
+
+impl<K, T> Serialize for Key<K, T>
+where
+    K: Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        self.id().serialize(serializer)
+    }
+}
+
+impl<'de, K, T> Deserialize<'de> for Key<K, T>
+where
+    K: Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        K::deserialize(deserializer).map(Key::new)
+    }
+}
+
+impl<K, T, S, A> Serialize for HomogeneousHashMap<K, T, S, A>
+where
+    K: any::Any + Serialize,
+    T: any::Any + Serialize,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+struct HomogeneousHashMapVisitor<K, T, S, A> {
+    _marker: marker::PhantomData<(K, T, S, A)>,
+}
+
+impl<'de, K, T, S, A> Visitor<'de> for HomogeneousHashMapVisitor<K, T, S, A>
+where
+    K: any::Any + hash::Hash + Eq + Deserialize<'de>,
+    T: any::Any + Deserialize<'de>,
+    S: any::Any + hash::BuildHasher + Send + Sync + Default,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Default,
+{
+    type Value = HomogeneousHashMap<K, T, S, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<Map>(self, map: Map) -> Result<Self::Value, Map::Error>
+    where
+        Map: MapAccess<'de>,
+    {
+        let capacity = cmp::min(map.size_hint().unwrap_or(0), MAX_PREALLOCATED_ENTRIES);
+        let mut values = HomogeneousHashMap::with_capacity_and_hasher_in(capacity, S::default(), A::default());
+        extend_from_map_access(&mut values, map)?;
+
+        Ok(values)
+    }
+}
+
+impl<'de, K, T, S, A> Deserialize<'de> for HomogeneousHashMap<K, T, S, A>
+where
+    K: any::Any + hash::Hash + Eq + Deserialize<'de>,
+    T: any::Any + Deserialize<'de>,
+    S: any::Any + hash::BuildHasher + Send + Sync + Default,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Default,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        let visitor = HomogeneousHashMapVisitor {
+            _marker: marker::PhantomData,
+        };
+
+        deserializer.deserialize_map(visitor)
+    }
+}
+
+/// A deserialization seed that inserts every deserialized entry into an existing homogeneous
+/// hash map.
+///
+/// Entries whose keys already exist in the map have their values replaced, in the same way as
+/// [`HomogeneousHashMap::insert`].
+pub(crate) struct HomogeneousHashMapExtendSeed<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    map: &'a mut HomogeneousHashMap<K, T, S, A>,
+}
+
+impl<'a, K, T, S, A> HomogeneousHashMapExtendSeed<'a, K, T, S, A>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    /// Constructs a new deserialization seed extending the given map.
+    pub(crate) const fn new(map: &'a mut HomogeneousHashMap<K, T, S, A>) -> Self {
+        Self { map }
+    }
+}
+
+impl<'de, K, T, S, A> Visitor<'de> for HomogeneousHashMapExtendSeed<'_, K, T, S, A>
+where
+    K: any::Any + hash::Hash + Eq + Deserialize<'de>,
+    T: any::Any + Deserialize<'de>,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<Map>(self, map: Map) -> Result<Self::Value, Map::Error>
+    where
+        Map: MapAccess<'de>,
+    {
+        extend_from_map_access(self.map, map)
+    }
+}
+
+impl<'de, K, T, S, A> DeserializeSeed<'de> for HomogeneousHashMapExtendSeed<'_, K, T, S, A>
+where
+    K: any::Any + hash::Hash + Eq + Deserialize<'de>,
+    T: any::Any + Deserialize<'de>,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    type Value = ();
+
+    fn deserialize<De>(self, deserializer: De) -> Result<Self::Value, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+fn extend_from_map_access<'de, K, T, S, A, Map>(
+    values: &mut HomogeneousHashMap<K, T, S, A>,
+    mut map: Map,
+) -> Result<(), Map::Error>
+where
+    K: any::Any + hash::Hash + Eq + Deserialize<'de>,
+    T: any::Any + Deserialize<'de>,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+    Map: MapAccess<'de>,
+{
+    while let Some((key, value)) = map.next_entry()? {
+        values.insert(key, value);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,666 @@
+use crate::error::{
+    TryReserveError,
+    TryReserveErrorKind,
+};
+use crate::type_tuple::TypeTuple;
+
+use alloc_crate::vec::Vec;
+use core::any;
+use core::fmt;
+use core::iter;
+use core::marker;
+use core::mem;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+use hashbrown::hash_map;
+
+/// The number of type bits stored in each word of a type signature.
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+/// The function that sets or clears the bit of a type in the signature of a key.
+type UpdateSignature<K, S, A> = fn(&mut TypeSignatureIndex<K, S, A>, &K, usize);
+
+/// The function that clones a type signature index.
+type CloneIndex<K, S, A> = fn(&TypeSignatureIndex<K, S, A>) -> TypeSignatureIndex<K, S, A>;
+
+/// An index from each key of a heterogeneous hash map to the signature of the types that have a
+/// value stored under that key.
+///
+/// A type signature is a bitset with one bit for every type registered in the heterogeneous hash
+/// map. The index answers queries such as "which keys have a value of all of these types?" by
+/// scanning the stored signatures, instead of probing the storage of every type for every key.
+///
+/// The index is opt-in. A heterogeneous hash map builds its index the first time
+/// [`HeterogeneousHashMap::signature_index`] is called, and from then on keeps it up to date as
+/// types and values are inserted and removed. Operations that hand out mutable access to the
+/// storage of a type, such as [`HeterogeneousHashMap::get_map_mut`] or
+/// [`HeterogeneousHashMap::entry`], cannot be tracked key by key, so they mark that type as
+/// stale instead, and the next call to [`HeterogeneousHashMap::signature_index`] rescans only
+/// the stale types.
+///
+/// [`HeterogeneousHashMap::signature_index`]: crate::HeterogeneousHashMap::signature_index
+/// [`HeterogeneousHashMap::get_map_mut`]: crate::HeterogeneousHashMap::get_map_mut
+/// [`HeterogeneousHashMap::entry`]: crate::HeterogeneousHashMap::entry
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 1_i32);
+/// het_map.insert(Key::new(1_usize), 1.5_f64);
+/// het_map.insert(Key::new(2_usize), 2_i32);
+/// het_map.insert(Key::new(3_usize), 3.5_f64);
+///
+/// let index = het_map.signature_index();
+///
+/// let with_all: Vec<usize> = index.keys_with_all::<(i32, f64)>().copied().collect();
+/// let mut with_any: Vec<usize> = index.keys_with_any::<(i32, f64)>().copied().collect();
+/// with_any.sort();
+/// let without: Vec<usize> = index.keys_without::<(f64,)>().copied().collect();
+///
+/// assert_eq!(with_all, [1]);
+/// assert_eq!(with_any, [1, 2, 3]);
+/// assert_eq!(without, [2]);
+///
+/// het_map.remove::<f64, _>(&1_usize);
+/// het_map.insert(Key::new(2_usize), 2.5_f64);
+///
+/// let index = het_map.signature_index();
+///
+/// let with_all: Vec<usize> = index.keys_with_all::<(i32, f64)>().copied().collect();
+///
+/// assert_eq!(with_all, [2]);
+/// ```
+pub struct TypeSignatureIndex<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    bits: hash_map::HashMap<any::TypeId, usize, S>,
+    free_bits: Vec<usize>,
+    stale: Vec<any::TypeId>,
+    rows: hash_map::HashMap<K, usize, S>,
+    keys: Vec<K>,
+    signatures: Vec<u64>,
+    words_per_signature: usize,
+    set_bit: UpdateSignature<K, S, A>,
+    clear_bit: UpdateSignature<K, S, A>,
+    clone: CloneIndex<K, S, A>,
+    _marker: marker::PhantomData<A>,
+}
+
+impl<K, S, A> TypeSignatureIndex<K, S, A>
+where
+    K: any::Any + Clone + hash::Hash + Eq,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new type signature index over the given types, with no keys.
+    ///
+    /// Every type starts out stale, so the first refresh of the index records the keys already
+    /// stored in the heterogeneous hash map. The operations that need to hash, compare, or clone
+    /// keys are captured here, so that the heterogeneous hash map can maintain the index from
+    /// methods that do not require these bounds of the key type.
+    pub(crate) fn with_types<I>(type_ids: I, build_hasher: S) -> Self
+    where
+        I: IntoIterator<Item = any::TypeId>,
+    {
+        let mut index = Self {
+            bits: hash_map::HashMap::with_hasher(build_hasher.clone()),
+            free_bits: Vec::new(),
+            stale: Vec::new(),
+            rows: hash_map::HashMap::with_hasher(build_hasher),
+            keys: Vec::new(),
+            signatures: Vec::new(),
+            words_per_signature: 0,
+            set_bit: Self::set_bit_of,
+            clear_bit: Self::clear_bit_of,
+            clone: Self::clone_of,
+            _marker: marker::PhantomData,
+        };
+        for type_id in type_ids {
+            index.insert_type(type_id);
+        }
+        index.mark_all_stale();
+
+        index
+    }
+
+    fn set_bit_of(index: &mut Self, key: &K, bit: usize) {
+        let row = match index.rows.get(key) {
+            Some(row) => *row,
+            None => {
+                let row = index.keys.len();
+                index.rows.insert(key.clone(), row);
+                index.keys.push(key.clone());
+                index.signatures.extend(iter::repeat_n(0, index.words_per_signature));
+
+                row
+            }
+        };
+
+        index.signatures[row * index.words_per_signature + bit / BITS_PER_WORD] |= 1 << (bit % BITS_PER_WORD);
+    }
+
+    fn clear_bit_of(index: &mut Self, key: &K, bit: usize) {
+        let Some(row) = index.rows.get(key).copied() else {
+            return;
+        };
+
+        let words = index.words_per_signature;
+        let signature = &mut index.signatures[(row * words)..((row + 1) * words)];
+        signature[bit / BITS_PER_WORD] &= !(1 << (bit % BITS_PER_WORD));
+        if signature.iter().any(|word| *word != 0) {
+            return;
+        }
+
+        // The key has no value of any type left, so its row is replaced by the last row.
+        let last = index.keys.len() - 1;
+        index.rows.remove(key);
+        index.keys.swap_remove(row);
+        if row != last {
+            index
+                .signatures
+                .copy_within((last * words)..((last + 1) * words), row * words);
+            *index
+                .rows
+                .get_mut(&index.keys[row])
+                .expect("Every indexed key must have a row") = row;
+        }
+        index.signatures.truncate(last * words);
+    }
+
+    fn clone_of(index: &Self) -> Self {
+        Self {
+            bits: index.bits.clone(),
+            free_bits: index.free_bits.clone(),
+            stale: index.stale.clone(),
+            rows: index.rows.clone(),
+            keys: index.keys.clone(),
+            signatures: index.signatures.clone(),
+            words_per_signature: index.words_per_signature,
+            set_bit: index.set_bit,
+            clear_bit: index.clear_bit,
+            clone: index.clone,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<K, S, A> TypeSignatureIndex<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Assigns a signature bit to the type with the given [`TypeId`], if it does not have one.
+    ///
+    /// The bits of removed types are reused before the signatures are widened.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn insert_type(&mut self, type_id: any::TypeId) {
+        if self.bits.contains_key(&type_id) {
+            return;
+        }
+
+        let bit = self.free_bits.pop().unwrap_or(self.bits.len());
+        self.bits.insert(type_id, bit);
+
+        let words_per_signature = bit / BITS_PER_WORD + 1;
+        if words_per_signature > self.words_per_signature {
+            self.widen(words_per_signature);
+        }
+    }
+
+    /// Releases the signature bit of the type with the given [`TypeId`], clearing it from the
+    /// signature of every key.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn remove_type(&mut self, type_id: any::TypeId) {
+        self.clear_types(&[type_id]);
+        self.stale.retain(|stale_type_id| *stale_type_id != type_id);
+        if let Some(bit) = self.bits.remove(&type_id) {
+            self.free_bits.push(bit);
+        }
+    }
+
+    /// Clears the bits of the types with the given [`TypeId`]s from the signature of every key,
+    /// keeping the bits assigned to the types.
+    ///
+    /// This method makes a single pass over the signatures, however many types are cleared.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn clear_types(&mut self, type_ids: &[any::TypeId]) {
+        let mut mask: Vec<u64> = iter::repeat_n(0, self.words_per_signature).collect();
+        for type_id in type_ids {
+            if let Some(bit) = self.bits.get(type_id) {
+                mask[bit / BITS_PER_WORD] |= 1 << (bit % BITS_PER_WORD);
+            }
+        }
+        if mask.iter().all(|word| *word == 0) {
+            return;
+        }
+
+        for signature in self.signatures.chunks_exact_mut(self.words_per_signature) {
+            for (word, mask_word) in signature.iter_mut().zip(mask.iter()) {
+                *word &= !mask_word;
+            }
+        }
+
+        self.retain_rows(|signature| signature.iter().any(|word| *word != 0));
+    }
+
+    /// Removes every key from the type signature index, keeping the bits of the types.
+    pub(crate) fn clear_keys(&mut self) {
+        self.rows.clear();
+        self.keys.clear();
+        self.signatures.clear();
+        self.stale.clear();
+    }
+
+    /// Removes every key and every type from the type signature index.
+    pub(crate) fn clear(&mut self) {
+        self.clear_keys();
+        self.bits.clear();
+        self.free_bits.clear();
+    }
+
+    /// Records that the given key has a value of the type with the given [`TypeId`].
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn insert(&mut self, type_id: any::TypeId, key: &K) {
+        if let Some(bit) = self.bits.get(&type_id).copied() {
+            (self.set_bit)(self, key, bit);
+        }
+    }
+
+    /// Records that the given key no longer has a value of the type with the given [`TypeId`].
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn remove(&mut self, type_id: any::TypeId, key: &K) {
+        if let Some(bit) = self.bits.get(&type_id).copied() {
+            (self.clear_bit)(self, key, bit);
+        }
+    }
+
+    /// Marks the type with the given [`TypeId`] as stale, so that its bits are recomputed from
+    /// the storage of the type on the next refresh.
+    ///
+    /// A type may be marked as stale before it is inserted, since its storage is scanned only if
+    /// it exists when the index is refreshed.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    pub(crate) fn mark_stale(&mut self, type_id: any::TypeId) {
+        if !self.stale.contains(&type_id) {
+            self.stale.push(type_id);
+        }
+    }
+
+    /// Marks every type in the type signature index as stale.
+    pub(crate) fn mark_all_stale(&mut self) {
+        self.stale.clear();
+        self.stale.extend(self.bits.keys().copied());
+    }
+
+    /// Returns the stale types, leaving none marked as stale.
+    pub(crate) fn take_stale(&mut self) -> Vec<any::TypeId> {
+        mem::take(&mut self.stale)
+    }
+
+    /// Widens every signature to the given number of words, in place.
+    fn widen(&mut self, words_per_signature: usize) {
+        let old_words = self.words_per_signature;
+        self.signatures.resize(self.keys.len() * words_per_signature, 0);
+        for row in (0..self.keys.len()).rev() {
+            let old_start = row * old_words;
+            let new_start = row * words_per_signature;
+            self.signatures.copy_within(old_start..(old_start + old_words), new_start);
+            self.signatures[(new_start + old_words)..(new_start + words_per_signature)].fill(0);
+        }
+
+        self.words_per_signature = words_per_signature;
+    }
+
+    /// Tries to reserve the memory needed to assign a signature bit to one more type, so that a
+    /// following call to [`insert_type`] does not allocate.
+    ///
+    /// [`insert_type`]: TypeSignatureIndex::insert_type
+    pub(crate) fn try_reserve_type(&mut self) -> Result<(), TryReserveError> {
+        self.bits.try_reserve(1).map_err(TryReserveError::from_hashbrown)?;
+        if self.free_bits.is_empty() && self.bits.len() / BITS_PER_WORD + 1 > self.words_per_signature {
+            let additional_words = self.keys.len();
+            self.signatures
+                .try_reserve(additional_words)
+                .map_err(|_| TryReserveError::from_vec::<u64>(self.signatures.len(), additional_words))?;
+        }
+
+        Ok(())
+    }
+
+    /// Retains only the rows whose signatures satisfy the given predicate, preserving the order
+    /// of the remaining rows.
+    fn retain_rows<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&[u64]) -> bool,
+    {
+        let words = self.words_per_signature;
+        let mut new_rows = Vec::with_capacity(self.keys.len());
+        let mut len = 0;
+        for row in 0..self.keys.len() {
+            if keep(&self.signatures[(row * words)..((row + 1) * words)]) {
+                self.signatures.copy_within((row * words)..((row + 1) * words), len * words);
+                new_rows.push(Some(len));
+                len += 1;
+            } else {
+                new_rows.push(None);
+            }
+        }
+
+        if len == self.keys.len() {
+            return;
+        }
+
+        self.signatures.truncate(len * words);
+        let mut row = 0;
+        self.keys.retain(|_| {
+            row += 1;
+            new_rows[row - 1].is_some()
+        });
+        self.rows.retain(|_, row| match new_rows[*row] {
+            Some(new_row) => {
+                *row = new_row;
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Returns an iterator over the keys whose signatures satisfy the given predicate.
+    fn keys_matching<'a, F>(&'a self, mut predicate: F) -> impl Iterator<Item = &'a K> + 'a
+    where
+        F: FnMut(&[u64]) -> bool + 'a,
+    {
+        let words = self.words_per_signature;
+
+        self.keys.iter().enumerate().filter_map(move |(row, key)| {
+            let signature = &self.signatures[(row * words)..((row + 1) * words)];
+
+            if predicate(signature) { Some(key) } else { None }
+        })
+    }
+
+    /// Returns the number of distinct keys in the type signature index.
+    ///
+    /// This is the number of keys that have a value of at least one type in the heterogeneous
+    /// hash map.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    /// het_map.insert(Key::new(1_usize), 1.5_f64);
+    /// het_map.insert(Key::new(2_usize), 2_i32);
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// assert_eq!(index.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Determines whether the type signature index contains no keys.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// assert!(het_map.signature_index().is_empty());
+    ///
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    ///
+    /// assert!(!het_map.signature_index().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<K, S, A> TypeSignatureIndex<K, S, A>
+where
+    K: any::Any + hash::Hash + Eq,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Tries to reserve capacity for at least `additional` more keys in the type signature
+    /// index, so that recording the types of that many new keys does not allocate.
+    ///
+    /// Cloning a key into the index can still allocate if the clone of the key type allocates.
+    pub(crate) fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.rows.try_reserve(additional).map_err(TryReserveError::from_hashbrown)?;
+        self.keys
+            .try_reserve(additional)
+            .map_err(|_| TryReserveError::from_vec::<K>(self.keys.len(), additional))?;
+        let additional_words = additional
+            .checked_mul(self.words_per_signature)
+            .ok_or(TryReserveError::from(TryReserveErrorKind::CapacityOverflow))?;
+        self.signatures
+            .try_reserve(additional_words)
+            .map_err(|_| TryReserveError::from_vec::<u64>(self.signatures.len(), additional_words))?;
+
+        Ok(())
+    }
+
+    /// Returns the signature mask of the types in the tuple `Types`, ignoring the types that do
+    /// not exist in the index, together with whether every type exists in the index.
+    fn mask<Types>(&self) -> (Vec<u64>, bool)
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        let mut mask: Vec<u64> = iter::repeat_n(0, self.words_per_signature).collect();
+        let mut all_exist = true;
+        for type_id in Types::type_ids() {
+            match self.bits.get(&type_id) {
+                Some(bit) => mask[bit / BITS_PER_WORD] |= 1 << (bit % BITS_PER_WORD),
+                None => all_exist = false,
+            }
+        }
+
+        (mask, all_exist)
+    }
+
+    /// Returns an iterator over the keys that have a value of every type in the tuple `Types`.
+    ///
+    /// If a type in the tuple does not exist in the heterogeneous hash map, no key has a value of
+    /// that type, so the iterator is empty. The iterator yields the keys in an arbitrary order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n * (t / 64))** time, where `n` is the number of keys in
+    /// the index, and `t` is the number of types in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    /// het_map.insert(Key::new(1_usize), 1.5_f64);
+    /// het_map.insert(Key::new(1_usize), String::from("one"));
+    /// het_map.insert(Key::new(2_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 2.5_f64);
+    /// het_map.insert(Key::new(3_usize), 3_i32);
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// let mut keys: Vec<usize> = index.keys_with_all::<(i32, f64)>().copied().collect();
+    /// keys.sort();
+    ///
+    /// assert_eq!(keys, [1, 2]);
+    /// assert_eq!(index.keys_with_all::<(i32, f64, String)>().collect::<Vec<_>>(), [&1]);
+    /// assert_eq!(index.keys_with_all::<(i32, u8)>().count(), 0);
+    /// ```
+    pub fn keys_with_all<Types>(&self) -> impl Iterator<Item = &K> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        let (mask, all_exist) = self.mask::<Types>();
+
+        self.keys_matching(move |signature| {
+            all_exist
+                && signature
+                    .iter()
+                    .zip(mask.iter())
+                    .all(|(word, mask_word)| word & mask_word == *mask_word)
+        })
+    }
+
+    /// Returns an iterator over the keys that have a value of at least one type in the tuple
+    /// `Types`.
+    ///
+    /// The types in the tuple that do not exist in the heterogeneous hash map are ignored. The
+    /// iterator yields the keys in an arbitrary order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n * (t / 64))** time, where `n` is the number of keys in
+    /// the index, and `t` is the number of types in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    /// het_map.insert(Key::new(2_usize), 2.5_f64);
+    /// het_map.insert(Key::new(3_usize), String::from("three"));
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// let mut keys: Vec<usize> = index.keys_with_any::<(i32, f64, u8)>().copied().collect();
+    /// keys.sort();
+    ///
+    /// assert_eq!(keys, [1, 2]);
+    /// assert_eq!(index.keys_with_any::<(u8,)>().count(), 0);
+    /// ```
+    pub fn keys_with_any<Types>(&self) -> impl Iterator<Item = &K> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        let (mask, _) = self.mask::<Types>();
+
+        self.keys_matching(move |signature| {
+            signature
+                .iter()
+                .zip(mask.iter())
+                .any(|(word, mask_word)| word & mask_word != 0)
+        })
+    }
+
+    /// Returns an iterator over the keys that have no value of any type in the tuple `Types`.
+    ///
+    /// Only keys that have a value of some other type in the heterogeneous hash map are yielded.
+    /// The types in the tuple that do not exist in the heterogeneous hash map are ignored. The
+    /// iterator yields the keys in an arbitrary order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n * (t / 64))** time, where `n` is the number of keys in
+    /// the index, and `t` is the number of types in the heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    /// het_map.insert(Key::new(1_usize), 1.5_f64);
+    /// het_map.insert(Key::new(2_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), String::from("three"));
+    ///
+    /// let index = het_map.signature_index();
+    ///
+    /// let mut keys: Vec<usize> = index.keys_without::<(f64,)>().copied().collect();
+    /// keys.sort();
+    ///
+    /// assert_eq!(keys, [2, 3]);
+    /// assert_eq!(index.keys_without::<(i32, String)>().collect::<Vec<_>>(), Vec::<&usize>::new());
+    /// ```
+    pub fn keys_without<Types>(&self) -> impl Iterator<Item = &K> + '_
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        let (mask, _) = self.mask::<Types>();
+
+        self.keys_matching(move |signature| {
+            signature
+                .iter()
+                .zip(mask.iter())
+                .all(|(word, mask_word)| word & mask_word == 0)
+        })
+    }
+}
+
+impl<K, S, A> Clone for TypeSignatureIndex<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn clone(&self) -> Self {
+        (self.clone)(self)
+    }
+}
+
+impl<K, S, A> fmt::Debug for TypeSignatureIndex<K, S, A>
+where
+    K: any::Any + fmt::Debug,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("TypeSignatureIndex")
+            .field("keys", &self.keys)
+            .field("types", &self.bits.len())
+            .finish()
+    }
+}
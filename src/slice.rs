@@ -0,0 +1,742 @@
+use crate::iterator::{
+    Iter,
+    IterMut,
+    Keys,
+    Values,
+    ValuesMut,
+};
+use crate::key::Key;
+
+use core::cmp;
+use core::fmt;
+use core::ops;
+
+/// A dynamically-sized slice of the entries of a homogeneous hash map.
+///
+/// A slice is a borrowed view of a contiguous range of entries of a homogeneous hash map, in
+/// storage order. Slices are created by the [`HomogeneousHashMap::as_slice`],
+/// [`HomogeneousHashMap::as_mut_slice`], and [`HomogeneousHashMap::get_range`] methods, and by
+/// the [`HeterogeneousHashMap::as_slice`] method for a given type.
+///
+/// Unlike the iterators over a homogeneous hash map, a slice supports indexing by position, and
+/// can be split into sub-slices. This makes slices useful for divide-and-conquer processing, and
+/// for paginating over the entries of a map.
+///
+/// [`HomogeneousHashMap::as_slice`]: crate::HomogeneousHashMap::as_slice
+/// [`HomogeneousHashMap::as_mut_slice`]: crate::HomogeneousHashMap::as_mut_slice
+/// [`HomogeneousHashMap::get_range`]: crate::HomogeneousHashMap::get_range
+/// [`HeterogeneousHashMap::as_slice`]: crate::HeterogeneousHashMap::as_slice
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+/// #
+/// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+///     (Key::new("foo"),  1_i32),
+///     (Key::new("bar"),  2_i32),
+///     (Key::new("baz"),  3_i32),
+///     (Key::new("quux"), 4_i32),
+/// ]);
+/// let slice = map.get_range(1..3).unwrap();
+///
+/// assert_eq!(slice.len(), 2);
+/// assert_eq!(slice.first(), Some((&Key::new("bar"), &2_i32)));
+/// assert_eq!(slice.last(), Some((&Key::new("baz"), &3_i32)));
+/// ```
+#[repr(transparent)]
+pub struct Slice<K, T> {
+    inner: opaque::index_map::map::Slice<Key<K, T>, T>,
+}
+
+impl<K, T> Slice<K, T> {
+    /// Constructs a new slice.
+    #[inline]
+    pub(crate) const fn from_inner_ref(slice: &opaque::index_map::map::Slice<Key<K, T>, T>) -> &Self {
+        // SAFETY: `Slice` is `#[repr(transparent)]` over `opaque::index_map::map::Slice<Key<K, T>, T>`,
+        // so both types have the same layout and the same pointer metadata. The returned reference
+        // borrows from `slice`, so it has the same lifetime and the same aliasing guarantees.
+        unsafe { &*(slice as *const opaque::index_map::map::Slice<Key<K, T>, T> as *const Self) }
+    }
+
+    /// Constructs a new mutable slice.
+    #[inline]
+    pub(crate) const fn from_inner_ref_mut(slice: &mut opaque::index_map::map::Slice<Key<K, T>, T>) -> &mut Self {
+        // SAFETY: `Slice` is `#[repr(transparent)]` over `opaque::index_map::map::Slice<Key<K, T>, T>`,
+        // so both types have the same layout and the same pointer metadata. The returned reference
+        // reborrows `slice` uniquely, so no other reference to the entries can exist while it lives.
+        unsafe { &mut *(slice as *mut opaque::index_map::map::Slice<Key<K, T>, T> as *mut Self) }
+    }
+
+    /// Returns the number of entries in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.get_range(..).unwrap().len(), 3);
+    /// assert_eq!(map.get_range(1..).unwrap().len(), 2);
+    /// assert_eq!(map.get_range(3..).unwrap().len(), 0);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Determines whether the slice is empty.
+    ///
+    /// This method returns `true` if the slice contains no entries. This method returns `false`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    /// ]);
+    ///
+    /// assert!(!map.get_range(..).unwrap().is_empty());
+    /// assert!(map.get_range(2..).unwrap().is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns a reference to the key-value pair stored at the given index in the slice, if it
+    /// exists.
+    ///
+    /// The index of an entry in a slice is relative to the start of the slice. This method
+    /// returns `None` if `index >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.get_range(1..).unwrap();
+    ///
+    /// assert_eq!(slice.get_index(0), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(slice.get_index(1), Some((&Key::new("baz"), &3_i32)));
+    /// assert_eq!(slice.get_index(2), None);
+    /// ```
+    #[inline]
+    pub fn get_index(&self, index: usize) -> Option<(&Key<K, T>, &T)> {
+        self.inner.get_index(index)
+    }
+
+    /// Returns a reference to the key and a mutable reference to the value of the key-value
+    /// pair stored at the given index in the slice, if it exists.
+    ///
+    /// The index of an entry in a slice is relative to the start of the slice. This method
+    /// returns `None` if `index >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.as_mut_slice();
+    ///
+    /// if let Some((_key, value)) = slice.get_index_mut(1) {
+    ///     *value = 20_i32;
+    /// }
+    ///
+    /// assert_eq!(slice.get_index(1), Some((&Key::new("bar"), &20_i32)));
+    /// assert_eq!(slice.get_index_mut(3), None);
+    /// ```
+    #[inline]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&Key<K, T>, &mut T)> {
+        self.inner.get_index_mut(index)
+    }
+
+    /// Returns a sub-slice of the entries in the given range of indices, if the range is in
+    /// bounds.
+    ///
+    /// The indices of the range are relative to the start of the slice. This method returns
+    /// `None` if the starting point of the range is greater than the end point, or the end point
+    /// is greater than `self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.as_slice();
+    /// let sub_slice = slice.get_range(1..).unwrap();
+    ///
+    /// assert_eq!(sub_slice.first(), Some((&Key::new("bar"), &2_i32)));
+    /// assert!(slice.get_range(2..4).is_none());
+    /// ```
+    #[inline]
+    pub fn get_range<R>(&self, range: R) -> Option<&Self>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        self.inner.get_range(range).map(Self::from_inner_ref)
+    }
+
+    /// Returns a mutable sub-slice of the entries in the given range of indices, if the range
+    /// is in bounds.
+    ///
+    /// The indices of the range are relative to the start of the slice. This method returns
+    /// `None` if the starting point of the range is greater than the end point, or the end point
+    /// is greater than `self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let slice = map.as_mut_slice();
+    ///
+    /// for value in slice.get_range_mut(1..).unwrap().values_mut() {
+    ///     *value *= 10_i32;
+    /// }
+    ///
+    /// assert_eq!(map.get_index(0), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_index(1), Some((&Key::new("bar"), &20_i32)));
+    /// assert_eq!(map.get_index(2), Some((&Key::new("baz"), &30_i32)));
+    /// ```
+    #[inline]
+    pub fn get_range_mut<R>(&mut self, range: R) -> Option<&mut Self>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        self.inner.get_range_mut(range).map(Self::from_inner_ref_mut)
+    }
+
+    /// Returns a reference to the first key-value pair in the slice, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.get_range(1..).unwrap().first(), Some((&Key::new("bar"), &2_i32)));
+    /// assert_eq!(map.get_range(2..).unwrap().first(), None);
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<(&Key<K, T>, &T)> {
+        self.inner.first()
+    }
+
+    /// Returns a reference to the last key-value pair in the slice, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    /// ]);
+    ///
+    /// assert_eq!(map.get_range(..1).unwrap().last(), Some((&Key::new("foo"), &1_i32)));
+    /// assert_eq!(map.get_range(..0).unwrap().last(), None);
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<(&Key<K, T>, &T)> {
+        self.inner.last()
+    }
+
+    /// Returns a reference to the key and a mutable reference to the value of the first
+    /// key-value pair in the slice, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// if let Some((_key, value)) = map.as_mut_slice().first_mut() {
+    ///     *value = 10_i32;
+    /// }
+    ///
+    /// assert_eq!(map.first(), Some((&Key::new("foo"), &10_i32)));
+    /// ```
+    #[inline]
+    pub fn first_mut(&mut self) -> Option<(&Key<K, T>, &mut T)> {
+        self.inner.first_mut()
+    }
+
+    /// Returns a reference to the key and a mutable reference to the value of the last
+    /// key-value pair in the slice, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// if let Some((_key, value)) = map.as_mut_slice().last_mut() {
+    ///     *value = 30_i32;
+    /// }
+    ///
+    /// assert_eq!(map.last(), Some((&Key::new("baz"), &30_i32)));
+    /// ```
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<(&Key<K, T>, &mut T)> {
+        self.inner.last_mut()
+    }
+
+    /// Divides the slice into two sub-slices at the given index.
+    ///
+    /// The first sub-slice contains the entries in the index range `[0, index)`, and the second
+    /// sub-slice contains the entries in the index range `[index, len)`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let (left, right) = map.as_slice().split_at(1);
+    ///
+    /// assert_eq!(left.len(), 1);
+    /// assert_eq!(right.len(), 2);
+    /// assert_eq!(right.first(), Some((&Key::new("bar"), &2_i32)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split_at(&self, index: usize) -> (&Self, &Self) {
+        let (left, right) = self.inner.split_at(index);
+
+        (Self::from_inner_ref(left), Self::from_inner_ref(right))
+    }
+
+    /// Divides the mutable slice into two mutable sub-slices at the given index.
+    ///
+    /// The first sub-slice contains the entries in the index range `[0, index)`, and the second
+    /// sub-slice contains the entries in the index range `[index, len)`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index > self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let (left, right) = map.as_mut_slice().split_at_mut(1);
+    ///
+    /// for value in left.values_mut() {
+    ///     *value += 100_i32;
+    /// }
+    /// for value in right.values_mut() {
+    ///     *value += 200_i32;
+    /// }
+    ///
+    /// let values: Vec<i32> = map.values().copied().collect();
+    ///
+    /// assert_eq!(values, [101_i32, 202_i32, 203_i32]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn split_at_mut(&mut self, index: usize) -> (&mut Self, &mut Self) {
+        let (left, right) = self.inner.split_at_mut(index);
+
+        (Self::from_inner_ref_mut(left), Self::from_inner_ref_mut(right))
+    }
+
+    /// Returns an iterator over the entries of the slice, in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let values: Vec<i32> = map.get_range(1..).unwrap().iter().map(|(_k, v)| *v).collect();
+    ///
+    /// assert_eq!(values, [2_i32, 3_i32]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, T> {
+        Iter::new(self.inner.iter())
+    }
+
+    /// Returns a mutable iterator over the entries of the slice, in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// for (_key, value) in map.as_mut_slice().iter_mut() {
+    ///     *value *= 2_i32;
+    /// }
+    ///
+    /// let values: Vec<i32> = map.values().copied().collect();
+    ///
+    /// assert_eq!(values, [2_i32, 4_i32, 6_i32]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, T> {
+        IterMut::new(self.inner.iter_mut())
+    }
+
+    /// Returns an iterator over the keys of the slice, in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let keys: Vec<&str> = map.as_slice()[1..].keys().map(|key| *key.id()).collect();
+    ///
+    /// assert_eq!(keys, ["bar", "baz"]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, T> {
+        Keys::new(self.inner.keys())
+    }
+
+    /// Returns an iterator over the values of the slice, in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    /// let values: Vec<i32> = map.as_slice()[..2].values().copied().collect();
+    ///
+    /// assert_eq!(values, [1_i32, 2_i32]);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, T> {
+        Values::new(self.inner.values())
+    }
+
+    /// Returns a mutable iterator over the values of the slice, in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<&str, i32> = HomogeneousHashMap::from([
+    ///     (Key::new("foo"), 1_i32),
+    ///     (Key::new("bar"), 2_i32),
+    ///     (Key::new("baz"), 3_i32),
+    /// ]);
+    ///
+    /// for value in map.as_mut_slice()[1..].values_mut() {
+    ///     *value = 0_i32;
+    /// }
+    ///
+    /// let values: Vec<i32> = map.values().copied().collect();
+    ///
+    /// assert_eq!(values, [1_i32, 0_i32, 0_i32]);
+    /// ```
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, T> {
+        ValuesMut::new(self.inner.values_mut())
+    }
+
+    /// Searches a slice sorted by key for the given key using binary search.
+    ///
+    /// This method returns `Ok(index)` if the key is found, where `index` is the index of the
+    /// matching entry in the slice. This method returns `Err(index)` if the key is not found,
+    /// where `index` is the index at which an entry with the given key could be inserted while
+    /// keeping the slice sorted. If the slice is not sorted by key, the result is unspecified.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(log(n))** time, where `n` is the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<i32, i32> = HomogeneousHashMap::from([
+    ///     (Key::new(5_i32), 50_i32),
+    ///     (Key::new(1_i32), 10_i32),
+    ///     (Key::new(3_i32), 30_i32),
+    /// ]);
+    /// map.sort_keys();
+    /// let slice = map.as_slice();
+    ///
+    /// assert_eq!(slice.binary_search_keys(&Key::new(3_i32)), Ok(1));
+    /// assert_eq!(slice.binary_search_keys(&Key::new(4_i32)), Err(2));
+    /// ```
+    #[inline]
+    pub fn binary_search_keys(&self, key: &Key<K, T>) -> Result<usize, usize>
+    where
+        K: Ord,
+    {
+        self.inner.binary_search_keys(key)
+    }
+
+    /// Searches a sorted slice using binary search with the given comparison function.
+    ///
+    /// The comparison function receives each key-value pair, and returns the ordering of that
+    /// entry relative to the target. This method returns `Ok(index)` if a matching entry is
+    /// found, and `Err(index)` with the insertion point of the target otherwise. If the slice is
+    /// not sorted consistently with the comparison function, the result is unspecified.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(log(n))** time, where `n` is the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<i32, i32> = HomogeneousHashMap::from([
+    ///     (Key::new(5_i32), 50_i32),
+    ///     (Key::new(1_i32), 10_i32),
+    ///     (Key::new(3_i32), 30_i32),
+    /// ]);
+    /// map.sort_keys();
+    /// let slice = map.as_slice();
+    ///
+    /// assert_eq!(slice.binary_search_by(|_key, value| value.cmp(&50_i32)), Ok(2));
+    /// assert_eq!(slice.binary_search_by(|_key, value| value.cmp(&0_i32)), Err(0));
+    /// ```
+    #[inline]
+    pub fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a Key<K, T>, &'a T) -> cmp::Ordering,
+    {
+        self.inner.binary_search_by(f)
+    }
+
+    /// Searches a sorted slice using binary search with the given key extraction function.
+    ///
+    /// This method returns `Ok(index)` if an entry whose extracted key equals `b` is found, and
+    /// `Err(index)` with the insertion point of `b` otherwise. If the slice is not sorted by the
+    /// extracted key, the result is unspecified.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(log(n))** time, where `n` is the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<i32, i32> = HomogeneousHashMap::from([
+    ///     (Key::new(5_i32), 50_i32),
+    ///     (Key::new(1_i32), 10_i32),
+    ///     (Key::new(3_i32), 30_i32),
+    /// ]);
+    /// map.sort_keys();
+    /// let slice = map.as_slice();
+    ///
+    /// assert_eq!(slice.binary_search_by_key(&30_i32, |_key, value| *value), Ok(1));
+    /// assert_eq!(slice.binary_search_by_key(&60_i32, |_key, value| *value), Err(3));
+    /// ```
+    #[inline]
+    pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a Key<K, T>, &'a T) -> B,
+        B: Ord,
+    {
+        self.inner.binary_search_by_key(b, f)
+    }
+
+    /// Returns the index of the partition point of a slice partitioned by the given predicate.
+    ///
+    /// The slice is assumed to be partitioned such that every entry for which the predicate
+    /// returns `true` precedes every entry for which the predicate returns `false`. This method
+    /// returns the index of the first entry for which the predicate returns `false`.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(log(n))** time, where `n` is the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, HomogeneousHashMap};
+    /// #
+    /// let mut map: HomogeneousHashMap<i32, i32> = HomogeneousHashMap::from([
+    ///     (Key::new(5_i32), 50_i32),
+    ///     (Key::new(1_i32), 10_i32),
+    ///     (Key::new(3_i32), 30_i32),
+    /// ]);
+    /// map.sort_keys();
+    /// let slice = map.as_slice();
+    ///
+    /// assert_eq!(slice.partition_point(|key, _value| *key.id() < 4_i32), 2);
+    /// ```
+    #[inline]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&Key<K, T>, &T) -> bool,
+    {
+        self.inner.partition_point(pred)
+    }
+}
+
+impl<K, T> PartialEq for Slice<K, T>
+where
+    K: PartialEq,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K, T> Eq for Slice<K, T>
+where
+    K: Eq,
+    T: Eq,
+{
+}
+
+impl<K, T> fmt::Debug for Slice<K, T>
+where
+    K: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<K, T> ops::Index<usize> for Slice<K, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.inner[index]
+    }
+}
+
+impl<K, T> ops::IndexMut<usize> for Slice<K, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.inner[index]
+    }
+}
+
+macro_rules! impl_index_range {
+    ($($range:ty),* $(,)?) => {
+        $(
+            impl<K, T> ops::Index<$range> for Slice<K, T> {
+                type Output = Slice<K, T>;
+
+                fn index(&self, range: $range) -> &Self::Output {
+                    Slice::from_inner_ref(&self.inner[range])
+                }
+            }
+
+            impl<K, T> ops::IndexMut<$range> for Slice<K, T> {
+                fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                    Slice::from_inner_ref_mut(&mut self.inner[range])
+                }
+            }
+        )*
+    };
+}
+
+impl_index_range!(
+    ops::Range<usize>,
+    ops::RangeFrom<usize>,
+    ops::RangeFull,
+    ops::RangeInclusive<usize>,
+    ops::RangeTo<usize>,
+    ops::RangeToInclusive<usize>,
+);
+
+impl<'a, K, T> IntoIterator for &'a Slice<K, T> {
+    type Item = (&'a Key<K, T>, &'a T);
+    type IntoIter = Iter<'a, K, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, T> IntoIterator for &'a mut Slice<K, T> {
+    type Item = (&'a Key<K, T>, &'a mut T);
+    type IntoIter = IterMut<'a, K, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,751 @@
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::key::Key;
+use crate::metadata::TypeMetadata;
+use crate::vtable::TypeVTable;
+
+use core::any;
+use core::fmt;
+use core::marker;
+use opaque::index_map::TypeErasedIndexMap;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+/// A type-erased view of the storage of one type in a heterogeneous hash map.
+///
+/// A type storage exposes the entries of one type in a heterogeneous hash map without naming the
+/// type at compile time. Every operation is dispatched through the type-specific operations
+/// captured when the type was inserted into the map, so the values are seen as [`Any`] trait
+/// objects. If the concrete type is known, [`downcast_ref`] recovers the homogeneous hash map
+/// storing the values. Type storages are passed to a [`TypeVisitor`] by
+/// [`HeterogeneousHashMap::for_each_type`].
+///
+/// [`Any`]: core::any::Any
+/// [`downcast_ref`]: TypeStorage::downcast_ref
+/// [`HeterogeneousHashMap::for_each_type`]: crate::HeterogeneousHashMap::for_each_type
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+/// # use core::any::type_name;
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(3_usize), 4_i32);
+/// het_map.insert(Key::new(1_usize), String::from("foo"));
+///
+/// let mut lengths = Vec::new();
+/// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+///     lengths.push((String::from(storage.metadata().type_name()), storage.len()));
+/// });
+/// lengths.sort();
+/// let mut expected = [(String::from(type_name::<String>()), 1), (String::from(type_name::<i32>()), 2)];
+/// expected.sort();
+///
+/// assert_eq!(lengths, expected);
+/// ```
+pub struct TypeStorage<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    metadata: TypeMetadata,
+    map: &'a TypeErasedIndexMap,
+    vtable: &'a TypeVTable,
+    _marker: marker::PhantomData<(K, S, A)>,
+}
+
+impl<'a, K, S, A> TypeStorage<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new type-erased view of the storage of one type in a heterogeneous hash map.
+    pub(crate) const fn new(metadata: TypeMetadata, map: &'a TypeErasedIndexMap, vtable: &'a TypeVTable) -> Self {
+        Self {
+            metadata,
+            map,
+            vtable,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns the metadata of the type stored in the type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeMetadata, TypeStorage};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     assert_eq!(storage.metadata(), TypeMetadata::of::<i32>());
+    /// });
+    /// ```
+    pub const fn metadata(&self) -> TypeMetadata {
+        self.metadata
+    }
+
+    /// Returns the number of values stored in the type storage.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     assert_eq!(storage.len(), 2);
+    /// });
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vtable.len_map(self.map)
+    }
+
+    /// Determines whether the type storage contains no values.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     assert!(storage.is_empty());
+    /// });
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a type-erased reference to the value stored under the given key, if it exists.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time once values of the type have been inserted with
+    /// a method that hashes keys, such as [`HeterogeneousHashMap::insert`], and in **O(n)** time
+    /// otherwise, where `n` is the number of values in the type storage.
+    ///
+    /// [`HeterogeneousHashMap::insert`]: crate::HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     assert_eq!(storage.get(&1_usize).and_then(|value| value.downcast_ref::<i32>()), Some(&2_i32));
+    ///     assert!(storage.get(&3_usize).is_none());
+    /// });
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&'a dyn any::Any>
+    where
+        K: Eq,
+    {
+        self.vtable.get_value(self.map, key)
+    }
+
+    /// Returns an iterator over the keys and type-erased values in the type storage, in storage
+    /// order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n)** time, where `n` is the number of values in the
+    /// type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     let entries: Vec<(usize, i32)> = storage
+    ///         .iter()
+    ///         .map(|(key, value)| (*key, *value.downcast_ref::<i32>().unwrap()))
+    ///         .collect();
+    ///
+    ///     assert_eq!(entries, [(1, 2), (3, 4)]);
+    /// });
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'a K, &'a dyn any::Any)> + use<'a, K, S, A> {
+        self.vtable.iter_map(self.map).map(|(key, value)| {
+            let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+
+            (key, value)
+        })
+    }
+
+    /// Returns a reference to the homogeneous hash map storing the values, if the type storage
+    /// stores values of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+    ///     assert!(storage.downcast_ref::<u32>().is_none());
+    ///
+    ///     let map = storage.downcast_ref::<i32>().unwrap();
+    ///
+    ///     assert_eq!(map.get(&1_usize), Some(&2_i32));
+    /// });
+    /// ```
+    pub fn downcast_ref<T>(&self) -> Option<&'a HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        if self.metadata.type_id() != any::TypeId::of::<T>() {
+            return None;
+        }
+
+        Some(HomogeneousHashMap::from_inner_ref(self.map.as_proj::<Key<K, T>, T, S, A>()))
+    }
+}
+
+impl<K, S, A> fmt::Debug for TypeStorage<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("TypeStorage")
+            .field("metadata", &self.metadata)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// A mutable type-erased view of the storage of one type in a heterogeneous hash map.
+///
+/// This is the mutable counterpart of [`TypeStorage`]. Mutable type storages are passed to a
+/// [`TypeVisitorMut`] by [`HeterogeneousHashMap::for_each_type_mut`].
+///
+/// [`HeterogeneousHashMap::for_each_type_mut`]: crate::HeterogeneousHashMap::for_each_type_mut
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(1_usize), 3_u64);
+///
+/// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+///     for (_key, value) in storage.iter_mut() {
+///         if let Some(value) = value.downcast_mut::<i32>() {
+///             *value *= 10;
+///         } else if let Some(value) = value.downcast_mut::<u64>() {
+///             *value *= 100;
+///         }
+///     }
+/// });
+///
+/// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&20_i32));
+/// assert_eq!(het_map.get::<u64, _>(&1_usize), Some(&300_u64));
+/// ```
+pub struct TypeStorageMut<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    metadata: TypeMetadata,
+    map: &'a mut TypeErasedIndexMap,
+    vtable: &'a TypeVTable,
+    _marker: marker::PhantomData<(K, S, A)>,
+}
+
+impl<'a, K, S, A> TypeStorageMut<'a, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new mutable type-erased view of the storage of one type in a heterogeneous
+    /// hash map.
+    pub(crate) const fn new(metadata: TypeMetadata, map: &'a mut TypeErasedIndexMap, vtable: &'a TypeVTable) -> Self {
+        Self {
+            metadata,
+            map,
+            vtable,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns the metadata of the type stored in the type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeMetadata, TypeStorageMut};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     assert_eq!(storage.metadata(), TypeMetadata::of::<i32>());
+    /// });
+    /// ```
+    pub const fn metadata(&self) -> TypeMetadata {
+        self.metadata
+    }
+
+    /// Returns the number of values stored in the type storage.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     assert_eq!(storage.len(), 2);
+    /// });
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vtable.len_map(self.map)
+    }
+
+    /// Determines whether the type storage contains no values.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(1)** time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     assert!(storage.is_empty());
+    /// });
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a type-erased reference to the value stored under the given key, if it exists.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time once values of the type have been inserted with
+    /// a method that hashes keys, such as [`HeterogeneousHashMap::insert`], and in **O(n)** time
+    /// otherwise, where `n` is the number of values in the type storage.
+    ///
+    /// [`HeterogeneousHashMap::insert`]: crate::HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     assert_eq!(storage.get(&1_usize).and_then(|value| value.downcast_ref::<i32>()), Some(&2_i32));
+    ///     assert!(storage.get(&3_usize).is_none());
+    /// });
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&dyn any::Any>
+    where
+        K: Eq,
+    {
+        self.vtable.get_value(self.map, key)
+    }
+
+    /// Returns a type-erased mutable reference to the value stored under the given key, if it
+    /// exists.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in average **O(1)** time once values of the type have been inserted with
+    /// a method that hashes keys, such as [`HeterogeneousHashMap::insert`], and in **O(n)** time
+    /// otherwise, where `n` is the number of values in the type storage.
+    ///
+    /// [`HeterogeneousHashMap::insert`]: crate::HeterogeneousHashMap::insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     if let Some(value) = storage.get_mut(&1_usize).and_then(|value| value.downcast_mut::<i32>()) {
+    ///         *value = 5;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&5_i32));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut dyn any::Any>
+    where
+        K: Eq,
+    {
+        self.vtable.get_value_mut(self.map, key)
+    }
+
+    /// Returns an iterator over the keys and type-erased values in the type storage, in storage
+    /// order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n)** time, where `n` is the number of values in the
+    /// type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     let keys: Vec<usize> = storage.iter().map(|(key, _value)| *key).collect();
+    ///
+    ///     assert_eq!(keys, [1, 3]);
+    /// });
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &dyn any::Any)> {
+        self.vtable.iter_map(self.map).map(|(key, value)| {
+            let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+
+            (key, value)
+        })
+    }
+
+    /// Returns an iterator over the keys and type-erased mutable values in the type storage, in
+    /// storage order.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// Iterating to completion runs in **O(n)** time, where `n` is the number of values in the
+    /// type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(3_usize), 4_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     for (key, value) in storage.iter_mut() {
+    ///         *value.downcast_mut::<i32>().unwrap() += *key as i32;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&7_i32));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut dyn any::Any)> {
+        self.vtable.iter_map_mut(self.map).map(|(key, value)| {
+            let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+
+            (key, value)
+        })
+    }
+
+    /// Converts the type storage into an iterator over its keys and type-erased mutable values,
+    /// in storage order.
+    pub(crate) fn into_iter_mut(self) -> impl Iterator<Item = (&'a K, &'a mut dyn any::Any)> + 'a {
+        self.vtable.iter_map_mut(self.map).map(|(key, value)| {
+            let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+
+            (key, value)
+        })
+    }
+
+    /// Removes every value from the type storage, without changing its capacity.
+    ///
+    /// # Complexity Characteristics
+    ///
+    /// This method runs in **O(n)** time, where `n` is the number of values in the type storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), 3_u64);
+    ///
+    /// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     if storage.metadata().type_id() == core::any::TypeId::of::<i32>() {
+    ///         storage.clear();
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    /// assert_eq!(het_map.len::<u64>(), Some(1));
+    /// ```
+    pub fn clear(&mut self) {
+        self.vtable.clear_map(self.map);
+    }
+
+    /// Returns a reference to the homogeneous hash map storing the values, if the type storage
+    /// stores values of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     assert!(storage.downcast_ref::<u32>().is_none());
+    ///     assert_eq!(storage.downcast_ref::<i32>().unwrap().get(&1_usize), Some(&2_i32));
+    /// });
+    /// ```
+    pub fn downcast_ref<T>(&self) -> Option<&HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        if self.metadata.type_id() != any::TypeId::of::<T>() {
+            return None;
+        }
+
+        Some(HomogeneousHashMap::from_inner_ref(self.map.as_proj::<Key<K, T>, T, S, A>()))
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map storing the values, if the type
+    /// storage stores values of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut};
+    /// #
+    /// let mut het_map = HeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+    ///     if let Some(map) = storage.downcast_mut::<i32>() {
+    ///         map.insert(Key::new(3_usize), 4_i32);
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&4_i32));
+    /// ```
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        if self.metadata.type_id() != any::TypeId::of::<T>() {
+            return None;
+        }
+
+        Some(HomogeneousHashMap::from_inner_ref_mut(
+            self.map.as_proj_mut::<Key<K, T>, T, S, A>(),
+        ))
+    }
+}
+
+impl<K, S, A> fmt::Debug for TypeStorageMut<'_, K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("TypeStorageMut")
+            .field("metadata", &self.metadata)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// A visitor over the storage of every type in a heterogeneous hash map.
+///
+/// A type visitor is passed to [`HeterogeneousHashMap::for_each_type`], which calls
+/// [`visit`] once with the [`TypeStorage`] of each type in the map. This lets generic tools such
+/// as inspectors, exporters, and invariant checkers walk the whole map without a compile-time
+/// list of its types. Every closure taking a [`TypeStorage`] is a type visitor.
+///
+/// [`HeterogeneousHashMap::for_each_type`]: crate::HeterogeneousHashMap::for_each_type
+/// [`visit`]: TypeVisitor::visit
+///
+/// # Examples
+///
+/// ```
+/// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorage, TypeVisitor};
+/// # use std::hash::RandomState;
+/// # #[cfg(feature = "nightly")]
+/// # use std::alloc::Global;
+/// # #[cfg(not(feature = "nightly"))]
+/// # use opaque::allocator_api::alloc::Global;
+/// #
+/// struct CountEntries {
+///     count: usize,
+/// }
+///
+/// impl TypeVisitor<usize, RandomState, Global> for CountEntries {
+///     fn visit(&mut self, storage: TypeStorage<'_, usize, RandomState, Global>) {
+///         self.count += storage.len();
+///     }
+/// }
+///
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(3_usize), 4_i32);
+/// het_map.insert(Key::new(1_usize), String::from("foo"));
+///
+/// let mut visitor = CountEntries { count: 0 };
+/// het_map.for_each_type(&mut visitor);
+///
+/// assert_eq!(visitor.count, 3);
+/// ```
+pub trait TypeVisitor<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Visits the storage of one type in a heterogeneous hash map.
+    fn visit(&mut self, storage: TypeStorage<'_, K, S, A>);
+}
+
+impl<K, S, A, F> TypeVisitor<K, S, A> for F
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    F: FnMut(TypeStorage<'_, K, S, A>),
+{
+    fn visit(&mut self, storage: TypeStorage<'_, K, S, A>) {
+        self(storage)
+    }
+}
+
+/// A visitor over the mutable storage of every type in a heterogeneous hash map.
+///
+/// A mutable type visitor is passed to [`HeterogeneousHashMap::for_each_type_mut`], which calls
+/// [`visit_mut`] once with the [`TypeStorageMut`] of each type in the map. Every closure taking a
+/// [`TypeStorageMut`] is a mutable type visitor.
+///
+/// [`HeterogeneousHashMap::for_each_type_mut`]: crate::HeterogeneousHashMap::for_each_type_mut
+/// [`visit_mut`]: TypeVisitorMut::visit_mut
+///
+/// # Examples
+///
+/// ```
+/// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key, TypeStorageMut, TypeVisitorMut};
+/// # use std::hash::RandomState;
+/// # #[cfg(feature = "nightly")]
+/// # use std::alloc::Global;
+/// # #[cfg(not(feature = "nightly"))]
+/// # use opaque::allocator_api::alloc::Global;
+/// #
+/// struct ClearAll;
+///
+/// impl TypeVisitorMut<usize, RandomState, Global> for ClearAll {
+///     fn visit_mut(&mut self, mut storage: TypeStorageMut<'_, usize, RandomState, Global>) {
+///         storage.clear();
+///     }
+/// }
+///
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(1_usize), String::from("foo"));
+///
+/// het_map.for_each_type_mut(&mut ClearAll);
+///
+/// assert_eq!(het_map.len::<i32>(), Some(0));
+/// assert_eq!(het_map.len::<String>(), Some(0));
+/// ```
+pub trait TypeVisitorMut<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Visits the mutable storage of one type in a heterogeneous hash map.
+    fn visit_mut(&mut self, storage: TypeStorageMut<'_, K, S, A>);
+}
+
+impl<K, S, A, F> TypeVisitorMut<K, S, A> for F
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    F: FnMut(TypeStorageMut<'_, K, S, A>),
+{
+    fn visit_mut(&mut self, storage: TypeStorageMut<'_, K, S, A>) {
+        self(storage)
+    }
+}
@@ -0,0 +1,1902 @@
+use crate::entry::Entry;
+use crate::error::TryReserveError;
+use crate::heterogeneous_hash_map::HeterogeneousHashMap;
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::iterator::{
+    Drain,
+    ExtractIf,
+    IterMut,
+    ValuesMut,
+};
+use crate::key::Key;
+use crate::signature_index::TypeSignatureIndex;
+use crate::type_tuple::TypeTuple;
+
+use core::any;
+use core::borrow::Borrow;
+use core::cmp;
+use core::fmt;
+use core::marker;
+use core::ops;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+/// A marker that opts a type out of the auto traits [`Send`] and [`Sync`], so that the thread
+/// safety of the type is decided solely by its explicit implementations.
+type NotSendSync<M> = marker::PhantomData<(M, *const ())>;
+
+mod sealed {
+    pub trait Sealed {
+        /// The name of the thread-safe heterogeneous hash maps with this thread safety marker.
+        const NAME: &'static str;
+    }
+}
+
+/// A marker for the thread safety of a [`ThreadSafeHeterogeneousHashMap`].
+///
+/// This trait is sealed, and is implemented by [`SendOnly`] and [`SendSync`] only. Every type
+/// admitted by either marker is at least [`Send`].
+pub trait ThreadSafety: sealed::Sealed {}
+
+/// A thread safety marker that admits the type `T` into a [`ThreadSafeHeterogeneousHashMap`].
+///
+/// [`SendOnly`] admits the types that are [`Send`], and [`SendSync`] admits the types that are
+/// [`Send`] and [`Sync`].
+pub trait Admits<T>: ThreadSafety {}
+
+/// The thread safety marker of a [`SendHeterogeneousHashMap`], which admits the types that are
+/// [`Send`].
+pub enum SendOnly {}
+
+impl sealed::Sealed for SendOnly {
+    const NAME: &'static str = "SendHeterogeneousHashMap";
+}
+
+impl ThreadSafety for SendOnly {}
+
+impl<T> Admits<T> for SendOnly where T: Send {}
+
+/// The thread safety marker of a [`SyncHeterogeneousHashMap`], which admits the types that are
+/// [`Send`] and [`Sync`].
+pub enum SendSync {}
+
+impl sealed::Sealed for SendSync {
+    const NAME: &'static str = "SyncHeterogeneousHashMap";
+}
+
+impl ThreadSafety for SendSync {}
+
+impl<T> Admits<T> for SendSync where T: Send + Sync {}
+
+/// A heterogeneous hash map whose values are all admitted by the thread safety marker `M`.
+///
+/// A thread-safe heterogeneous hash map wraps a [`HeterogeneousHashMap`], and only admits the
+/// types `T` for which `M` implements [`Admits<T>`]. Every method that can insert a new type into
+/// the map requires it, so the compiler checks every insertion site. The marker is either
+/// [`SendOnly`] or [`SendSync`], and the map is used through the aliases
+/// [`SendHeterogeneousHashMap`] and [`SyncHeterogeneousHashMap`] respectively.
+///
+/// A thread-safe heterogeneous hash map dereferences to the underlying heterogeneous hash map,
+/// which provides every read-only operation, and it provides the mutating operations of the
+/// underlying map itself, with an [`Admits`] bound on each one that can insert a new type. It does
+/// not dereference mutably to the underlying map, because that would allow inserting types that
+/// the marker does not admit. [`into_inner`] gives up the guarantee and returns the underlying
+/// map.
+///
+/// [`into_inner`]: ThreadSafeHeterogeneousHashMap::into_inner
+#[cfg(feature = "std")]
+pub struct ThreadSafeHeterogeneousHashMap<K, M, S = hash::RandomState, A = alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    pub(crate) inner: HeterogeneousHashMap<K, S, A>,
+    _marker: NotSendSync<M>,
+}
+
+#[cfg(not(feature = "std"))]
+pub struct ThreadSafeHeterogeneousHashMap<K, M, S, A = alloc::Global>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    pub(crate) inner: HeterogeneousHashMap<K, S, A>,
+    _marker: NotSendSync<M>,
+}
+
+/// A heterogeneous hash map whose values are all [`Send`], and which can therefore be sent to
+/// another thread.
+///
+/// A send heterogeneous hash map is a [`ThreadSafeHeterogeneousHashMap`] with the marker
+/// [`SendOnly`], so it only admits the types that are [`Send`]. The map implements [`Send`]
+/// whenever its key type does. The map does not implement [`Sync`], because its values need not
+/// be [`Sync`]. Use a [`SyncHeterogeneousHashMap`] to share a map between threads.
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+/// # use std::thread;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(1_usize), String::from("foo"));
+///
+/// let het_map = thread::spawn(move || {
+///     het_map.insert(Key::new(2_usize), 3_i32);
+///     het_map
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(het_map.len::<i32>(), Some(2));
+/// assert_eq!(het_map.get::<String, _>(&1_usize), Some(&String::from("foo")));
+/// ```
+#[cfg(feature = "std")]
+pub type SendHeterogeneousHashMap<K, S = hash::RandomState, A = alloc::Global> =
+    ThreadSafeHeterogeneousHashMap<K, SendOnly, S, A>;
+
+#[cfg(not(feature = "std"))]
+pub type SendHeterogeneousHashMap<K, S, A = alloc::Global> = ThreadSafeHeterogeneousHashMap<K, SendOnly, S, A>;
+
+/// A heterogeneous hash map whose values are all [`Send`] and [`Sync`], and which can therefore
+/// be sent to and shared between threads.
+///
+/// A sync heterogeneous hash map is a [`ThreadSafeHeterogeneousHashMap`] with the marker
+/// [`SendSync`], so it only admits the types that are [`Send`] and [`Sync`]. The map implements
+/// [`Send`] and [`Sync`] whenever its key type does.
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::thread;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(1_usize), String::from("foo"));
+///
+/// thread::scope(|scope| {
+///     scope.spawn(|| assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32)));
+///     scope.spawn(|| assert_eq!(het_map.get::<String, _>(&1_usize), Some(&String::from("foo"))));
+/// });
+/// ```
+#[cfg(feature = "std")]
+pub type SyncHeterogeneousHashMap<K, S = hash::RandomState, A = alloc::Global> =
+    ThreadSafeHeterogeneousHashMap<K, SendSync, S, A>;
+
+#[cfg(not(feature = "std"))]
+pub type SyncHeterogeneousHashMap<K, S, A = alloc::Global> = ThreadSafeHeterogeneousHashMap<K, SendSync, S, A>;
+
+/// Compile-fail tests for [`SendHeterogeneousHashMap`].
+///
+/// A type that is not [`Send`] cannot be inserted into the map by any method.
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.insert_type::<Rc<i32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), Rc::new(2_i32));
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.get_or_insert_map_mut::<Rc<i32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.extend([(Key::new(1_usize), Rc::new(2_i32))]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.entry_or_insert_type(Key::new(1_usize)).or_insert(Rc::new(2_i32));
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// het_map.reserve::<Rc<i32>>(10);
+/// ```
+///
+/// The underlying map cannot be borrowed mutably.
+///
+/// ```compile_fail,E0596
+/// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+/// (*het_map).insert(Key::new(1_usize), Rc::new(2_i32));
+/// ```
+///
+/// The map cannot be shared between threads.
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+/// #
+/// fn assert_sync<T: Sync>() {}
+///
+/// assert_sync::<SendHeterogeneousHashMap<usize>>();
+/// ```
+#[cfg(doctest)]
+struct SendHeterogeneousHashMapCompileFail;
+
+/// Compile-fail tests for [`SyncHeterogeneousHashMap`].
+///
+/// A type that is not [`Sync`] cannot be inserted into the map by any method, even if it is
+/// [`Send`].
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SyncHeterogeneousHashMap;
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.insert_type::<Cell<i32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), Cell::new(2_i32));
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::SyncHeterogeneousHashMap;
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.get_or_insert_map_mut::<Cell<i32>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.extend([(Key::new(1_usize), Cell::new(2_i32))]);
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.entry_or_insert_type(Key::new(1_usize)).or_insert(Cell::new(2_i32));
+/// ```
+///
+/// A type that is not [`Send`] cannot be inserted into the map either.
+///
+/// ```compile_fail,E0277
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::rc::Rc;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), Rc::new(2_i32));
+/// ```
+///
+/// The underlying map cannot be borrowed mutably.
+///
+/// ```compile_fail,E0596
+/// # use heterogeneous_hash_map::{Key, SyncHeterogeneousHashMap};
+/// # use std::cell::Cell;
+/// #
+/// let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+/// (*het_map).insert(Key::new(1_usize), Cell::new(2_i32));
+/// ```
+#[cfg(doctest)]
+struct SyncHeterogeneousHashMapCompileFail;
+
+// SAFETY: Every type in a thread-safe heterogeneous hash map is `Send`, because every method
+// that can insert a type requires the marker to admit it, every marker admits only `Send` types,
+// and the underlying map cannot be borrowed mutably. The key type is `Send` by the bound below,
+// the hash builder and the memory allocator are `Send` by the bounds of the map, and the
+// remaining state of the underlying map consists of type metadata, function pointers, and views
+// that are `Send + Sync`.
+unsafe impl<K, M, S, A> Send for ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any + Send,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+}
+
+// SAFETY: Every type in a sync heterogeneous hash map is `Send + Sync`, because every method that
+// can insert a type requires the marker `SendSync` to admit it, and the underlying map cannot be
+// borrowed mutably. The key type is `Send + Sync` by the bound below, the hash builder and the
+// memory allocator are `Send + Sync` by the bounds of the map, and the remaining state of the
+// underlying map consists of type metadata, function pointers, and views that are `Send + Sync`.
+unsafe impl<K, S, A> Sync for ThreadSafeHeterogeneousHashMap<K, SendSync, S, A>
+where
+    K: any::Any + Send + Sync,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+}
+
+impl<K, M, S, A> ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Constructs a new empty thread-safe heterogeneous hash map with the custom hash builder and
+    /// the custom memory allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "nightly", feature(allocator_api))]
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// # #[cfg(feature = "nightly")]
+    /// # use std::alloc::Global;
+    /// # #[cfg(not(feature = "nightly"))]
+    /// # use opaque::allocator_api::alloc::Global;
+    /// #
+    /// let het_map: SendHeterogeneousHashMap<usize, RandomState, Global> = SendHeterogeneousHashMap::with_hasher_in(RandomState::new(), Global);
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// ```
+    #[must_use]
+    pub fn with_hasher_in(build_hasher: S, alloc: A) -> Self {
+        Self {
+            inner: HeterogeneousHashMap::with_hasher_in(build_hasher, alloc),
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<K, M, S> ThreadSafeHeterogeneousHashMap<K, M, S, alloc::Global>
+where
+    K: any::Any,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+{
+    /// Constructs a new empty thread-safe heterogeneous hash map with the custom hash builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// # use std::hash::RandomState;
+    /// #
+    /// let het_map: SendHeterogeneousHashMap<usize, RandomState> = SendHeterogeneousHashMap::with_hasher(RandomState::new());
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// ```
+    #[must_use]
+    pub fn with_hasher(build_hasher: S) -> Self {
+        Self::with_hasher_in(build_hasher, alloc::Global)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, M> ThreadSafeHeterogeneousHashMap<K, M, hash::RandomState, alloc::Global>
+where
+    K: any::Any,
+    M: ThreadSafety,
+{
+    /// Constructs a new empty thread-safe heterogeneous hash map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(hash::RandomState::new())
+    }
+}
+
+impl<K, M, S, A> ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Returns the underlying heterogeneous hash map, giving up the guarantee that every type
+    /// stored in it is admitted by its thread safety marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// let mut inner = het_map.into_inner();
+    /// inner.insert(Key::new(1_usize), std::rc::Rc::new(3_i32));
+    ///
+    /// assert_eq!(inner.len_types(), 2);
+    /// ```
+    pub fn into_inner(self) -> HeterogeneousHashMap<K, S, A> {
+        self.inner
+    }
+
+    /// Inserts a new type into the thread-safe heterogeneous hash map.
+    ///
+    /// This method returns `true` if the type `T` did not exist in the map, and `false` if it
+    /// did. See [`HeterogeneousHashMap::insert_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.insert_type::<i32>());
+    /// assert!(!het_map.insert_type::<i32>());
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    /// ```
+    pub fn insert_type<T>(&mut self) -> bool
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.insert_type::<T>()
+    }
+
+    /// Inserts a new type into the thread-safe heterogeneous hash map with a given minimum type
+    /// capacity.
+    ///
+    /// This method returns `true` if the type `T` did not exist in the map, and `false` if it
+    /// did. See [`HeterogeneousHashMap::insert_type_with_capacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.insert_type_with_capacity::<i32>(10));
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// ```
+    pub fn insert_type_with_capacity<T>(&mut self, capacity: usize) -> bool
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.insert_type_with_capacity::<T>(capacity)
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map storing the values of the type
+    /// `T`, if the type exists in the map.
+    ///
+    /// See [`HeterogeneousHashMap::get_map_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// if let Some(map) = het_map.get_map_mut::<i32>() {
+    ///     map.insert(Key::new(3_usize), 4_i32);
+    /// }
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert!(het_map.get_map_mut::<u64>().is_none());
+    /// ```
+    pub fn get_map_mut<T>(&mut self) -> Option<&mut HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.inner.get_map_mut::<T>()
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map storing the values of the type
+    /// `T`, inserting the type into the map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::get_or_insert_map_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// het_map.get_or_insert_map_mut::<i32>().insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// ```
+    pub fn get_or_insert_map_mut<T>(&mut self) -> &mut HomogeneousHashMap<K, T, S, A>
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.get_or_insert_map_mut::<T>()
+    }
+
+    /// Inserts a value of the type `T` into the thread-safe heterogeneous hash map under the given
+    /// key, inserting the type into the map first if it does not exist.
+    ///
+    /// This method returns `Some(old_value)` if the map already contained a value of the type
+    /// `T` with the same key, and `None` otherwise. See [`HeterogeneousHashMap::insert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.insert(Key::new(1_usize), 2_i32), None);
+    /// assert_eq!(het_map.insert(Key::new(1_usize), 3_i32), Some(2_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn insert<T>(&mut self, key: Key<K, T>, value: T) -> Option<T>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.insert(key, value)
+    }
+
+    /// Returns a mutable reference to the value of the type `T` with the given key, if it
+    /// exists.
+    ///
+    /// See [`HeterogeneousHashMap::get_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// if let Some(value) = het_map.get_mut::<i32, _>(&1_usize) {
+    ///     *value = 3_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn get_mut<T, Q>(&mut self, key: &Q) -> Option<&mut T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.get_mut::<T, Q>(key)
+    }
+
+    /// Removes the value of the type `T` with the given key from the thread-safe heterogeneous hash
+    /// map, and returns it, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.remove::<i32, _>(&1_usize), Some(2_i32));
+    /// assert_eq!(het_map.remove::<i32, _>(&1_usize), None);
+    /// ```
+    pub fn remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.remove::<T, Q>(key)
+    }
+
+    /// Removes the type `T` from the thread-safe heterogeneous hash map, together with every value
+    /// of that type.
+    ///
+    /// This method returns `Some(count)`, where `count` is the number of values removed, if the
+    /// type `T` existed in the map. See [`HeterogeneousHashMap::remove_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.remove_type::<i32>(), Some(1));
+    /// assert!(!het_map.contains_type::<i32>());
+    /// ```
+    pub fn remove_type<T>(&mut self) -> Option<usize>
+    where
+        T: any::Any,
+    {
+        self.inner.remove_type::<T>()
+    }
+
+    /// Removes the type `T` from the thread-safe heterogeneous hash map, and returns its values as
+    /// a homogeneous hash map, if the type exists in the map.
+    ///
+    /// See [`HeterogeneousHashMap::take_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// let map = het_map.take_type::<i32>().unwrap();
+    ///
+    /// assert_eq!(map.get(&1_usize), Some(&2_i32));
+    /// assert!(!het_map.contains_type::<i32>());
+    /// ```
+    pub fn take_type<T>(&mut self) -> Option<HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.inner.take_type::<T>()
+    }
+
+    /// Removes every type and every value from the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::clear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), 3_f64);
+    ///
+    /// het_map.clear();
+    ///
+    /// assert!(het_map.is_empty_types());
+    /// ```
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Tries to insert a new type into the thread-safe heterogeneous hash map with a given minimum
+    /// type capacity.
+    ///
+    /// See [`HeterogeneousHashMap::try_insert_type_with_capacity`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.try_insert_type_with_capacity::<i32>(10), Ok(true));
+    /// assert_eq!(het_map.try_insert_type_with_capacity::<i32>(10), Ok(false));
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// ```
+    pub fn try_insert_type_with_capacity<T>(&mut self, capacity: usize) -> Result<bool, TryReserveError>
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.try_insert_type_with_capacity::<T>(capacity)
+    }
+
+    /// Inserts a new type into the thread-safe heterogeneous hash map, and registers it for
+    /// cloning.
+    ///
+    /// See [`HeterogeneousHashMap::insert_cloneable_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.insert_cloneable_type::<i32>());
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// let cloned = het_map.try_clone().unwrap();
+    ///
+    /// assert_eq!(cloned.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// ```
+    pub fn insert_cloneable_type<T>(&mut self) -> bool
+    where
+        K: Clone,
+        T: any::Any + Clone,
+        M: Admits<T>,
+    {
+        self.inner.insert_cloneable_type::<T>()
+    }
+
+    /// Inserts a new type into the thread-safe heterogeneous hash map, and registers it for
+    /// equality comparisons.
+    ///
+    /// See [`HeterogeneousHashMap::insert_comparable_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map1: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// let mut het_map2: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map1.insert_comparable_type::<i32>());
+    /// assert!(het_map2.insert_comparable_type::<i32>());
+    /// het_map1.insert(Key::new(0_usize), 1_i32);
+    /// het_map2.insert(Key::new(0_usize), 1_i32);
+    ///
+    /// assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
+    /// ```
+    pub fn insert_comparable_type<T>(&mut self) -> bool
+    where
+        K: hash::Hash + Eq,
+        T: any::Any + PartialEq,
+        M: Admits<T>,
+    {
+        self.inner.insert_comparable_type::<T>()
+    }
+
+    /// Inserts a new type into the thread-safe heterogeneous hash map, and registers it for debug
+    /// formatting.
+    ///
+    /// See [`HeterogeneousHashMap::insert_debug_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.insert_debug_type::<i32>());
+    /// assert!(!het_map.insert_debug_type::<i32>());
+    /// assert!(het_map.contains_type::<i32>());
+    /// ```
+    pub fn insert_debug_type<T>(&mut self) -> bool
+    where
+        K: fmt::Debug,
+        T: any::Any + fmt::Debug,
+        M: Admits<T>,
+    {
+        self.inner.insert_debug_type::<T>()
+    }
+
+    /// Registers a view of the values of type `T` as the type `U`, inserting the type `T` into
+    /// the thread-safe heterogeneous hash map if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::register_as`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// # use core::fmt::Display;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.register_as::<i32, dyn Display>(|value| value));
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// let formatted: Vec<String> = het_map.get_as::<dyn Display>(&1_usize).map(|value| value.to_string()).collect();
+    ///
+    /// assert_eq!(formatted, ["2"]);
+    /// ```
+    pub fn register_as<T, U>(&mut self, upcast: fn(&T) -> &U) -> bool
+    where
+        T: any::Any,
+        M: Admits<T>,
+        U: ?Sized + 'static,
+    {
+        self.inner.register_as::<T, U>(upcast)
+    }
+
+    /// Registers a shared and a mutable view of the values of type `T` as the type `U`,
+    /// inserting the type `T` into the thread-safe heterogeneous hash map if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::register_as_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// # use core::ops::AddAssign;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.register_as_mut::<i32, dyn AddAssign<i32>>(|value| value, |value| value));
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// for (_, value) in het_map.iter_as_mut::<dyn AddAssign<i32>>() {
+    ///     *value += 1_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn register_as_mut<T, U>(&mut self, upcast: fn(&T) -> &U, upcast_mut: fn(&mut T) -> &mut U) -> bool
+    where
+        T: any::Any,
+        M: Admits<T>,
+        U: ?Sized + 'static,
+    {
+        self.inner.register_as_mut::<T, U>(upcast, upcast_mut)
+    }
+
+    /// Returns an iterator over the keys and mutable views of the values of every type
+    /// registered with a mutable view as the type `U` in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::iter_as_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// # use core::ops::AddAssign;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.register_as_mut::<i32, dyn AddAssign<i32>>(|value| value, |value| value);
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// for (_, value) in het_map.iter_as_mut::<dyn AddAssign<i32>>() {
+    ///     *value += 1_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn iter_as_mut<U>(&mut self) -> impl Iterator<Item = (&K, &mut U)> + '_
+    where
+        U: ?Sized + 'static,
+    {
+        self.inner.iter_as_mut::<U>()
+    }
+
+    /// Returns a mutable reference to the homogeneous hash map storing the values of the type
+    /// `T`, inserting the type into the map with a given minimum capacity first if it does not
+    /// exist.
+    ///
+    /// See [`HeterogeneousHashMap::get_or_insert_with_capacity_map_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// het_map.get_or_insert_with_capacity_map_mut::<i32>(10).insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// ```
+    pub fn get_or_insert_with_capacity_map_mut<T>(&mut self, capacity: usize) -> &mut HomogeneousHashMap<K, T, S, A>
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.get_or_insert_with_capacity_map_mut::<T>(capacity)
+    }
+
+    /// Returns mutable references to the homogeneous hash maps storing the values of every type
+    /// in the tuple `Types`, if every type exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::get_maps_mut`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the tuple `Types` contains the same type more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), 0.5_f64);
+    ///
+    /// let (ints, floats) = het_map.get_maps_mut::<(i32, f64)>().unwrap();
+    /// floats.insert(Key::new(1_usize), ints[&Key::new(1_usize)] as f64);
+    ///
+    /// assert_eq!(het_map.get::<f64, _>(&1_usize), Some(&2.0_f64));
+    /// assert!(het_map.get_maps_mut::<(i32, String)>().is_none());
+    /// ```
+    #[track_caller]
+    pub fn get_maps_mut<Types>(&mut self) -> Option<Types::MapsMut<'_>>
+    where
+        Types: TypeTuple<K, S, A>,
+    {
+        self.inner.get_maps_mut::<Types>()
+    }
+
+    /// Reserves capacity for at least `additional` more values of the type `T`, inserting the
+    /// type into the thread-safe heterogeneous hash map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::reserve`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the new capacity overflows `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// het_map.reserve::<i32>(10);
+    ///
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// ```
+    pub fn reserve<T>(&mut self, additional: usize)
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.reserve::<T>(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more values of the type `T`,
+    /// inserting the type into the thread-safe heterogeneous hash map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::try_reserve`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity overflows, or the allocator reports a
+    /// failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.try_reserve::<i32>(10).is_ok());
+    /// assert!(het_map.capacity::<i32>() >= Some(10));
+    /// ```
+    pub fn try_reserve<T>(&mut self, additional: usize) -> Result<(), TryReserveError>
+    where
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.try_reserve::<T>(additional)
+    }
+
+    /// Returns the signature index of the thread-safe heterogeneous hash map, building it on the
+    /// first call.
+    ///
+    /// See [`HeterogeneousHashMap::signature_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    /// het_map.insert(Key::new(1_usize), 1.5_f64);
+    /// het_map.insert(Key::new(2_usize), 2_i32);
+    ///
+    /// let keys: Vec<usize> = het_map.signature_index().keys_with_all::<(i32, f64)>().copied().collect();
+    ///
+    /// assert_eq!(keys, [1]);
+    /// ```
+    pub fn signature_index(&mut self) -> &TypeSignatureIndex<K, S, A>
+    where
+        K: Clone + hash::Hash + Eq,
+    {
+        self.inner.signature_index()
+    }
+
+    /// Drops the signature index of the thread-safe heterogeneous hash map, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::disable_signature_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 1_i32);
+    ///
+    /// assert_eq!(het_map.signature_index().len(), 1);
+    ///
+    /// het_map.disable_signature_index();
+    ///
+    /// assert_eq!(het_map.signature_index().len(), 1);
+    /// ```
+    pub fn disable_signature_index(&mut self) {
+        self.inner.disable_signature_index();
+    }
+
+    /// Removes every value from the thread-safe heterogeneous hash map, keeping every type.
+    ///
+    /// See [`HeterogeneousHashMap::clear_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.clear_values();
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    /// ```
+    pub fn clear_values(&mut self) {
+        self.inner.clear_values();
+    }
+
+    /// Shrinks the capacity of the thread-safe heterogeneous hash map as much as possible.
+    ///
+    /// See [`HeterogeneousHashMap::shrink_to_fit_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert_type_with_capacity::<i32>(100);
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.shrink_to_fit_all();
+    ///
+    /// assert!(het_map.capacity::<i32>() < Some(100));
+    /// ```
+    pub fn shrink_to_fit_all(&mut self) {
+        self.inner.shrink_to_fit_all();
+    }
+
+    /// Reserves capacity for at least `additional` more types in the thread-safe heterogeneous hash
+    /// map.
+    ///
+    /// See [`HeterogeneousHashMap::reserve_types`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// het_map.reserve_types(10);
+    ///
+    /// assert!(het_map.capacity_types() >= 10);
+    /// ```
+    pub fn reserve_types(&mut self, additional: usize) {
+        self.inner.reserve_types(additional);
+    }
+
+    /// Shrinks the capacity of the type tables of the thread-safe heterogeneous hash map as much as
+    /// possible.
+    ///
+    /// See [`HeterogeneousHashMap::shrink_types_to_fit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::SendHeterogeneousHashMap;
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.reserve_types(100);
+    /// het_map.insert_type::<i32>();
+    ///
+    /// het_map.shrink_types_to_fit();
+    ///
+    /// assert!(het_map.capacity_types() >= 1);
+    /// ```
+    pub fn shrink_types_to_fit(&mut self) {
+        self.inner.shrink_types_to_fit();
+    }
+
+    /// Returns the key and a mutable reference to the value of the type `T` with the given key,
+    /// if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::get_key_value_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// if let Some((key, value)) = het_map.get_key_value_mut::<i32, _>(&1_usize) {
+    ///     *value += *key.id() as i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn get_key_value_mut<T, Q>(&mut self, key: &Q) -> Option<(&Key<K, T>, &mut T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.get_key_value_mut::<T, Q>(key)
+    }
+
+    /// Returns mutable references to the values of the type `T` with the given keys, at once.
+    ///
+    /// See [`HeterogeneousHashMap::get_disjoint_mut`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any two of the given keys refer to the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(2_usize), 3_i32);
+    ///
+    /// if let [Some(a), Some(b)] = het_map.get_disjoint_mut::<i32, _, 2>([&1_usize, &2_usize]) {
+    ///     core::mem::swap(a, b);
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&2_i32));
+    /// ```
+    pub fn get_disjoint_mut<T, Q, const N: usize>(&mut self, ks: [&Q; N]) -> [Option<&'_ mut T>; N]
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.get_disjoint_mut::<T, Q, N>(ks)
+    }
+
+    /// Returns the key and a mutable reference to the value of the type `T` at the given
+    /// index, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::get_index_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// if let Some((_, value)) = het_map.get_index_mut::<i32>(0) {
+    ///     *value = 3_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// assert!(het_map.get_index_mut::<i32>(1).is_none());
+    /// ```
+    pub fn get_index_mut<T>(&mut self, index: usize) -> Option<(&Key<K, T>, &mut T)>
+    where
+        T: any::Any,
+    {
+        self.inner.get_index_mut::<T>(index)
+    }
+
+    /// Tries to insert a value of the type `T` into the thread-safe heterogeneous hash map under
+    /// the given key, inserting the type into the map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::try_insert`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert_eq!(het_map.try_insert(Key::new(1_usize), 2_i32), Ok(None));
+    /// assert_eq!(het_map.try_insert(Key::new(1_usize), 3_i32), Ok(Some(2_i32)));
+    /// ```
+    pub fn try_insert<T>(&mut self, key: Key<K, T>, value: T) -> Result<Option<T>, TryReserveError>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.try_insert(key, value)
+    }
+
+    /// Removes the values with the given key from every type in the thread-safe heterogeneous hash
+    /// map, and returns the number of values removed.
+    ///
+    /// See [`HeterogeneousHashMap::remove_key_everywhere`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// assert_eq!(het_map.remove_key_everywhere(&1_usize), 2);
+    /// assert_eq!(het_map.len_types(), 2);
+    /// ```
+    pub fn remove_key_everywhere(&mut self, key: &K) -> usize
+    where
+        K: Eq,
+    {
+        self.inner.remove_key_everywhere(key)
+    }
+
+    /// Removes the values with any of the given keys from every type in the thread-safe
+    /// heterogeneous hash map, and returns the number of values removed.
+    ///
+    /// See [`HeterogeneousHashMap::remove_keys_everywhere`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..4).map(|i| (Key::new(i), i as i32)));
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// assert_eq!(het_map.remove_keys_everywhere([1_usize, 2]), 3);
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// ```
+    pub fn remove_keys_everywhere<I>(&mut self, keys: I) -> usize
+    where
+        K: Eq,
+        I: IntoIterator,
+        I::Item: Borrow<K>,
+    {
+        self.inner.remove_keys_everywhere(keys)
+    }
+
+    /// Retains only the values whose keys satisfy the predicate, across every type in the send
+    /// heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::retain_keys`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..4).map(|i| (Key::new(i), i as i32)));
+    /// het_map.insert(Key::new(1_usize), String::from("foo"));
+    ///
+    /// het_map.retain_keys(|key| *key >= 2);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.len::<String>(), Some(0));
+    /// ```
+    pub fn retain_keys<F>(&mut self, keep: F)
+    where
+        F: FnMut(&K) -> bool,
+    {
+        self.inner.retain_keys(keep);
+    }
+
+    /// Removes the value of the type `T` with the given key from the thread-safe heterogeneous hash
+    /// map, and returns it together with its key, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::remove_entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// assert_eq!(het_map.remove_entry::<i32, _>(&1_usize), Some((Key::new(1_usize), 2_i32)));
+    /// assert_eq!(het_map.remove_entry::<i32, _>(&1_usize), None);
+    /// ```
+    pub fn remove_entry<T, Q>(&mut self, key: &Q) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.remove_entry::<T, Q>(key)
+    }
+
+    /// Removes the value of the type `T` with the given key from the thread-safe heterogeneous hash
+    /// map by swapping it with the last value of that type, and returns it, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::swap_remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.swap_remove::<i32, _>(&0_usize), Some(0_i32));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(2_usize), &2_i32)));
+    /// ```
+    pub fn swap_remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.swap_remove::<T, Q>(key)
+    }
+
+    /// Removes the value of the type `T` with the given key from the thread-safe heterogeneous hash
+    /// map by shifting every following value of that type, and returns it, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::shift_remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.shift_remove::<i32, _>(&0_usize), Some(0_i32));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(1_usize), &1_i32)));
+    /// ```
+    pub fn shift_remove<T, Q>(&mut self, key: &Q) -> Option<T>
+    where
+        T: any::Any,
+        Key<K, T>: Borrow<Q>,
+        Q: any::Any + hash::Hash + Eq + ?Sized,
+    {
+        self.inner.shift_remove::<T, Q>(key)
+    }
+
+    /// Removes the last value of the type `T` from the thread-safe heterogeneous hash map, and
+    /// returns it together with its key, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::pop`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..2).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.pop::<i32>(), Some((Key::new(1_usize), 1_i32)));
+    /// assert_eq!(het_map.pop::<i32>(), Some((Key::new(0_usize), 0_i32)));
+    /// assert_eq!(het_map.pop::<i32>(), None);
+    /// ```
+    pub fn pop<T>(&mut self) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        self.inner.pop::<T>()
+    }
+
+    /// Removes the value of the type `T` at the given index from the thread-safe heterogeneous hash
+    /// map by swapping it with the last value of that type, and returns it together with its
+    /// key, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::swap_remove_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.swap_remove_index::<i32>(0), Some((Key::new(0_usize), 0_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(2_usize), &2_i32)));
+    /// ```
+    pub fn swap_remove_index<T>(&mut self, index: usize) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        self.inner.swap_remove_index::<T>(index)
+    }
+
+    /// Removes the value of the type `T` at the given index from the thread-safe heterogeneous hash
+    /// map by shifting every following value of that type, and returns it together with its
+    /// key, if it exists.
+    ///
+    /// See [`HeterogeneousHashMap::shift_remove_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// assert_eq!(het_map.shift_remove_index::<i32>(0), Some((Key::new(0_usize), 0_i32)));
+    /// assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(1_usize), &1_i32)));
+    /// ```
+    pub fn shift_remove_index<T>(&mut self, index: usize) -> Option<(Key<K, T>, T)>
+    where
+        T: any::Any,
+    {
+        self.inner.shift_remove_index::<T>(index)
+    }
+
+    /// Returns the entry for the given key among the values of the type `T`, if the type exists
+    /// in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.insert_type::<i32>();
+    ///
+    /// *het_map.entry(Key::new(1_usize)).unwrap().or_insert(2_i32) += 1_i32;
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// assert!(het_map.entry::<u64>(Key::new(1_usize)).is_none());
+    /// ```
+    pub fn entry<T>(&mut self, key: Key<K, T>) -> Option<Entry<'_, K, T, A>>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+    {
+        self.inner.entry::<T>(key)
+    }
+
+    /// Returns the entry for the given key among the values of the type `T`, inserting the type
+    /// into the thread-safe heterogeneous hash map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::entry_or_insert_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// *het_map.entry_or_insert_type(Key::new(1_usize)).or_insert(2_i32) += 1_i32;
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    /// ```
+    pub fn entry_or_insert_type<T>(&mut self, key: Key<K, T>) -> Entry<'_, K, T, A>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+    {
+        self.inner.entry_or_insert_type::<T>(key)
+    }
+
+    /// Inserts one or more values of the type `T` into the thread-safe heterogeneous hash map from
+    /// an iterable, inserting the type into the map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::extend`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// het_map.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// ```
+    pub fn extend<I, T>(&mut self, iterable: I)
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+        I: IntoIterator<Item = (Key<K, T>, T)>,
+    {
+        self.inner.extend::<I, T>(iterable);
+    }
+
+    /// Tries to insert one or more values of the type `T` into the thread-safe heterogeneous hash
+    /// map from an iterable, inserting the type into the map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::try_extend`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the capacity of either the type table or the storage for
+    /// values of type `T` overflows, or the allocator reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    ///
+    /// assert!(het_map.try_extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]).is_ok());
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// ```
+    pub fn try_extend<I, T>(&mut self, iterable: I) -> Result<(), TryReserveError>
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+        I: IntoIterator<Item = (Key<K, T>, T)>,
+    {
+        self.inner.try_extend::<I, T>(iterable)
+    }
+
+    /// Sorts the values of the type `T` in the thread-safe heterogeneous hash map by their keys.
+    ///
+    /// See [`HeterogeneousHashMap::sort_keys`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).rev().map(|i| (Key::new(i), i as i32)));
+    ///
+    /// het_map.sort_keys::<i32>();
+    ///
+    /// assert_eq!(het_map.first::<i32>(), Some((&Key::new(0_usize), &0_i32)));
+    /// ```
+    pub fn sort_keys<T>(&mut self)
+    where
+        K: hash::Hash + Ord,
+        T: any::Any,
+    {
+        self.inner.sort_keys::<T>();
+    }
+
+    /// Sorts the values of the type `T` in the thread-safe heterogeneous hash map with a comparison
+    /// function.
+    ///
+    /// See [`HeterogeneousHashMap::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// het_map.sort_by::<i32, _>(|_, value1, _, value2| value2.cmp(value1));
+    ///
+    /// assert_eq!(het_map.first::<i32>(), Some((&Key::new(2_usize), &2_i32)));
+    /// ```
+    pub fn sort_by<T, F>(&mut self, cmp: F)
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        F: FnMut(&Key<K, T>, &T, &Key<K, T>, &T) -> cmp::Ordering,
+    {
+        self.inner.sort_by::<T, F>(cmp);
+    }
+
+    /// Returns an iterator over the keys and mutable references to the values of the type `T`,
+    /// if the type exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::iter_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// for (key, value) in het_map.iter_mut::<i32>().unwrap() {
+    ///     *value += *key.id() as i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&4_i32));
+    /// assert!(het_map.iter_mut::<u64>().is_none());
+    /// ```
+    pub fn iter_mut<T>(&mut self) -> Option<IterMut<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.inner.iter_mut::<T>()
+    }
+
+    /// Returns an iterator over mutable references to the values of the type `T`, if the type
+    /// exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::values_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// for value in het_map.values_mut::<i32>().unwrap() {
+    ///     *value *= 10_i32;
+    /// }
+    ///
+    /// assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&20_i32));
+    /// assert!(het_map.values_mut::<u64>().is_none());
+    /// ```
+    pub fn values_mut<T>(&mut self) -> Option<ValuesMut<'_, K, T>>
+    where
+        T: any::Any,
+    {
+        self.inner.values_mut::<T>()
+    }
+
+    /// Removes every value of the type `T` from the thread-safe heterogeneous hash map, and returns
+    /// them as an iterator, if the type exists in the map.
+    ///
+    /// See [`HeterogeneousHashMap::drain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// let drained: Vec<i32> = het_map.drain::<i32>().unwrap().map(|(_, value)| value).collect();
+    ///
+    /// assert_eq!(drained, [0, 1, 2]);
+    /// assert_eq!(het_map.len::<i32>(), Some(0));
+    /// ```
+    pub fn drain<T>(&mut self) -> Option<Drain<'_, K, T, A>>
+    where
+        T: any::Any,
+    {
+        self.inner.drain::<T>()
+    }
+
+    /// Shortens the storage of the type `T` in the thread-safe heterogeneous hash map to the first
+    /// `len` values, dropping the rest.
+    ///
+    /// See [`HeterogeneousHashMap::truncate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// het_map.truncate::<i32>(1);
+    ///
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// ```
+    pub fn truncate<T>(&mut self, len: usize)
+    where
+        T: any::Any,
+    {
+        self.inner.truncate::<T>(len);
+    }
+
+    /// Splits the storage of the type `T` in the thread-safe heterogeneous hash map at the given
+    /// index, and returns the values from the index onwards, if the type exists in the map.
+    ///
+    /// See [`HeterogeneousHashMap::split_off`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `at` is greater than the number of values of the type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// let tail = het_map.split_off::<i32>(1).unwrap();
+    ///
+    /// assert_eq!(tail.len(), 2);
+    /// assert_eq!(het_map.len::<i32>(), Some(1));
+    /// ```
+    pub fn split_off<T>(&mut self, at: usize) -> Option<HomogeneousHashMap<K, T, S, A>>
+    where
+        T: any::Any,
+    {
+        self.inner.split_off::<T>(at)
+    }
+
+    /// Moves every value from a homogeneous hash map into the thread-safe heterogeneous hash map,
+    /// inserting the type `T` into the map first if it does not exist.
+    ///
+    /// See [`HeterogeneousHashMap::append`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{HomogeneousHashMap, Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// let mut map = HomogeneousHashMap::new();
+    /// map.insert(Key::new(1_usize), 2_i32);
+    ///
+    /// het_map.append(&mut map);
+    ///
+    /// assert!(map.is_empty());
+    /// assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+    /// ```
+    pub fn append<T, S2>(&mut self, other: &mut HomogeneousHashMap<K, T, S2, A>)
+    where
+        K: hash::Hash + Eq,
+        T: any::Any,
+        M: Admits<T>,
+        S2: any::Any + hash::BuildHasher + Send + Sync,
+        S2::Hasher: any::Any + hash::Hasher + Send + Sync,
+    {
+        self.inner.append::<T, S2>(other);
+    }
+
+    /// Returns an iterator that removes and yields the values of the type `T` for which the
+    /// predicate returns `true`, if the type exists in the thread-safe heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::extract_if`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use heterogeneous_hash_map::{Key, SendHeterogeneousHashMap};
+    /// #
+    /// let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    /// het_map.extend((0..4).map(|i| (Key::new(i), i as i32)));
+    ///
+    /// let odd: Vec<i32> = het_map.extract_if::<i32, _>(|_, value| *value % 2 == 1).unwrap().map(|(_, value)| value).collect();
+    ///
+    /// assert_eq!(odd, [1, 3]);
+    /// assert_eq!(het_map.len::<i32>(), Some(2));
+    /// ```
+    pub fn extract_if<T, F>(&mut self, keep: F) -> Option<ExtractIf<'_, K, T, F, A>>
+    where
+        T: any::Any,
+        F: FnMut(&Key<K, T>, &mut T) -> bool,
+    {
+        self.inner.extract_if::<T, F>(keep)
+    }
+}
+
+impl<K, M, S, A> ops::Deref for ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    type Target = HeterogeneousHashMap<K, S, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<K, M, S, A> Default for ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone + Default,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone + Default,
+{
+    fn default() -> Self {
+        Self::with_hasher_in(S::default(), A::default())
+    }
+}
+
+impl<K, M, S, A> fmt::Debug for ThreadSafeHeterogeneousHashMap<K, M, S, A>
+where
+    K: any::Any + fmt::Debug,
+    M: ThreadSafety,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple(M::NAME).field(&self.inner).finish()
+    }
+}
+
+impl<K, S, A> From<SyncHeterogeneousHashMap<K, S, A>> for SendHeterogeneousHashMap<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// Converts a sync heterogeneous hash map into a send heterogeneous hash map, since every
+    /// type that is `Send + Sync` is also `Send`.
+    fn from(het_map: SyncHeterogeneousHashMap<K, S, A>) -> Self {
+        Self {
+            inner: het_map.inner,
+            _marker: marker::PhantomData,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use alloc_crate::boxed::Box;
+use alloc_crate::sync::Arc;
+use core::any;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+use hashbrown::hash_map;
+
+/// An upcast from a type-erased value to a reference to the trait object type `U`.
+type Upcast<U> = dyn for<'a> Fn(&'a dyn any::Any) -> &'a U + Send + Sync;
+
+/// An upcast from a type-erased value to a mutable reference to the trait object type `U`.
+type UpcastMut<U> = dyn for<'a> Fn(&'a mut dyn any::Any) -> &'a mut U + Send + Sync;
+
+/// The upcasts registered for the values of one type stored inside a heterogeneous hash map to
+/// the (typically unsized) type `U`, such as a trait object type.
+///
+/// The upcasts are captured when the concrete value type `T` is still known, and wrap the
+/// function pointers supplied by the caller so that they accept type-erased values.
+pub(crate) struct TraitView<U>
+where
+    U: ?Sized + 'static,
+{
+    upcast: Box<Upcast<U>>,
+    upcast_mut: Option<Box<UpcastMut<U>>>,
+}
+
+impl<U> TraitView<U>
+where
+    U: ?Sized + 'static,
+{
+    /// Constructs the view of the values of type `T` as `U` from a shared upcast.
+    pub(crate) fn new<T>(upcast: fn(&T) -> &U) -> Self
+    where
+        T: any::Any,
+    {
+        Self {
+            upcast: Box::new(move |value| upcast(value.downcast_ref::<T>().expect("The value must have the viewed type"))),
+            upcast_mut: None,
+        }
+    }
+
+    /// Constructs the view of the values of type `T` as `U` from a shared and a mutable upcast.
+    pub(crate) fn with_mut<T>(upcast: fn(&T) -> &U, upcast_mut: fn(&mut T) -> &mut U) -> Self
+    where
+        T: any::Any,
+    {
+        Self {
+            upcast_mut: Some(Box::new(move |value| {
+                upcast_mut(value.downcast_mut::<T>().expect("The value must have the viewed type"))
+            })),
+            ..Self::new(upcast)
+        }
+    }
+
+    /// Upcasts a type-erased value of the viewed type to `U`.
+    pub(crate) fn upcast<'a>(&self, value: &'a dyn any::Any) -> &'a U {
+        (self.upcast)(value)
+    }
+
+    /// Upcasts a type-erased mutable value of the viewed type to `U`.
+    ///
+    /// This method returns `None` if no mutable upcast was registered for the viewed type.
+    pub(crate) fn upcast_mut<'a>(&self, value: &'a mut dyn any::Any) -> Option<&'a mut U> {
+        self.upcast_mut.as_ref().map(|upcast_mut| upcast_mut(value))
+    }
+
+    /// Determines whether a mutable upcast was registered for the viewed type.
+    pub(crate) const fn has_mut(&self) -> bool {
+        self.upcast_mut.is_some()
+    }
+}
+
+/// The views of the types stored inside a heterogeneous hash map as other types, keyed by the
+/// [`TypeId`] of the value type and the [`TypeId`] of the type it is viewed as.
+///
+/// Each view is a [`TraitView<U>`], where `U` is the type that the value type is viewed as.
+///
+/// [`TypeId`]: core::any::TypeId
+pub(crate) type TraitViews<S> = hash_map::HashMap<(any::TypeId, any::TypeId), Arc<dyn any::Any + Send + Sync>, S>;
+
+/// Returns the view of the values of the type with the given [`TypeId`] as `U`, if one was
+/// registered.
+///
+/// [`TypeId`]: core::any::TypeId
+pub(crate) fn get_view<U, S>(views: &TraitViews<S>, type_id: any::TypeId) -> Option<&TraitView<U>>
+where
+    U: ?Sized + 'static,
+    S: hash::BuildHasher,
+{
+    let view = views.get(&(type_id, any::TypeId::of::<U>()))?;
+
+    Some(
+        view.downcast_ref::<TraitView<U>>()
+            .expect("Every view must be keyed by the type it views as"),
+    )
+}
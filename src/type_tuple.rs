@@ -0,0 +1,372 @@
+use crate::heterogeneous_hash_map::HeterogeneousHashMap;
+use crate::homogeneous_hash_map::HomogeneousHashMap;
+use crate::key::Key;
+
+use alloc_crate::vec::Vec;
+use core::any;
+use core::iter;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+/// A tuple of value types that can be queried together in a heterogeneous hash map.
+///
+/// This trait is implemented for tuples of up to twelve value types, and is what allows a single
+/// call such as [`HeterogeneousHashMap::get_many_types`] to look up the same key for several
+/// types at once, [`HeterogeneousHashMap::get_maps_mut`] to borrow the maps of several types
+/// mutably at the same time, or [`HeterogeneousHashMap::join`] to iterate over the keys present in
+/// several types, optionally together with the values of other types through
+/// [`HeterogeneousHashMap::join_with`]. It also names the types queried through a [`TypeSignatureIndex`]. Each element
+/// of the tuple names one type stored in the heterogeneous hash map.
+///
+/// [`TypeSignatureIndex`]: crate::TypeSignatureIndex
+///
+/// # Examples
+///
+/// ```
+/// # use heterogeneous_hash_map::{HeterogeneousHashMap, Key};
+/// #
+/// let mut het_map = HeterogeneousHashMap::new();
+/// het_map.insert(Key::new(1_usize), 2_i32);
+/// het_map.insert(Key::new(1_usize), 3.5_f64);
+///
+/// let (int, float, string) = het_map.get_many_types::<(i32, f64, String)>(&1_usize);
+///
+/// assert_eq!(int, Some(&2_i32));
+/// assert_eq!(float, Some(&3.5_f64));
+/// assert_eq!(string, None);
+/// ```
+pub trait TypeTuple<K, S, A>
+where
+    K: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync + Clone,
+{
+    /// The tuple of optional references to the values of each type in the tuple.
+    type Refs<'a>;
+
+    /// The tuple of optional mutable references to the values of each type in the tuple.
+    type RefsMut<'a>;
+
+    /// The tuple of mutable references to the homogeneous hash maps storing the values of each
+    /// type in the tuple.
+    type MapsMut<'a>;
+
+    /// The tuple of a key and references to the values of each type in the tuple stored under
+    /// that key.
+    type JoinItem<'a>;
+
+    /// The tuple of a key and mutable references to the values of each type in the tuple stored
+    /// under that key.
+    type JoinItemMut<'a>;
+
+    /// Returns an iterator over the [`TypeId`] of each type in the tuple, in the order of the
+    /// tuple.
+    ///
+    /// [`TypeId`]: core::any::TypeId
+    fn type_ids() -> impl Iterator<Item = any::TypeId>;
+
+    /// Returns a reference to the value stored under the given key for each type in the tuple.
+    ///
+    /// See [`HeterogeneousHashMap::get_many_types`].
+    fn get_many_types<'a>(het_map: &'a HeterogeneousHashMap<K, S, A>, key: &K) -> Self::Refs<'a>;
+
+    /// Returns a mutable reference to the value stored under the given key for each type in the
+    /// tuple.
+    ///
+    /// See [`HeterogeneousHashMap::get_many_types_mut`].
+    fn get_many_types_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>, key: &K) -> Self::RefsMut<'a>;
+
+    /// Returns a mutable reference to the homogeneous hash map of each type in the tuple, if
+    /// every type exists in the heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::get_maps_mut`].
+    fn get_maps_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> Option<Self::MapsMut<'a>>;
+
+    /// Returns a mutable reference to the homogeneous hash map of each type in the tuple,
+    /// inserting the types that do not exist in the heterogeneous hash map.
+    ///
+    /// See [`HeterogeneousHashMap::get_or_insert_maps_mut`].
+    fn get_or_insert_maps_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> Self::MapsMut<'a>;
+
+    /// Returns an iterator over the keys that have a value of every type in the tuple, together
+    /// with references to those values.
+    ///
+    /// See [`HeterogeneousHashMap::join`].
+    fn join<'a>(het_map: &'a HeterogeneousHashMap<K, S, A>) -> impl Iterator<Item = Self::JoinItem<'a>> + 'a;
+
+    /// Returns an iterator over the keys that have a value of every type in the tuple, together
+    /// with references to those values, and optional references to the values of each type in
+    /// the tuple `Optional`.
+    ///
+    /// See [`HeterogeneousHashMap::join_with`].
+    fn join_with<'a, Optional>(
+        het_map: &'a HeterogeneousHashMap<K, S, A>,
+    ) -> impl Iterator<Item = (Self::JoinItem<'a>, Optional::Refs<'a>)> + 'a
+    where
+        Optional: TypeTuple<K, S, A>;
+
+    /// Returns an iterator over the keys that have a value of every type in the tuple, together
+    /// with mutable references to those values.
+    ///
+    /// See [`HeterogeneousHashMap::join_mut`].
+    fn join_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> impl Iterator<Item = Self::JoinItemMut<'a>> + 'a;
+}
+
+/// Returns the position of the shortest map among the given map lengths.
+fn shortest(lengths: &[usize]) -> usize {
+    lengths
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, length)| **length)
+        .map(|(position, _)| position)
+        .unwrap_or(0)
+}
+
+/// Returns the entries of a homogeneous hash map at the given storage indices, in the order of
+/// the indices, with mutable references to their values.
+///
+/// The storage indices must be distinct and in bounds. The entries are split off the map in
+/// ascending storage order, so the mutable references are disjoint.
+fn get_indices_mut<'a, K, T, S, A>(
+    map: &'a mut HomogeneousHashMap<K, T, S, A>,
+    indices: &[usize],
+) -> Vec<(&'a Key<K, T>, &'a mut T)>
+where
+    K: any::Any,
+    T: any::Any,
+    S: any::Any + hash::BuildHasher + Send + Sync,
+    S::Hasher: any::Any + hash::Hasher + Send + Sync,
+    A: any::Any + alloc::Allocator + Send + Sync,
+{
+    let mut order: Vec<usize> = (0..indices.len()).collect();
+    order.sort_unstable_by_key(|position| indices[*position]);
+
+    let mut entries: Vec<Option<(&'a Key<K, T>, &'a mut T)>> = iter::repeat_with(|| None).take(indices.len()).collect();
+    let mut rest = map.as_mut_slice();
+    let mut offset = 0;
+    for position in order {
+        let index = indices[position];
+        let (_, tail) = rest.split_at_mut(index - offset);
+        let (entry, tail) = tail.split_at_mut(1);
+        entries[position] = entry.first_mut();
+        rest = tail;
+        offset = index + 1;
+    }
+
+    entries
+        .into_iter()
+        .map(|entry| entry.expect("Every storage index must be distinct and in bounds"))
+        .collect()
+}
+
+macro_rules! impl_type_tuple {
+    ($(($type_param:ident, $map:ident)),+ $(,)?) => {
+        impl<K, S, A, $($type_param,)+> TypeTuple<K, S, A> for ($($type_param,)+)
+        where
+            K: any::Any + hash::Hash + Eq,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+            $($type_param: any::Any,)+
+        {
+            type Refs<'a> = ($(Option<&'a $type_param>,)+);
+            type RefsMut<'a> = ($(Option<&'a mut $type_param>,)+);
+            type MapsMut<'a> = ($(&'a mut HomogeneousHashMap<K, $type_param, S, A>,)+);
+            type JoinItem<'a> = (&'a K, $(&'a $type_param,)+);
+            type JoinItemMut<'a> = (&'a K, $(&'a mut $type_param,)+);
+
+            fn type_ids() -> impl Iterator<Item = any::TypeId> {
+                [$(any::TypeId::of::<$type_param>(),)+].into_iter()
+            }
+
+            fn get_many_types<'a>(het_map: &'a HeterogeneousHashMap<K, S, A>, key: &K) -> Self::Refs<'a> {
+                ($(het_map.get::<$type_param, K>(key),)+)
+            }
+
+            #[track_caller]
+            fn get_many_types_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>, key: &K) -> Self::RefsMut<'a> {
+                let [$($map,)+] = het_map.get_erased_maps_mut([$(&any::TypeId::of::<$type_param>(),)+]);
+
+                ($($map.and_then(|map| map.as_proj_mut::<Key<K, $type_param>, $type_param, S, A>().get_mut(key)),)+)
+            }
+
+            #[track_caller]
+            fn get_maps_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> Option<Self::MapsMut<'a>> {
+                $(het_map.set_key_lookup::<$type_param>();)+
+                let [$($map,)+] = het_map.get_erased_maps_mut([$(&any::TypeId::of::<$type_param>(),)+]);
+
+                Some(($(HomogeneousHashMap::from_inner_ref_mut($map?.as_proj_mut::<Key<K, $type_param>, $type_param, S, A>()),)+))
+            }
+
+            #[track_caller]
+            fn get_or_insert_maps_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> Self::MapsMut<'a> {
+                $(het_map.insert_type::<$type_param>();)+
+
+                Self::get_maps_mut(het_map).expect("Every type in the tuple was inserted into the map")
+            }
+
+            fn join<'a>(het_map: &'a HeterogeneousHashMap<K, S, A>) -> impl Iterator<Item = Self::JoinItem<'a>> + 'a {
+                let ($($map,)+) = ($(het_map.get_map::<$type_param>(),)+);
+                let maps = (|| Some(($($map?,)+)))();
+
+                maps.into_iter().flat_map(|($($map,)+)| {
+                    let lengths = [$($map.len(),)+];
+                    let driver = shortest(&lengths);
+
+                    (0..lengths[driver]).filter_map(move |index| {
+                        let mut positions = 0..;
+                        let mut key = None;
+                        $(
+                            if positions.next() == Some(driver) {
+                                key = $map.get_index(index).map(|(key, _)| key.id());
+                            }
+                        )+
+                        let key = key?;
+
+                        Some((key, $($map.get(key)?,)+))
+                    })
+                })
+            }
+
+            fn join_with<'a, Optional>(
+                het_map: &'a HeterogeneousHashMap<K, S, A>,
+            ) -> impl Iterator<Item = (Self::JoinItem<'a>, Optional::Refs<'a>)> + 'a
+            where
+                Optional: TypeTuple<K, S, A>,
+            {
+                Self::join(het_map).map(move |item| {
+                    let optional = Optional::get_many_types(het_map, item.0);
+
+                    (item, optional)
+                })
+            }
+
+            #[track_caller]
+            fn join_mut<'a>(het_map: &'a mut HeterogeneousHashMap<K, S, A>) -> impl Iterator<Item = Self::JoinItemMut<'a>> + 'a {
+                let maps = Self::get_maps_mut(het_map);
+
+                maps.into_iter().flat_map(|($($map,)+)| {
+                    let lengths = [$($map.len(),)+];
+                    let driver = shortest(&lengths);
+
+                    let mut rows = Vec::new();
+                    for index in 0..lengths[driver] {
+                        let mut positions = 0..;
+                        let mut key = None;
+                        $(
+                            if positions.next() == Some(driver) {
+                                key = $map.get_index(index).map(|(key, _)| key.id());
+                            }
+                        )+
+                        let row = key.and_then(|key| Some([$($map.get_index_of(key)?,)+]));
+                        rows.extend(row);
+                    }
+
+                    let mut positions = 0..;
+                    $(
+                        let column = positions.next().unwrap_or_default();
+                        let indices: Vec<usize> = rows.iter().map(|row| row[column]).collect();
+                        let mut $map = get_indices_mut($map, &indices).into_iter();
+                    )+
+
+                    iter::from_fn(move || {
+                        let mut key = None;
+                        $(
+                            let (entry_key, $map) = $map.next()?;
+                            key = key.or(Some(entry_key.id()));
+                        )+
+
+                        Some((key?, $($map,)+))
+                    })
+                })
+            }
+        }
+    };
+}
+
+impl_type_tuple!((T1, map1));
+impl_type_tuple!((T1, map1), (T2, map2));
+impl_type_tuple!((T1, map1), (T2, map2), (T3, map3));
+impl_type_tuple!((T1, map1), (T2, map2), (T3, map3), (T4, map4));
+impl_type_tuple!((T1, map1), (T2, map2), (T3, map3), (T4, map4), (T5, map5));
+impl_type_tuple!((T1, map1), (T2, map2), (T3, map3), (T4, map4), (T5, map5), (T6, map6));
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7)
+);
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7),
+    (T8, map8)
+);
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7),
+    (T8, map8),
+    (T9, map9),
+);
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7),
+    (T8, map8),
+    (T9, map9),
+    (T10, map10),
+);
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7),
+    (T8, map8),
+    (T9, map9),
+    (T10, map10),
+    (T11, map11),
+);
+impl_type_tuple!(
+    (T1, map1),
+    (T2, map2),
+    (T3, map3),
+    (T4, map4),
+    (T5, map5),
+    (T6, map6),
+    (T7, map7),
+    (T8, map8),
+    (T9, map9),
+    (T10, map10),
+    (T11, map11),
+    (T12, map12),
+);
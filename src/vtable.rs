@@ -0,0 +1,474 @@
+use crate::key::Key;
+
+use alloc_crate::boxed::Box;
+use core::any;
+use core::fmt;
+use opaque::index_map::TypeErasedIndexMap;
+
+#[cfg(feature = "std")]
+use std::hash;
+
+#[cfg(not(feature = "std"))]
+use core::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+/// A predicate over the type-erased keys of a heterogeneous hash map.
+pub(crate) type KeyPredicate<'a> = dyn FnMut(&dyn any::Any) -> bool + 'a;
+
+/// An iterator over the type-erased keys and values of a type-erased map.
+pub(crate) type ErasedIter<'a> = dyn Iterator<Item = (&'a dyn any::Any, &'a dyn any::Any)> + 'a;
+
+/// An iterator over the type-erased keys and mutable values of a type-erased map.
+pub(crate) type ErasedIterMut<'a> = dyn Iterator<Item = (&'a dyn any::Any, &'a mut dyn any::Any)> + 'a;
+
+/// A type-erased key and value of a type-erased map.
+type ErasedEntry<'a> = (&'a dyn any::Any, &'a dyn any::Any);
+
+/// A hashed lookup of the storage index of a type-erased key in a type-erased map.
+type KeyLookup = fn(&TypeErasedIndexMap, &dyn any::Any) -> Option<usize>;
+
+/// The table of type-specific operations for a data type stored inside a heterogeneous hash map.
+///
+/// A heterogeneous hash map erases the type of the map storing the values of each type, so any
+/// operation that needs the concrete type of the values, such as cloning, comparing, or
+/// formatting, is captured here as a function pointer at the point where the concrete type is
+/// still known. Operations that every value type supports, such as clearing or shrinking the
+/// map, or accessing a value by its storage index without naming its type, always exist.
+/// Operations that require additional trait bounds on the key type or on the value type are
+/// optional. The hashed key lookup is captured by the first method that inserts a value of the
+/// type and knows that the key type is hashable, and the remaining optional operations only
+/// exist for types registered with the corresponding `insert_*_type` method.
+#[derive(Copy, Clone)]
+pub(crate) struct TypeVTable {
+    len: fn(&TypeErasedIndexMap) -> usize,
+    get_index: for<'a> fn(&'a TypeErasedIndexMap, usize) -> Option<ErasedEntry<'a>>,
+    get_index_mut: for<'a> fn(&'a mut TypeErasedIndexMap, usize) -> Option<&'a mut dyn any::Any>,
+    iter: for<'a> fn(&'a TypeErasedIndexMap) -> Box<ErasedIter<'a>>,
+    iter_mut: for<'a> fn(&'a mut TypeErasedIndexMap) -> Box<ErasedIterMut<'a>>,
+    swap_remove_index: fn(&mut TypeErasedIndexMap, usize) -> Option<Box<dyn any::Any>>,
+    swap_remove_index_drop: fn(&mut TypeErasedIndexMap, usize) -> bool,
+    retain_keys: fn(&mut TypeErasedIndexMap, &mut KeyPredicate<'_>),
+    clear: fn(&mut TypeErasedIndexMap),
+    shrink_to_fit: fn(&mut TypeErasedIndexMap),
+    get_index_of: Option<KeyLookup>,
+    clone: Option<fn(&TypeErasedIndexMap) -> TypeErasedIndexMap>,
+    eq: Option<fn(&TypeErasedIndexMap, &TypeErasedIndexMap) -> bool>,
+    debug: Option<fn(&TypeErasedIndexMap, &mut fmt::Formatter<'_>) -> fmt::Result>,
+}
+
+impl TypeVTable {
+    /// Constructs the table of type-specific operations for the values of type `T` stored in a
+    /// heterogeneous hash map whose optional operations are all missing.
+    pub(crate) fn new<K, T, S, A>() -> Self
+    where
+        K: any::Any,
+        T: any::Any,
+        S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+        S::Hasher: any::Any + hash::Hasher + Send + Sync,
+        A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    {
+        fn len_map<K, T, S, A>(map: &TypeErasedIndexMap) -> usize
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            map.as_proj::<Key<K, T>, T, S, A>().len()
+        }
+
+        fn get_index<K, T, S, A>(map: &TypeErasedIndexMap, index: usize) -> Option<ErasedEntry<'_>>
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let (key, value) = map.as_proj::<Key<K, T>, T, S, A>().get_index(index)?;
+
+            Some((key.id(), value))
+        }
+
+        fn get_index_mut<K, T, S, A>(map: &mut TypeErasedIndexMap, index: usize) -> Option<&mut dyn any::Any>
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let (_key, value) = map.as_proj_mut::<Key<K, T>, T, S, A>().get_index_mut(index)?;
+
+            Some(value)
+        }
+
+        fn iter_map<K, T, S, A>(map: &TypeErasedIndexMap) -> Box<ErasedIter<'_>>
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let iter = map
+                .as_proj::<Key<K, T>, T, S, A>()
+                .iter()
+                .map(|(key, value)| (key.id() as &dyn any::Any, value as &dyn any::Any));
+
+            Box::new(iter)
+        }
+
+        fn iter_map_mut<K, T, S, A>(map: &mut TypeErasedIndexMap) -> Box<ErasedIterMut<'_>>
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let iter = map
+                .as_proj_mut::<Key<K, T>, T, S, A>()
+                .iter_mut()
+                .map(|(key, value)| (key.id() as &dyn any::Any, value as &mut dyn any::Any));
+
+            Box::new(iter)
+        }
+
+        fn swap_remove_index<K, T, S, A>(map: &mut TypeErasedIndexMap, index: usize) -> Option<Box<dyn any::Any>>
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let (_key, value) = map.as_proj_mut::<Key<K, T>, T, S, A>().swap_remove_index(index)?;
+
+            Some(Box::new(value))
+        }
+
+        fn swap_remove_index_drop<K, T, S, A>(map: &mut TypeErasedIndexMap, index: usize) -> bool
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            map.as_proj_mut::<Key<K, T>, T, S, A>().swap_remove_index(index).is_some()
+        }
+
+        fn retain_keys<K, T, S, A>(map: &mut TypeErasedIndexMap, keep: &mut KeyPredicate<'_>)
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            map.as_proj_mut::<Key<K, T>, T, S, A>().retain(|key, _value| keep(key.id()));
+        }
+
+        fn clear_map<K, T, S, A>(map: &mut TypeErasedIndexMap)
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            map.as_proj_mut::<Key<K, T>, T, S, A>().clear();
+        }
+
+        fn shrink_map_to_fit<K, T, S, A>(map: &mut TypeErasedIndexMap)
+        where
+            K: any::Any,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            map.as_proj_mut::<Key<K, T>, T, S, A>().shrink_to_fit();
+        }
+
+        Self {
+            len: len_map::<K, T, S, A>,
+            get_index: get_index::<K, T, S, A>,
+            get_index_mut: get_index_mut::<K, T, S, A>,
+            iter: iter_map::<K, T, S, A>,
+            iter_mut: iter_map_mut::<K, T, S, A>,
+            swap_remove_index: swap_remove_index::<K, T, S, A>,
+            swap_remove_index_drop: swap_remove_index_drop::<K, T, S, A>,
+            retain_keys: retain_keys::<K, T, S, A>,
+            clear: clear_map::<K, T, S, A>,
+            shrink_to_fit: shrink_map_to_fit::<K, T, S, A>,
+            get_index_of: None,
+            clone: None,
+            eq: None,
+            debug: None,
+        }
+    }
+
+    /// Returns the number of values stored in a type-erased map.
+    pub(crate) fn len_map(&self, map: &TypeErasedIndexMap) -> usize {
+        (self.len)(map)
+    }
+
+    /// Registers the hashed key lookup for the values of type `T`.
+    pub(crate) fn set_key_lookup<K, T, S, A>(&mut self)
+    where
+        K: any::Any + hash::Hash + Eq,
+        T: any::Any,
+        S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+        S::Hasher: any::Any + hash::Hasher + Send + Sync,
+        A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    {
+        fn get_index_of<K, T, S, A>(map: &TypeErasedIndexMap, key: &dyn any::Any) -> Option<usize>
+        where
+            K: any::Any + hash::Hash + Eq,
+            T: any::Any,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let key = key.downcast_ref::<K>().expect("The key must have the key type of the map");
+
+            map.as_proj::<Key<K, T>, T, S, A>().get_index_of(key)
+        }
+
+        self.get_index_of = Some(get_index_of::<K, T, S, A>);
+    }
+
+    /// Returns the storage index of the given key in a type-erased map, if it exists.
+    ///
+    /// This method hashes the key with the registered key lookup, and falls back to a linear
+    /// scan over the keys of the map if no key lookup was registered for the type.
+    pub(crate) fn get_index_of<K>(&self, map: &TypeErasedIndexMap, key: &K) -> Option<usize>
+    where
+        K: any::Any + Eq,
+    {
+        match self.get_index_of {
+            Some(get_index_of) => get_index_of(map, key),
+            None => self
+                .iter_map(map)
+                .position(|(id, _value)| id.downcast_ref::<K>() == Some(key)),
+        }
+    }
+
+    /// Returns a reference to the value stored under the given key in a type-erased map, if it
+    /// exists.
+    pub(crate) fn get_value<'a, K>(&self, map: &'a TypeErasedIndexMap, key: &K) -> Option<&'a dyn any::Any>
+    where
+        K: any::Any + Eq,
+    {
+        let index = self.get_index_of(map, key)?;
+        let (_key, value) = (self.get_index)(map, index)?;
+
+        Some(value)
+    }
+
+    /// Returns a mutable reference to the value stored under the given key in a type-erased map,
+    /// if it exists.
+    pub(crate) fn get_value_mut<'a, K>(&self, map: &'a mut TypeErasedIndexMap, key: &K) -> Option<&'a mut dyn any::Any>
+    where
+        K: any::Any + Eq,
+    {
+        let index = self.get_index_of(map, key)?;
+
+        (self.get_index_mut)(map, index)
+    }
+
+    /// Returns a reference to the identifier of the key stored at the given storage index in a
+    /// type-erased map, if it exists.
+    ///
+    /// The identifier has the key type of the heterogeneous hash map.
+    pub(crate) fn get_key_index<'a>(&self, map: &'a TypeErasedIndexMap, index: usize) -> Option<&'a dyn any::Any> {
+        let (key, _value) = (self.get_index)(map, index)?;
+
+        Some(key)
+    }
+
+    /// Returns an iterator over the type-erased keys and values of a type-erased map, in storage
+    /// order.
+    ///
+    /// Each key is the identifier of a key, which has the key type of the heterogeneous hash map.
+    pub(crate) fn iter_map<'a>(&self, map: &'a TypeErasedIndexMap) -> Box<ErasedIter<'a>> {
+        (self.iter)(map)
+    }
+
+    /// Returns an iterator over the type-erased keys and mutable values of a type-erased map, in
+    /// storage order.
+    ///
+    /// Each key is the identifier of a key, which has the key type of the heterogeneous hash map.
+    pub(crate) fn iter_map_mut<'a>(&self, map: &'a mut TypeErasedIndexMap) -> Box<ErasedIterMut<'a>> {
+        (self.iter_mut)(map)
+    }
+
+    /// Removes the value stored under the given key from a type-erased map, and returns it
+    /// boxed, if it exists.
+    ///
+    /// The last value of the map takes the place of the removed value.
+    pub(crate) fn remove_value<K>(&self, map: &mut TypeErasedIndexMap, key: &K) -> Option<Box<dyn any::Any>>
+    where
+        K: any::Any + Eq,
+    {
+        let index = self.get_index_of(map, key)?;
+
+        (self.swap_remove_index)(map, index)
+    }
+
+    /// Removes the value stored under the given key from a type-erased map, and drops it.
+    ///
+    /// This method returns `true` if a value was removed. The last value of the map takes the
+    /// place of the removed value.
+    pub(crate) fn remove_key<K>(&self, map: &mut TypeErasedIndexMap, key: &K) -> bool
+    where
+        K: any::Any + Eq,
+    {
+        match self.get_index_of(map, key) {
+            Some(index) => (self.swap_remove_index_drop)(map, index),
+            None => false,
+        }
+    }
+
+    /// Retains only the entries of a type-erased map whose keys satisfy the given predicate.
+    ///
+    /// The predicate receives the identifier of each key, which has the key type of the
+    /// heterogeneous hash map.
+    pub(crate) fn retain_keys(&self, map: &mut TypeErasedIndexMap, keep: &mut KeyPredicate<'_>) {
+        (self.retain_keys)(map, keep)
+    }
+
+    /// Removes every value from a type-erased map, without changing its capacity.
+    pub(crate) fn clear_map(&self, map: &mut TypeErasedIndexMap) {
+        (self.clear)(map)
+    }
+
+    /// Shrinks the capacity of a type-erased map as much as possible.
+    pub(crate) fn shrink_map_to_fit(&self, map: &mut TypeErasedIndexMap) {
+        (self.shrink_to_fit)(map)
+    }
+
+    /// Registers the clone operation for the values of type `T`.
+    pub(crate) fn set_clone<K, T, S, A>(&mut self)
+    where
+        K: any::Any + Clone,
+        T: any::Any + Clone,
+        S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+        S::Hasher: any::Any + hash::Hasher + Send + Sync,
+        A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    {
+        fn clone_map<K, T, S, A>(map: &TypeErasedIndexMap) -> TypeErasedIndexMap
+        where
+            K: any::Any + Clone,
+            T: any::Any + Clone,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let proj_map = map.as_proj::<Key<K, T>, T, S, A>();
+
+            TypeErasedIndexMap::from_proj(proj_map.clone())
+        }
+
+        self.clone = Some(clone_map::<K, T, S, A>);
+    }
+
+    /// Clones a type-erased map using the registered clone operation.
+    ///
+    /// This method returns `None` if no clone operation was registered for the type.
+    pub(crate) fn clone_map(&self, map: &TypeErasedIndexMap) -> Option<TypeErasedIndexMap> {
+        self.clone.map(|clone| clone(map))
+    }
+
+    /// Registers the equality operation for the values of type `T`.
+    pub(crate) fn set_eq<K, T, S, A>(&mut self)
+    where
+        K: any::Any + hash::Hash + Eq,
+        T: any::Any + PartialEq,
+        S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+        S::Hasher: any::Any + hash::Hasher + Send + Sync,
+        A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    {
+        fn eq_map<K, T, S, A>(map: &TypeErasedIndexMap, other: &TypeErasedIndexMap) -> bool
+        where
+            K: any::Any + hash::Hash + Eq,
+            T: any::Any + PartialEq,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let proj_map = map.as_proj::<Key<K, T>, T, S, A>();
+            let proj_other = other.as_proj::<Key<K, T>, T, S, A>();
+
+            proj_map == proj_other
+        }
+
+        self.eq = Some(eq_map::<K, T, S, A>);
+    }
+
+    /// Determines whether the type has a registered equality operation.
+    pub(crate) const fn has_eq(&self) -> bool {
+        self.eq.is_some()
+    }
+
+    /// Compares two type-erased maps using the registered equality operation.
+    ///
+    /// This method returns `None` if no equality operation was registered for the type.
+    pub(crate) fn eq_map(&self, map: &TypeErasedIndexMap, other: &TypeErasedIndexMap) -> Option<bool> {
+        self.eq.map(|eq| eq(map, other))
+    }
+
+    /// Registers the debug formatting operation for the values of type `T`.
+    pub(crate) fn set_debug<K, T, S, A>(&mut self)
+    where
+        K: any::Any + fmt::Debug,
+        T: any::Any + fmt::Debug,
+        S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+        S::Hasher: any::Any + hash::Hasher + Send + Sync,
+        A: any::Any + alloc::Allocator + Send + Sync + Clone,
+    {
+        fn debug_map<K, T, S, A>(map: &TypeErasedIndexMap, formatter: &mut fmt::Formatter<'_>) -> fmt::Result
+        where
+            K: any::Any + fmt::Debug,
+            T: any::Any + fmt::Debug,
+            S: any::Any + hash::BuildHasher + Send + Sync + Clone,
+            S::Hasher: any::Any + hash::Hasher + Send + Sync,
+            A: any::Any + alloc::Allocator + Send + Sync + Clone,
+        {
+            let proj_map = map.as_proj::<Key<K, T>, T, S, A>();
+
+            formatter.debug_map().entries(proj_map.iter()).finish()
+        }
+
+        self.debug = Some(debug_map::<K, T, S, A>);
+    }
+
+    /// Returns a debug formatting view of a type-erased map using the registered debug
+    /// formatting operation.
+    ///
+    /// This method returns `None` if no debug formatting operation was registered for the type.
+    pub(crate) fn debug_map<'a>(&self, map: &'a TypeErasedIndexMap) -> Option<DebugMap<'a>> {
+        self.debug.map(|debug| DebugMap { map, debug })
+    }
+}
+
+/// A view of a type-erased map that formats its entries using the debug formatting operation
+/// registered for its type.
+pub(crate) struct DebugMap<'a> {
+    map: &'a TypeErasedIndexMap,
+    debug: fn(&TypeErasedIndexMap, &mut fmt::Formatter<'_>) -> fmt::Result,
+}
+
+impl fmt::Debug for DebugMap<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.debug)(self.map, formatter)
+    }
+}
@@ -1,6 +1,113 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::vec::Vec;
+use core::any;
+
 mod test_entry_api_many_types;
 mod test_entry_api_one_type;
+mod test_heterogeneous_hash_map_allocator;
+mod test_heterogeneous_hash_map_capacity;
+mod test_heterogeneous_hash_map_clone;
+mod test_heterogeneous_hash_map_concurrent;
+mod test_heterogeneous_hash_map_debug;
+mod test_heterogeneous_hash_map_dyn;
+mod test_heterogeneous_hash_map_eq;
+mod test_heterogeneous_hash_map_erased;
+mod test_heterogeneous_hash_map_index;
+mod test_heterogeneous_hash_map_join;
+mod test_heterogeneous_hash_map_key_queries;
 mod test_heterogeneous_hash_map_many_types;
+mod test_heterogeneous_hash_map_many_types_lookup;
+mod test_heterogeneous_hash_map_maps_mut;
 mod test_heterogeneous_hash_map_one_type;
+mod test_heterogeneous_hash_map_range;
+mod test_heterogeneous_hash_map_remove;
+mod test_heterogeneous_hash_map_remove_key;
+mod test_heterogeneous_hash_map_send_sync;
+mod test_heterogeneous_hash_map_signature_index;
+mod test_heterogeneous_hash_map_slice;
+mod test_heterogeneous_hash_map_sort;
+mod test_heterogeneous_hash_map_trait_view;
+mod test_heterogeneous_hash_map_try_reserve;
 mod test_heterogeneous_hash_map_two_types;
 mod test_heterogeneous_hash_map_types;
+
+#[cfg(feature = "rayon")]
+mod test_heterogeneous_hash_map_rayon;
+#[cfg(feature = "serde")]
+mod test_heterogeneous_hash_map_serde;
+
+/// Constructs a heterogeneous hash map storing the values `0..len` of both `i32` and `u64`, each
+/// under the key of the same value, in increasing key order.
+fn het_map_from_range(len: usize) -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend((0..len).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..len).map(|i| (Key::new(i), i as u64)));
+
+    het_map
+}
+
+/// Returns the keys of the values of type `T` in a heterogeneous hash map, in storage order.
+fn keys<T>(het_map: &HeterogeneousHashMap<usize>) -> Vec<usize>
+where
+    T: any::Any,
+{
+    het_map.keys::<T>().unwrap().map(|key| *key.id()).collect()
+}
+
+/// Collects the values of an iterator into a vector in increasing order.
+fn sorted<I>(values: I) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    let mut values: Vec<I::Item> = values.into_iter().collect();
+    values.sort();
+
+    values
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Position(i32, i32);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Velocity(i32, i32);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Health(u32);
+
+/// Constructs a heterogeneous hash map storing a [`Position`] under each of the keys `0..10`, a
+/// [`Velocity`] under every key divisible by three, and a [`Health`] under every even key.
+fn world() -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend((0..10).map(|i| (Key::new(i), Position(i as i32, 0))));
+    het_map.extend((0..10).filter(|i| i % 3 == 0).map(|i| (Key::new(i), Velocity(1, 1))));
+    het_map.extend((0..10).filter(|i| i % 2 == 0).map(|i| (Key::new(i), Health(i as u32 * 10))));
+
+    het_map
+}
+
+/// Constructs a heterogeneous hash map storing a [`Position`] under each of the keys `0..4`, and a
+/// [`Velocity`] under every even key.
+fn simulation_het_map() -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), Position(i as i32, 0))));
+    het_map.extend((0..4).filter(|i| i % 2 == 0).map(|i| (Key::new(i), Velocity(1, 1))));
+
+    het_map
+}
+
+/// Constructs a heterogeneous hash map storing a [`Position`], a [`Velocity`] and the `u32` of the
+/// same value under each of the keys `0..len`.
+#[cfg(feature = "rayon")]
+fn particle_het_map(len: usize) -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend((0..len).map(|i| (Key::new(i), Position(i as i32, 0))));
+    het_map.extend((0..len).map(|i| (Key::new(i), Velocity(1, -1))));
+    het_map.extend((0..len).map(|i| (Key::new(i), i as u32)));
+
+    het_map
+}
@@ -0,0 +1,206 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    HomogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::String;
+use std::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+#[derive(Clone, Debug)]
+struct WrappingBuildHasher<S> {
+    build_hasher: S,
+}
+
+impl<S> WrappingBuildHasher<S> {
+    fn new(build_hasher: S) -> Self {
+        Self { build_hasher }
+    }
+}
+
+impl<S> hash::BuildHasher for WrappingBuildHasher<S>
+where
+    S: hash::BuildHasher,
+{
+    type Hasher = S::Hasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        self.build_hasher.build_hasher()
+    }
+}
+
+#[cfg(feature = "nightly")]
+mod counting_allocator {
+    use core::ptr::NonNull;
+    use std::alloc;
+    use std::sync::Arc;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    #[derive(Clone, Debug, Default)]
+    pub struct CountingAllocator {
+        allocations: Arc<AtomicUsize>,
+    }
+
+    impl CountingAllocator {
+        pub fn allocations(&self) -> usize {
+            self.allocations.load(Ordering::SeqCst)
+        }
+    }
+
+    unsafe impl alloc::Allocator for CountingAllocator {
+        fn allocate(&self, layout: alloc::Layout) -> Result<NonNull<[u8]>, alloc::AllocError> {
+            self.allocations.fetch_add(1, Ordering::SeqCst);
+
+            alloc::Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: alloc::Layout) {
+            unsafe { alloc::Global.deallocate(ptr, layout) }
+        }
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_new_in_empty() {
+    let het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> = HeterogeneousHashMap::new_in(alloc::Global);
+
+    assert!(het_map.is_empty_types());
+    assert_eq!(het_map.len_types(), 0);
+    assert_eq!(het_map.capacity_types(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_with_capacity_in_empty() {
+    let het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> =
+        HeterogeneousHashMap::with_capacity_in(3, alloc::Global);
+
+    assert!(het_map.is_empty_types());
+    assert_eq!(het_map.len_types(), 0);
+    assert!(het_map.capacity_types() >= 3);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_with_hasher_in_insert_get() {
+    let mut het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> =
+        HeterogeneousHashMap::with_hasher_in(hash::RandomState::new(), alloc::Global);
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+    het_map.insert::<String>(Key::new(1_usize), String::from("foo"));
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    assert_eq!(het_map.get::<String, _>(&Key::new(1_usize)), Some(&String::from("foo")));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_with_capacity_and_hasher_in_insert_type_with_capacity() {
+    let mut het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> =
+        HeterogeneousHashMap::with_capacity_and_hasher_in(2, hash::RandomState::new(), alloc::Global);
+
+    assert!(het_map.insert_type_with_capacity::<i32>(10));
+    assert!(het_map.capacity::<i32>() >= Some(10));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_custom_hasher_type_storage() {
+    let mut het_map: HeterogeneousHashMap<usize, WrappingBuildHasher<hash::RandomState>> =
+        HeterogeneousHashMap::with_hasher(WrappingBuildHasher::new(hash::RandomState::new()));
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+    het_map.insert::<i32>(Key::new(2_usize), 3_i32);
+    het_map.insert_type_with_capacity::<u64>(4);
+
+    assert_eq!(het_map.len::<i32>(), Some(2));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(2_usize)), Some(&3_i32));
+    assert!(het_map.capacity::<u64>() >= Some(4));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_custom_hasher_take_type() {
+    let mut het_map: HeterogeneousHashMap<usize, WrappingBuildHasher<hash::RandomState>> =
+        HeterogeneousHashMap::with_hasher(WrappingBuildHasher::new(hash::RandomState::new()));
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    let map: HomogeneousHashMap<usize, i32, WrappingBuildHasher<hash::RandomState>> = het_map.take_type::<i32>().unwrap();
+
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_take_type_allocator() {
+    let mut het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> = HeterogeneousHashMap::new_in(alloc::Global);
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    let map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> = het_map.take_type::<i32>().unwrap();
+    let _alloc: &alloc::Global = map.allocator();
+
+    assert_eq!(map.get(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_allocator() {
+    let mut het_map: HeterogeneousHashMap<usize, hash::RandomState, alloc::Global> = HeterogeneousHashMap::new_in(alloc::Global);
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    let cloned_het_map = het_map.try_clone().unwrap();
+    let _alloc: &alloc::Global = cloned_het_map.allocator();
+
+    assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn test_heterogeneous_hash_map_counting_allocator() {
+    use self::counting_allocator::CountingAllocator;
+
+    let render_allocator = CountingAllocator::default();
+    let physics_allocator = CountingAllocator::default();
+    let mut render_state: HeterogeneousHashMap<usize, hash::RandomState, CountingAllocator> =
+        HeterogeneousHashMap::new_in(render_allocator.clone());
+    let physics_state: HeterogeneousHashMap<usize, hash::RandomState, CountingAllocator> =
+        HeterogeneousHashMap::new_in(physics_allocator.clone());
+
+    assert_eq!(render_allocator.allocations(), 0);
+
+    for i in 0..16_usize {
+        render_state.insert::<i32>(Key::new(i), i as i32);
+        render_state.insert::<String>(Key::new(i), String::from("mesh"));
+    }
+
+    assert!(render_allocator.allocations() > 0);
+    assert_eq!(physics_allocator.allocations(), 0);
+    assert!(physics_state.is_empty_types());
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn test_heterogeneous_hash_map_counting_allocator_type_storage() {
+    use self::counting_allocator::CountingAllocator;
+
+    let allocator = CountingAllocator::default();
+    let mut het_map: HeterogeneousHashMap<usize, hash::RandomState, CountingAllocator> =
+        HeterogeneousHashMap::new_in(allocator.clone());
+    het_map.insert_type::<i32>();
+    het_map.insert_type::<String>();
+    let allocations_before_reserve = allocator.allocations();
+    het_map.insert_type_with_capacity::<u64>(64);
+
+    assert!(allocator.allocations() > allocations_before_reserve);
+
+    let allocations_before_take = allocator.allocations();
+    let mut map: HomogeneousHashMap<usize, u64, hash::RandomState, CountingAllocator> = het_map.take_type::<u64>().unwrap();
+    map.extend((0..1024_usize).map(|i| (Key::new(i), i as u64)));
+
+    assert!(allocator.allocations() > allocations_before_take);
+    assert_eq!(map.len(), 1024);
+}
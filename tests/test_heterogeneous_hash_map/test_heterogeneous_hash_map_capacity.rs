@@ -0,0 +1,133 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_reserve() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(0_usize), 0_i32);
+    het_map.reserve::<i32>(10);
+
+    assert!(het_map.capacity::<i32>() >= Some(1 + 10));
+
+    let old_capacity = het_map.capacity::<i32>();
+    for i in 1..11 {
+        het_map.insert::<i32>(Key::new(i), i as i32);
+    }
+
+    assert_eq!(het_map.capacity::<i32>(), old_capacity);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_reserve_inserts_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert!(!het_map.contains_type::<String>());
+
+    het_map.reserve::<String>(5);
+
+    assert!(het_map.contains_type::<String>());
+    assert_eq!(het_map.len::<String>(), Some(0));
+    assert!(het_map.capacity::<String>() >= Some(5));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_reserve_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+    het_map.reserve_types(3);
+
+    assert!(het_map.capacity_types() >= het_map.len_types() + 3);
+
+    let old_capacity = het_map.capacity_types();
+    het_map.insert_type::<u8>();
+    het_map.insert_type::<u16>();
+    het_map.insert_type::<u32>();
+
+    assert_eq!(het_map.capacity_types(), old_capacity);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_shrink_types_to_fit() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::with_capacity(64);
+    het_map.insert_type_with_capacity::<i32>(16);
+    het_map.insert_type_with_capacity::<f64>(16);
+
+    het_map.shrink_types_to_fit();
+
+    assert!(het_map.capacity_types() >= 2);
+    assert!(het_map.capacity_types() < 64);
+    assert!(het_map.capacity::<i32>() >= Some(16));
+    assert!(het_map.capacity::<f64>() >= Some(16));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_shrink_to_fit_all() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::with_capacity(64);
+    het_map.insert_type_with_capacity::<i32>(64);
+    het_map.insert_type_with_capacity::<String>(64);
+    het_map.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]);
+
+    het_map.shrink_to_fit_all();
+
+    assert!(het_map.capacity_types() >= 2);
+    assert!(het_map.capacity_types() < 64);
+    assert!(het_map.capacity::<i32>() >= Some(2));
+    assert!(het_map.capacity::<i32>() < Some(64));
+    assert!(het_map.capacity::<String>() < Some(64));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clear_values() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..10).map(|i| (Key::new(i), String::from("foo"))));
+
+    let old_capacity_i32 = het_map.capacity::<i32>();
+    let old_capacity_string = het_map.capacity::<String>();
+    het_map.clear_values();
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.len_map(), 0);
+    assert!(het_map.contains_type::<i32>());
+    assert!(het_map.contains_type::<String>());
+    assert_eq!(het_map.len::<i32>(), Some(0));
+    assert_eq!(het_map.len::<String>(), Some(0));
+    assert_eq!(het_map.capacity::<i32>(), old_capacity_i32);
+    assert_eq!(het_map.capacity::<String>(), old_capacity_string);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clear_values_keeps_metadata() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let old_metadata = het_map.get_metadata::<i32>();
+    het_map.clear_values();
+
+    assert_eq!(het_map.get_metadata::<i32>(), old_metadata);
+    assert!(het_map.try_clone().is_ok());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clear_values_refill() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    for tick in 0..3_i32 {
+        het_map.extend((0..8).map(|i| (Key::new(i), tick)));
+
+        assert_eq!(het_map.len::<i32>(), Some(8));
+        assert_eq!(het_map.get::<i32, _>(&Key::new(7_usize)), Some(&tick));
+
+        het_map.clear_values();
+    }
+
+    assert_eq!(het_map.len_types(), 1);
+    assert_eq!(het_map.len::<i32>(), Some(0));
+}
@@ -0,0 +1,157 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TypeMetadata,
+};
+
+use core::any;
+use std::string::String;
+use std::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_empty() {
+    let het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    let cloned_het_map = het_map.try_clone().unwrap();
+
+    assert!(cloned_het_map.is_empty_types());
+    assert_eq!(cloned_het_map.len_types(), 0);
+    assert_eq!(cloned_het_map.len_map(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<()>();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert_cloneable_type::<String>();
+
+    let cloned_het_map = het_map.try_clone().unwrap();
+
+    assert_eq!(cloned_het_map.len_types(), 3);
+    assert_eq!(cloned_het_map.len_map(), 0);
+    assert_eq!(cloned_het_map.get_metadata::<()>(), Some(TypeMetadata::of::<()>()));
+    assert_eq!(cloned_het_map.get_metadata::<i32>(), Some(TypeMetadata::of::<i32>()));
+    assert_eq!(cloned_het_map.get_metadata::<String>(), Some(TypeMetadata::of::<String>()));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_values() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert_cloneable_type::<String>();
+    het_map.extend([(Key::new(2_usize), 3_i32), (Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]);
+    het_map.extend([(Key::new(1_usize), String::from("foo")), (Key::new(0_usize), String::from("bar"))]);
+
+    let cloned_het_map = het_map.try_clone().unwrap();
+
+    assert_eq!(cloned_het_map.len_types(), 2);
+    assert_eq!(cloned_het_map.len_map(), 5);
+
+    let expected_i32: Vec<(Key<usize, i32>, i32)> = het_map.iter::<i32>().unwrap().map(|(k, v)| (*k, *v)).collect();
+    let result_i32: Vec<(Key<usize, i32>, i32)> = cloned_het_map.iter::<i32>().unwrap().map(|(k, v)| (*k, *v)).collect();
+
+    assert_eq!(result_i32, expected_i32);
+
+    let expected_string: Vec<(Key<usize, String>, String)> =
+        het_map.iter::<String>().unwrap().map(|(k, v)| (*k, v.clone())).collect();
+    let result_string: Vec<(Key<usize, String>, String)> = cloned_het_map
+        .iter::<String>()
+        .unwrap()
+        .map(|(k, v)| (*k, v.clone()))
+        .collect();
+
+    assert_eq!(result_string, expected_string);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_independent() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let mut cloned_het_map = het_map.try_clone().unwrap();
+    cloned_het_map.insert::<i32>(Key::new(0_usize), 2_i32);
+    cloned_het_map.insert::<i32>(Key::new(1_usize), 3_i32);
+
+    assert_eq!(het_map.len::<i32>(), Some(1));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    assert_eq!(cloned_het_map.len::<i32>(), Some(2));
+    assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_preserves_cloneable_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let cloned_het_map = het_map.try_clone().unwrap();
+    let twice_cloned_het_map = cloned_het_map.try_clone().unwrap();
+
+    assert_eq!(twice_cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_insert_cloneable_type_existing_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    assert!(het_map.try_clone().is_err());
+    assert!(!het_map.insert_cloneable_type::<i32>());
+    assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+
+    let cloned_het_map = het_map.try_clone().unwrap();
+
+    assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_not_cloneable() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert_type::<String>();
+
+    let result = het_map.try_clone();
+
+    assert!(result.is_err());
+
+    let error = result.err().unwrap();
+
+    assert_eq!(error.metadata(), TypeMetadata::of::<String>());
+    assert_eq!(error.type_name(), any::type_name::<String>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_after_remove_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert_type::<String>();
+
+    assert!(het_map.try_clone().is_err());
+
+    het_map.remove_type::<String>();
+
+    assert!(het_map.try_clone().is_ok());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clone() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let cloned_het_map = het_map.clone();
+
+    assert_eq!(cloned_het_map.len_types(), 1);
+    assert_eq!(cloned_het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_clone_not_cloneable_panics() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<String>();
+
+    let _ = het_map.clone();
+}
@@ -0,0 +1,371 @@
+use heterogeneous_hash_map::{
+    ConcurrentHeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::{
+    String,
+    ToString,
+};
+use alloc_crate::vec::Vec;
+use std::sync::Barrier;
+use std::thread;
+
+const THREADS: usize = 8;
+const VALUES_PER_THREAD: usize = 250;
+
+fn assert_send_sync<T>()
+where
+    T: Send + Sync,
+{
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_is_send_sync() {
+    assert_send_sync::<ConcurrentHeterogeneousHashMap<usize>>();
+    assert_send_sync::<ConcurrentHeterogeneousHashMap<String>>();
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_empty() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+
+    assert!(het_map.is_empty_types());
+    assert_eq!(het_map.len::<i32>(), None);
+    assert_eq!(het_map.is_empty::<i32>(), None);
+    assert!(het_map.read::<i32>().is_none());
+    assert!(het_map.write::<i32>().is_none());
+    assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), None);
+    assert_eq!(het_map.remove::<i32, _>(&1_usize), None);
+    assert_eq!(het_map.remove_type::<i32>(), None);
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_default() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::default();
+
+    assert!(het_map.is_empty_types());
+
+    het_map.insert(Key::new(1_usize), 2_i32);
+
+    assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), Some(2_i32));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_insert_get_cloned_remove() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+
+    assert_eq!(het_map.insert(Key::new(1_usize), 2_i32), None);
+    assert_eq!(het_map.insert(Key::new(1_usize), String::from("foo")), None);
+    assert_eq!(het_map.insert(Key::new(1_usize), 3_i32), Some(2_i32));
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), Some(3_i32));
+    assert_eq!(het_map.get_cloned::<String, _>(&1_usize), Some(String::from("foo")));
+    assert!(het_map.contains_key::<i32, _>(&1_usize));
+
+    assert_eq!(het_map.remove::<i32, _>(&1_usize), Some(3_i32));
+    assert_eq!(het_map.remove::<i32, _>(&1_usize), None);
+    assert!(!het_map.contains_key::<i32, _>(&1_usize));
+    assert_eq!(het_map.len::<i32>(), Some(0));
+    assert_eq!(het_map.len::<String>(), Some(1));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_insert_type() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+
+    assert!(het_map.insert_type::<i32>());
+    assert!(!het_map.insert_type::<i32>());
+    assert!(het_map.contains_type::<i32>());
+    assert_eq!(het_map.len::<i32>(), Some(0));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_write_guard() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+    {
+        let mut map = het_map.write::<i32>().unwrap();
+        map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+        map.retain(|_key, value| *value % 2 == 0);
+    }
+    let map = het_map.read::<i32>().unwrap();
+
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_remove_type() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    het_map.insert(Key::new(3_usize), 4_i32);
+    het_map.insert(Key::new(1_usize), 5_u64);
+
+    assert_eq!(het_map.remove_type::<i32>(), Some(2));
+    assert!(!het_map.contains_type::<i32>());
+    assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), None);
+    assert_eq!(het_map.get_cloned::<u64, _>(&1_usize), Some(5_u64));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_guard_outlives_remove_type() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+    let mut map = het_map.write::<String>().unwrap();
+
+    thread::scope(|scope| {
+        let remover = scope.spawn(|| het_map.remove_type::<String>());
+        while het_map.contains_type::<String>() {
+            thread::yield_now();
+        }
+
+        map.insert(Key::new(2_usize), String::from("bar"));
+
+        assert_eq!(map.len(), 2);
+
+        drop(map);
+
+        assert_eq!(remover.join().unwrap(), Some(2));
+    });
+
+    assert!(!het_map.contains_type::<String>());
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_read_guard_outlives_remove_type() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let map = het_map.read::<i32>().unwrap();
+
+    assert_eq!(het_map.remove_type::<i32>(), Some(1));
+    assert!(!het_map.contains_type::<i32>());
+    assert_eq!(map.get(&1_usize), Some(&2_i32));
+
+    het_map.insert(Key::new(3_usize), 4_i32);
+
+    assert_eq!(map.len(), 1);
+    assert_eq!(het_map.len::<i32>(), Some(1));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_panic_keeps_partial_updates() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+
+    let result = thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let mut map = het_map.write::<i32>().unwrap();
+                map.insert(Key::new(1_usize), 1_i32);
+                panic!("interrupted update");
+            })
+            .join()
+    });
+
+    assert!(result.is_err());
+    assert_eq!(het_map.len::<i32>(), Some(1));
+    assert_eq!(het_map.get_cloned::<i32, _>(&1_usize), Some(1_i32));
+    assert_eq!(het_map.insert(Key::new(2_usize), 2_i32), None);
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_threads_insert_distinct_types() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            for i in 0..VALUES_PER_THREAD {
+                het_map.insert(Key::new(i), i as i32);
+            }
+        });
+        scope.spawn(|| {
+            for i in 0..VALUES_PER_THREAD {
+                het_map.insert(Key::new(i), i as f64);
+            }
+        });
+        scope.spawn(|| {
+            for i in 0..VALUES_PER_THREAD {
+                het_map.insert(Key::new(i), i.to_string());
+            }
+        });
+    });
+
+    assert_eq!(het_map.len_types(), 3);
+    for i in 0..VALUES_PER_THREAD {
+        assert_eq!(het_map.get_cloned::<i32, _>(&i), Some(i as i32));
+        assert_eq!(het_map.get_cloned::<f64, _>(&i), Some(i as f64));
+        assert_eq!(het_map.get_cloned::<String, _>(&i), Some(i.to_string()));
+    }
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_threads_insert_same_type() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    let barrier = Barrier::new(THREADS);
+
+    thread::scope(|scope| {
+        for thread_index in 0..THREADS {
+            let het_map = &het_map;
+            let barrier = &barrier;
+            scope.spawn(move || {
+                barrier.wait();
+                for i in 0..VALUES_PER_THREAD {
+                    let key = thread_index * VALUES_PER_THREAD + i;
+                    het_map.insert(Key::new(key), key as u64);
+                }
+            });
+        }
+    });
+
+    assert_eq!(het_map.len_types(), 1);
+    assert_eq!(het_map.len::<u64>(), Some(THREADS * VALUES_PER_THREAD));
+    for key in 0..(THREADS * VALUES_PER_THREAD) {
+        assert_eq!(het_map.get_cloned::<u64, _>(&key), Some(key as u64));
+    }
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_threads_insert_and_remove() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+
+    thread::scope(|scope| {
+        for thread_index in 0..THREADS {
+            let het_map = &het_map;
+            scope.spawn(move || {
+                for i in 0..VALUES_PER_THREAD {
+                    let key = thread_index * VALUES_PER_THREAD + i;
+                    het_map.insert(Key::new(key), key as i32);
+                    if key % 2 == 1 {
+                        assert_eq!(het_map.remove::<i32, _>(&key), Some(key as i32));
+                    }
+                }
+            });
+        }
+    });
+
+    assert_eq!(het_map.len::<i32>(), Some(THREADS * VALUES_PER_THREAD / 2));
+    for key in 0..(THREADS * VALUES_PER_THREAD) {
+        let expected = if key % 2 == 0 { Some(key as i32) } else { None };
+
+        assert_eq!(het_map.get_cloned::<i32, _>(&key), expected);
+    }
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_readers_share_type_lock() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let barrier = Barrier::new(THREADS);
+
+    // Every reader holds its guard while waiting on the barrier, so the barrier can only be
+    // passed if all the read guards are held at the same time.
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            let het_map = &het_map;
+            let barrier = &barrier;
+            scope.spawn(move || {
+                let map = het_map.read::<i32>().unwrap();
+                barrier.wait();
+
+                assert_eq!(map.get(&1_usize), Some(&2_i32));
+            });
+        }
+    });
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_writer_does_not_block_other_types() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+    let mut map = het_map.write::<i32>().unwrap();
+
+    thread::scope(|scope| {
+        let worker = scope.spawn(|| {
+            het_map.insert(Key::new(2_usize), String::from("bar"));
+            het_map.insert(Key::new(1_usize), 3_u64);
+
+            het_map.get_cloned::<String, _>(&1_usize)
+        });
+
+        // The worker never touches `i32`, so it completes while the `i32` write lock is held.
+        assert_eq!(worker.join().unwrap(), Some(String::from("foo")));
+
+        map.insert(Key::new(2_usize), 4_i32);
+    });
+    drop(map);
+
+    assert_eq!(het_map.len_types(), 3);
+    assert_eq!(het_map.len::<i32>(), Some(2));
+    assert_eq!(het_map.len::<String>(), Some(2));
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_writers_are_exclusive() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(0_usize), 0_u64);
+
+    // Each increment reads and writes the counter through one write guard, so no increment can
+    // be lost unless two writers hold the lock at once.
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            let het_map = &het_map;
+            scope.spawn(move || {
+                for _ in 0..VALUES_PER_THREAD {
+                    let mut map = het_map.write::<u64>().unwrap();
+                    let counter = map.get_mut(&0_usize).unwrap();
+                    *counter += 1;
+                }
+            });
+        }
+    });
+
+    assert_eq!(
+        het_map.get_cloned::<u64, _>(&0_usize),
+        Some((THREADS * VALUES_PER_THREAD) as u64)
+    );
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_threads_insert_type_once() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    let barrier = Barrier::new(THREADS);
+
+    let inserted = thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let het_map = &het_map;
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    barrier.wait();
+                    het_map.insert_type::<i32>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|inserted| *inserted)
+            .count()
+    });
+
+    assert_eq!(inserted, 1);
+    assert_eq!(het_map.len_types(), 1);
+}
+
+#[test]
+fn test_concurrent_heterogeneous_hash_map_debug() {
+    let het_map: ConcurrentHeterogeneousHashMap<usize> = ConcurrentHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let map = het_map.write::<i32>().unwrap();
+
+    assert_eq!(
+        alloc_crate::format!("{:?}", het_map),
+        "ConcurrentHeterogeneousHashMap { len_types: 1, .. }"
+    );
+    assert_eq!(alloc_crate::format!("{:?}", map), alloc_crate::format!("{:?}", &*map));
+}
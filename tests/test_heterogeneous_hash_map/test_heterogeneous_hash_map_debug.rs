@@ -0,0 +1,106 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use std::format;
+use std::string::String;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CustomKey(usize);
+
+#[derive(Clone, PartialEq, Eq)]
+struct NotDebugValue(i32);
+
+#[test]
+fn test_heterogeneous_hash_map_debug_empty() {
+    let het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    let formatted = format!("{:?}", het_map);
+
+    assert_eq!(formatted, "HeterogeneousHashMap { entries: [] }");
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_summary() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<NotDebugValue>();
+    het_map.insert::<NotDebugValue>(Key::new(0_usize), NotDebugValue(1_i32));
+    het_map.insert::<NotDebugValue>(Key::new(1_usize), NotDebugValue(2_i32));
+
+    let formatted = format!("{:?}", het_map);
+
+    assert!(formatted.contains("NotDebugValue\""));
+    assert!(formatted.contains("length: 2"));
+    assert!(!formatted.contains("values"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_values() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_debug_type::<i32>();
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let formatted = format!("{:?}", het_map);
+
+    assert!(formatted.contains("type_name: \"i32\""));
+    assert!(formatted.contains("length: 2"));
+    assert!(formatted.contains("values: {Key { id: 1 }: 2, Key { id: 0 }: 1}"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_mixed_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_debug_type::<String>();
+    het_map.insert_type::<NotDebugValue>();
+    het_map.insert::<String>(Key::new(0_usize), String::from("foo"));
+    het_map.insert::<NotDebugValue>(Key::new(0_usize), NotDebugValue(1_i32));
+
+    let formatted = format!("{:?}", het_map);
+
+    assert!(formatted.contains("values: {Key { id: 0 }: \"foo\"}"));
+    assert_eq!(formatted.matches("values").count(), 1);
+    assert_eq!(formatted.matches("TypeEntry").count(), 2);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_sorted_by_type_name() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_debug_type::<u8>();
+    het_map.insert_debug_type::<i64>();
+    het_map.insert_debug_type::<f32>();
+    het_map.insert_debug_type::<bool>();
+
+    let formatted = format!("{:?}", het_map);
+    let bool_position = formatted.find("\"bool\"").unwrap();
+    let f32_position = formatted.find("\"f32\"").unwrap();
+    let i64_position = formatted.find("\"i64\"").unwrap();
+    let u8_position = formatted.find("\"u8\"").unwrap();
+
+    assert!(bool_position < f32_position);
+    assert!(f32_position < i64_position);
+    assert!(i64_position < u8_position);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_pretty() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_debug_type::<i32>();
+    het_map.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let formatted = format!("{:#?}", het_map);
+
+    assert!(formatted.contains("    values: {\n"));
+    assert!(formatted.contains("Key {\n"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_debug_custom_key() {
+    let mut het_map: HeterogeneousHashMap<CustomKey> = HeterogeneousHashMap::new();
+    het_map.insert_debug_type::<i32>();
+    het_map.insert::<i32>(Key::new(CustomKey(3_usize)), 4_i32);
+
+    let formatted = format!("{:?}", het_map);
+
+    assert!(formatted.contains("values: {Key { id: CustomKey(3) }: 4}"));
+}
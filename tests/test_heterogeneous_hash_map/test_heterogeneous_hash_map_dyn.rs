@@ -0,0 +1,246 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use core::any;
+use core::any::TypeId;
+use core::fmt;
+use core::hash;
+use core::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+#[test]
+fn test_heterogeneous_hash_map_len_by_id() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..3).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+    het_map.insert_type::<u8>();
+
+    assert_eq!(het_map.len_by_id(TypeId::of::<i32>()), het_map.len::<i32>());
+    assert_eq!(het_map.len_by_id(TypeId::of::<String>()), het_map.len::<String>());
+    assert_eq!(het_map.len_by_id(TypeId::of::<u8>()), Some(0));
+    assert_eq!(het_map.len_by_id(TypeId::of::<f64>()), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_dyn() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..4).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+
+    for i in 0..4 {
+        let value = het_map.get_dyn(TypeId::of::<i32>(), &i).unwrap();
+
+        assert_eq!(value.downcast_ref::<i32>(), het_map.get::<i32, _>(&Key::new(i)));
+        assert!(value.downcast_ref::<String>().is_none());
+    }
+
+    let value = het_map.get_dyn(TypeId::of::<String>(), &3_usize).unwrap();
+
+    assert_eq!(value.downcast_ref::<String>().map(String::as_str), Some("quux"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_dyn_missing() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..2).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..2).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+
+    assert!(het_map.get_dyn(TypeId::of::<i32>(), &2_usize).is_none());
+    assert!(het_map.get_dyn(TypeId::of::<f64>(), &0_usize).is_none());
+    assert!(!het_map.contains_type::<f64>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_dyn_mut() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..3).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+    for i in 0..3 {
+        let value = het_map.get_dyn_mut(TypeId::of::<i32>(), &i).unwrap();
+        *value.downcast_mut::<i32>().unwrap() *= 10_i32;
+    }
+
+    assert_eq!(het_map.values::<i32>().unwrap().copied().collect::<Vec<_>>(), [0, 10, 20]);
+    assert!(het_map.get_dyn_mut(TypeId::of::<i32>(), &3_usize).is_none());
+    assert!(het_map.get_dyn_mut(TypeId::of::<f64>(), &0_usize).is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_contains_key_dyn() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..3).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+
+    for i in 0..3 {
+        assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &i));
+        assert!(het_map.contains_key_dyn(TypeId::of::<String>(), &i));
+    }
+
+    assert!(!het_map.contains_key_dyn(TypeId::of::<i32>(), &3_usize));
+    assert!(!het_map.contains_key_dyn(TypeId::of::<f64>(), &0_usize));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_dyn() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..3).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..3).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+    let value = het_map.remove_dyn(TypeId::of::<i32>(), &1_usize).unwrap();
+
+    assert_eq!(value.downcast::<i32>().ok().map(|value| *value), Some(1_i32));
+    assert!(!het_map.contains_key::<i32, _>(&Key::new(1_usize)));
+    assert_eq!(het_map.len::<i32>(), Some(2));
+    assert_eq!(het_map.len::<String>(), Some(3));
+    assert!(het_map.remove_dyn(TypeId::of::<i32>(), &1_usize).is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_dyn_keeps_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..1).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..1).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+    let value = het_map.remove_dyn(TypeId::of::<String>(), &0_usize).unwrap();
+
+    assert!(value.is::<String>());
+    assert!(het_map.contains_type::<String>());
+    assert_eq!(het_map.len_by_id(TypeId::of::<String>()), Some(0));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_dyn_manifest_lookup() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..2).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..2).map(|i| (Key::new(i), String::from(["foo", "bar", "baz", "quux"][i % 4]))));
+    let manifest = [TypeId::of::<i32>(), TypeId::of::<String>(), TypeId::of::<f64>()];
+    let found: Vec<bool> = manifest
+        .iter()
+        .map(|type_id| het_map.contains_key_dyn(*type_id, &1_usize))
+        .collect();
+
+    assert_eq!(found, [true, true, false]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_dyn_without_key_lookup() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<i32>();
+    het_map
+        .get_map_mut::<i32>()
+        .unwrap()
+        .extend((0..4).map(|i| (Key::new(i), i as i32)));
+
+    assert_eq!(
+        het_map
+            .get_dyn(TypeId::of::<i32>(), &2_usize)
+            .and_then(|value| value.downcast_ref::<i32>()),
+        Some(&2_i32)
+    );
+    assert!(het_map.get_dyn(TypeId::of::<i32>(), &4_usize).is_none());
+
+    if let Some(value) = het_map
+        .get_dyn_mut(TypeId::of::<i32>(), &1_usize)
+        .and_then(|value| value.downcast_mut::<i32>())
+    {
+        *value = 10;
+    }
+
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&10_i32));
+    assert_eq!(
+        het_map
+            .remove_dyn(TypeId::of::<i32>(), &0_usize)
+            .and_then(|value| value.downcast::<i32>().ok())
+            .map(|value| *value),
+        Some(0_i32)
+    );
+
+    het_map.insert(Key::new(5_usize), 5_i32);
+
+    assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &5_usize));
+    assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &3_usize));
+    assert!(!het_map.contains_key_dyn(TypeId::of::<i32>(), &0_usize));
+}
+
+static HASH_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CountingKey(usize);
+
+impl hash::Hash for CountingKey {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: hash::Hasher,
+    {
+        HASH_CALLS.fetch_add(1, Ordering::Relaxed);
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_dyn_key_lookup_after_get_or_insert_maps_mut() {
+    let mut het_map: HeterogeneousHashMap<CountingKey> = HeterogeneousHashMap::new();
+    het_map
+        .get_or_insert_map_mut::<i32>()
+        .extend((0..4).map(|i| (Key::new(CountingKey(i)), i as i32)));
+
+    let hash_calls = HASH_CALLS.load(Ordering::Relaxed);
+
+    assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &CountingKey(2)));
+    assert_eq!(HASH_CALLS.load(Ordering::Relaxed), hash_calls);
+
+    let (map,) = het_map.get_or_insert_maps_mut::<(i32,)>();
+    map.insert(Key::new(CountingKey(4)), 4_i32);
+    let hash_calls = HASH_CALLS.load(Ordering::Relaxed);
+
+    assert!(het_map.contains_key_dyn(TypeId::of::<i32>(), &CountingKey(4)));
+    assert!(HASH_CALLS.load(Ordering::Relaxed) > hash_calls);
+    assert_eq!(
+        het_map
+            .get_dyn(TypeId::of::<i32>(), &CountingKey(3))
+            .and_then(|value| value.downcast_ref::<i32>()),
+        Some(&3_i32)
+    );
+    assert_eq!(
+        het_map
+            .remove_dyn(TypeId::of::<i32>(), &CountingKey(1))
+            .and_then(|value| value.downcast::<i32>().ok())
+            .map(|value| *value),
+        Some(1_i32)
+    );
+    assert!(!het_map.contains_key_dyn(TypeId::of::<i32>(), &CountingKey(1)));
+}
+
+#[derive(Clone, Debug)]
+struct UnhashableKey;
+
+fn register_types<K>(het_map: &mut HeterogeneousHashMap<K>)
+where
+    K: any::Any + Clone + fmt::Debug,
+{
+    het_map.insert_type::<i32>();
+    het_map.insert_type_with_capacity::<u8>(4);
+    het_map.try_insert_type_with_capacity::<u16>(4).unwrap();
+    het_map.insert_cloneable_type::<u32>();
+    het_map.insert_debug_type::<u64>();
+    het_map.register_as::<i64, dyn fmt::Debug>(|value| value);
+    het_map.get_or_insert_map_mut::<f32>();
+    het_map.get_or_insert_with_capacity_map_mut::<f64>(4);
+    het_map.reserve::<i8>(4);
+    het_map.try_reserve::<i16>(4).unwrap();
+}
+
+#[test]
+fn test_heterogeneous_hash_map_register_types_without_hashable_keys() {
+    let mut het_map: HeterogeneousHashMap<UnhashableKey> = HeterogeneousHashMap::new();
+    register_types(&mut het_map);
+
+    assert_eq!(het_map.len_types(), 10);
+    assert_eq!(het_map.len::<i32>(), Some(0));
+    assert!(het_map.capacity::<f64>() >= Some(4));
+    assert!(het_map.capacity::<i16>() >= Some(4));
+}
@@ -0,0 +1,173 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TypeMetadata,
+};
+
+use core::any;
+use std::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_empty() {
+    let het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    let het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(true));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_reflexive() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_comparable_type::<i32>();
+    het_map.insert_comparable_type::<String>();
+    het_map.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]);
+    het_map.extend([(Key::new(0_usize), String::from("foo"))]);
+
+    assert_eq!(het_map.try_eq(&het_map), Ok(true));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_insertion_order() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert_comparable_type::<String>();
+    het_map1.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32), (Key::new(2_usize), 3_i32)]);
+    het_map1.extend([(Key::new(0_usize), String::from("foo")), (Key::new(1_usize), String::from("bar"))]);
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<String>();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.extend([(Key::new(1_usize), String::from("bar")), (Key::new(0_usize), String::from("foo"))]);
+    het_map2.extend([(Key::new(2_usize), 3_i32), (Key::new(1_usize), 2_i32), (Key::new(0_usize), 1_i32)]);
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(true));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_different_values() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.insert::<i32>(Key::new(0_usize), 2_i32);
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(false));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_different_keys() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.insert::<i32>(Key::new(1_usize), 1_i32);
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(false));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_different_types() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.insert_comparable_type::<u32>();
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(false));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_empty_type_is_not_missing_type() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert_comparable_type::<u32>();
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_different_types_not_comparable() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_type::<String>();
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_type::<i32>();
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(false));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_not_comparable() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert_type::<String>();
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.insert_type::<String>();
+
+    let result = het_map1.try_eq(&het_map2);
+
+    assert!(result.is_err());
+
+    let error = result.unwrap_err();
+
+    assert_eq!(error.metadata(), TypeMetadata::of::<String>());
+    assert_eq!(error.type_name(), any::type_name::<String>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_eq_comparable_in_one_map() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<String>();
+    het_map1.insert::<String>(Key::new(0_usize), String::from("foo"));
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_type::<String>();
+    het_map2.insert::<String>(Key::new(0_usize), String::from("foo"));
+
+    assert_eq!(het_map1.try_eq(&het_map2), Ok(true));
+    assert_eq!(het_map2.try_eq(&het_map1), Ok(true));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_eq() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_comparable_type::<i32>();
+    het_map1.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_comparable_type::<i32>();
+    het_map2.insert::<i32>(Key::new(0_usize), 1_i32);
+
+    assert!(het_map1 == het_map2);
+
+    het_map2.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    assert!(het_map1 != het_map2);
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_eq_not_comparable_panics() {
+    let mut het_map1: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map1.insert_type::<String>();
+
+    let mut het_map2: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map2.insert_type::<String>();
+
+    let _ = het_map1 == het_map2;
+}
@@ -0,0 +1,270 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TypeMetadata,
+    TypeStorage,
+    TypeStorageMut,
+    TypeVisitor,
+    TypeVisitorMut,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use core::any::TypeId;
+use std::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+struct Inventory {
+    entries: Vec<(TypeId, usize)>,
+    empty_types: usize,
+}
+
+impl TypeVisitor<usize, hash::RandomState, alloc::Global> for Inventory {
+    fn visit(&mut self, storage: TypeStorage<'_, usize, hash::RandomState, alloc::Global>) {
+        if storage.is_empty() {
+            self.empty_types += 1;
+        }
+
+        let type_id = storage.metadata().type_id();
+        self.entries.extend(storage.iter().map(|(key, _value)| (type_id, *key)));
+    }
+}
+
+struct DoubleIntegers;
+
+impl TypeVisitorMut<usize, hash::RandomState, alloc::Global> for DoubleIntegers {
+    fn visit_mut(&mut self, mut storage: TypeStorageMut<'_, usize, hash::RandomState, alloc::Global>) {
+        if let Some(map) = storage.downcast_mut::<i32>() {
+            for value in map.values_mut() {
+                *value *= 2;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_erased() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    let mut result: Vec<(TypeId, usize)> = het_map
+        .iter_erased()
+        .map(|(metadata, key, _value)| (metadata.type_id(), *key))
+        .collect();
+    result.sort();
+    let mut expected = Vec::from([
+        (TypeId::of::<i32>(), 1),
+        (TypeId::of::<i32>(), 2),
+        (TypeId::of::<i32>(), 3),
+        (TypeId::of::<String>(), 1),
+        (TypeId::of::<f64>(), 2),
+    ]);
+    expected.sort();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_erased_values_match_metadata() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+
+    for (metadata, key, value) in het_map.iter_erased() {
+        assert_eq!(value.type_id(), metadata.type_id());
+        if let Some(value) = value.downcast_ref::<i32>() {
+            assert_eq!(*value, (*key as i32) * 10);
+        }
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_erased_storage_order() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    let keys: Vec<usize> = het_map
+        .iter_erased()
+        .filter(|(metadata, _key, _value)| *metadata == TypeMetadata::of::<i32>())
+        .map(|(_metadata, key, _value)| *key)
+        .collect();
+
+    assert_eq!(keys, [1, 2, 3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_erased_empty() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert_eq!(het_map.iter_erased().count(), 0);
+
+    het_map.insert_type::<i32>();
+
+    assert_eq!(het_map.iter_erased().count(), 0);
+    assert_eq!(het_map.iter_erased_mut().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_erased_mut() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    for (_metadata, _key, value) in het_map.iter_erased_mut() {
+        if let Some(value) = value.downcast_mut::<i32>() {
+            *value += 1;
+        } else if let Some(value) = value.downcast_mut::<String>() {
+            value.push('!');
+        } else if let Some(value) = value.downcast_mut::<f64>() {
+            *value = -*value;
+        }
+    }
+
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&11_i32));
+    assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&21_i32));
+    assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&31_i32));
+    assert_eq!(het_map.get::<String, _>(&1_usize).map(String::as_str), Some("one!"));
+    assert_eq!(het_map.get::<f64, _>(&2_usize), Some(&-2.5_f64));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_visits_every_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    let mut type_ids = Vec::new();
+    het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| type_ids.push(storage.metadata().type_id()));
+    type_ids.sort();
+    let mut expected = Vec::from([TypeId::of::<i32>(), TypeId::of::<String>(), TypeId::of::<f64>(), TypeId::of::<u8>()]);
+    expected.sort();
+
+    assert_eq!(type_ids, expected);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_visitor() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    let mut visitor = Inventory {
+        entries: Vec::new(),
+        empty_types: 0,
+    };
+    het_map.for_each_type(&mut visitor);
+    visitor.entries.sort();
+    let mut expected: Vec<(TypeId, usize)> = het_map
+        .iter_erased()
+        .map(|(metadata, key, _value)| (metadata.type_id(), *key))
+        .collect();
+    expected.sort();
+
+    assert_eq!(visitor.entries, expected);
+    assert_eq!(visitor.empty_types, 1);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_storage_get() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    let mut found = 0;
+    het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+        if let Some(value) = storage.get(&2_usize) {
+            assert_eq!(value.type_id(), storage.metadata().type_id());
+            found += 1;
+        }
+    });
+
+    assert_eq!(found, 2);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_storage_downcast_ref() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    het_map.for_each_type(&mut |storage: TypeStorage<'_, usize, _, _>| {
+        let is_i32 = storage.metadata() == TypeMetadata::of::<i32>();
+
+        assert_eq!(storage.downcast_ref::<i32>().is_some(), is_i32);
+        if let Some(map) = storage.downcast_ref::<i32>() {
+            assert_eq!(map.len(), 3);
+            assert_eq!(map.get(&3_usize), Some(&30_i32));
+        }
+    });
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_mut_visitor() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    het_map.for_each_type_mut(&mut DoubleIntegers);
+
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&20_i32));
+    assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&40_i32));
+    assert_eq!(het_map.get::<i32, _>(&3_usize), Some(&60_i32));
+    assert_eq!(het_map.get::<f64, _>(&2_usize), Some(&2.5_f64));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_for_each_type_mut_storage_operations() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 10_i32);
+    het_map.insert(Key::new(2_usize), 20_i32);
+    het_map.insert(Key::new(3_usize), 30_i32);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert_type::<u8>();
+    het_map.for_each_type_mut(&mut |mut storage: TypeStorageMut<'_, usize, _, _>| {
+        if storage.metadata() == TypeMetadata::of::<String>() {
+            storage.clear();
+        } else if let Some(value) = storage.get_mut(&2_usize).and_then(|value| value.downcast_mut::<f64>()) {
+            *value = 0.5;
+        }
+    });
+
+    assert_eq!(het_map.len::<String>(), Some(0));
+    assert_eq!(het_map.len::<i32>(), Some(3));
+    assert_eq!(het_map.get::<f64, _>(&2_usize), Some(&0.5_f64));
+}
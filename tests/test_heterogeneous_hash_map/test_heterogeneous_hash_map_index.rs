@@ -0,0 +1,121 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_get_index_per_type_order() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..8).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..8).rev().map(|i| (Key::new(i), i as u64)));
+
+    for i in 0..8 {
+        assert_eq!(het_map.get_index::<i32>(i), Some((&Key::new(i), &((i as i32) * 10))));
+        assert_eq!(het_map.get_index::<u64>(i), Some((&Key::new(7 - i), &((7 - i) as u64))));
+    }
+
+    assert_eq!(het_map.get_index::<i32>(8), None);
+    assert_eq!(het_map.get_index::<u64>(8), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_index_missing_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.get_index::<String>(0), None);
+    assert_eq!(het_map.get_index_of::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.get_full::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.first::<String>(), None);
+    assert_eq!(het_map.last::<String>(), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_index_mut() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+    if let Some((_, value)) = het_map.get_index_mut::<u64>(0) {
+        *value = 100;
+    }
+
+    assert_eq!(het_map.get::<u64, _>(&Key::new(3_usize)), Some(&100_u64));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(3_usize)), Some(&30_i32));
+    assert_eq!(het_map.get_index_mut::<String>(0), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_index_of_get_full() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.get_index_of::<i32, _>(&Key::new(1_usize)), Some(1));
+    assert_eq!(het_map.get_index_of::<u64, _>(&Key::new(1_usize)), Some(2));
+    assert_eq!(
+        het_map.get_full::<i32, _>(&Key::new(1_usize)),
+        Some((1, &Key::new(1_usize), &10_i32))
+    );
+    assert_eq!(
+        het_map.get_full::<u64, _>(&Key::new(1_usize)),
+        Some((2, &Key::new(1_usize), &1_u64))
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_first_last() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.first::<i32>(), Some((&Key::new(0_usize), &0_i32)));
+    assert_eq!(het_map.last::<i32>(), Some((&Key::new(3_usize), &30_i32)));
+    assert_eq!(het_map.first::<u64>(), Some((&Key::new(3_usize), &3_u64)));
+    assert_eq!(het_map.last::<u64>(), Some((&Key::new(0_usize), &0_u64)));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_pop() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..2).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..2).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.pop::<i32>(), Some((Key::new(1_usize), 10_i32)));
+    assert_eq!(het_map.pop::<i32>(), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(het_map.pop::<i32>(), None);
+    assert_eq!(het_map.pop::<String>(), None);
+    assert!(het_map.contains_type::<i32>());
+    assert_eq!(het_map.len::<u64>(), Some(2));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_swap_remove_index() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.swap_remove_index::<i32>(0), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(3_usize), &30_i32)));
+    assert_eq!(het_map.len::<i32>(), Some(3));
+    assert_eq!(het_map.len::<u64>(), Some(4));
+    assert_eq!(het_map.swap_remove_index::<i32>(3), None);
+    assert_eq!(het_map.swap_remove_index::<String>(0), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_shift_remove_index() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    het_map.extend((0..4).rev().map(|i| (Key::new(i), i as u64)));
+
+    assert_eq!(het_map.shift_remove_index::<i32>(0), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(het_map.get_index::<i32>(0), Some((&Key::new(1_usize), &10_i32)));
+    assert_eq!(het_map.get_index::<i32>(1), Some((&Key::new(2_usize), &20_i32)));
+    assert_eq!(het_map.get_index::<i32>(2), Some((&Key::new(3_usize), &30_i32)));
+    assert_eq!(het_map.len::<u64>(), Some(4));
+    assert_eq!(het_map.shift_remove_index::<i32>(3), None);
+    assert_eq!(het_map.shift_remove_index::<String>(0), None);
+}
@@ -0,0 +1,198 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::{
+    Health,
+    Position,
+    Velocity,
+    sorted,
+    world,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_join_two_types() {
+    let het_map = world();
+    let keys = sorted(het_map.join::<(Position, Velocity)>().map(|(key, _, _)| *key));
+
+    assert_eq!(keys, [0, 3, 6, 9]);
+
+    for (key, position, velocity) in het_map.join::<(Position, Velocity)>() {
+        assert_eq!(position, &Position(*key as i32, 0));
+        assert_eq!(velocity, &Velocity(1, 1));
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_order_independent() {
+    let het_map = world();
+    let keys1 = sorted(het_map.join::<(Position, Velocity)>().map(|(key, _, _)| *key));
+    let keys2 = sorted(het_map.join::<(Velocity, Position)>().map(|(key, _, _)| *key));
+
+    assert_eq!(keys1, keys2);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_three_types() {
+    let het_map = world();
+    let joined = sorted(
+        het_map
+            .join::<(Position, Velocity, Health)>()
+            .map(|(key, _, _, health)| (*key, health.0)),
+    );
+
+    assert_eq!(joined, [(0, 0), (6, 60)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_one_type() {
+    let het_map = world();
+
+    assert_eq!(het_map.join::<(Velocity,)>().count(), 4);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_missing_type() {
+    let het_map = world();
+
+    assert_eq!(het_map.join::<(Position, String)>().count(), 0);
+    assert_eq!(het_map.join::<(String, Position)>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_empty_type() {
+    let mut het_map = world();
+    het_map.insert_type::<String>();
+
+    assert_eq!(het_map.join::<(Position, String)>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_repeated_type() {
+    let het_map = world();
+
+    assert_eq!(het_map.join::<(Velocity, Velocity)>().count(), 4);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_with_optional_type() {
+    let het_map = world();
+    let joined = sorted(
+        het_map
+            .join_with::<(Position,), (Velocity,)>()
+            .map(|((key, position), (velocity,))| (*key, position.0 + velocity.map_or(0, |velocity| velocity.0))),
+    );
+
+    assert_eq!(
+        joined,
+        [(0, 1), (1, 1), (2, 2), (3, 4), (4, 4), (5, 5), (6, 7), (7, 7), (8, 8), (9, 10)]
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_with_two_optional_types() {
+    let het_map = world();
+    let joined = sorted(
+        het_map
+            .join_with::<(Position, Velocity), (Health, String)>()
+            .map(|((key, _, _), (health, string))| (*key, health.map(|health| health.0), string.is_some())),
+    );
+
+    assert_eq!(
+        joined,
+        [(0, Some(0), false), (3, None, false), (6, Some(60), false), (9, None, false)]
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_with_required_missing_type() {
+    let het_map = world();
+
+    assert_eq!(het_map.join_with::<(String,), (Position,)>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_with_optional_required_type() {
+    let het_map = world();
+    for ((key, velocity), (same_velocity,)) in het_map.join_with::<(Velocity,), (Velocity,)>() {
+        assert_eq!(same_velocity, Some(velocity));
+        assert_eq!(key % 3, 0);
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_mut() {
+    let mut het_map = world();
+    for (_key, position, velocity) in het_map.join_mut::<(Position, Velocity)>() {
+        position.0 += velocity.0;
+        position.1 += velocity.1;
+    }
+
+    for i in 0..10 {
+        let expected = if i % 3 == 0 {
+            Position(i as i32 + 1, 1)
+        } else {
+            Position(i as i32, 0)
+        };
+
+        assert_eq!(het_map.get::<Position, _>(&Key::new(i)), Some(&expected));
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_mut_keys() {
+    let mut het_map = world();
+    let keys = sorted(het_map.join_mut::<(Health, Velocity)>().map(|(key, _, _)| *key));
+
+    assert_eq!(keys, [0, 6]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_mut_three_types() {
+    let mut het_map = world();
+    for (_key, position, velocity, health) in het_map.join_mut::<(Position, Velocity, Health)>() {
+        position.0 = 100;
+        velocity.0 = 0;
+        health.0 = 1;
+    }
+
+    assert_eq!(het_map.get::<Position, _>(&Key::new(6_usize)), Some(&Position(100, 0)));
+    assert_eq!(het_map.get::<Velocity, _>(&Key::new(6_usize)), Some(&Velocity(0, 1)));
+    assert_eq!(het_map.get::<Health, _>(&Key::new(6_usize)), Some(&Health(1)));
+    assert_eq!(het_map.get::<Health, _>(&Key::new(4_usize)), Some(&Health(40)));
+    assert_eq!(het_map.get::<Position, _>(&Key::new(3_usize)), Some(&Position(3, 0)));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_mut_shuffled_storage() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend([5_usize, 1, 4, 2, 3].map(|i| (Key::new(i), i as i32)));
+    het_map.extend([2_usize, 3, 5, 1].map(|i| (Key::new(i), i as u64 * 10)));
+    let mut joined = Vec::new();
+    for (key, int, long) in het_map.join_mut::<(i32, u64)>() {
+        *int *= -1;
+        joined.push((*key, *int, *long));
+    }
+
+    assert_eq!(sorted(joined), [(1, -1, 10), (2, -2, 20), (3, -3, 30), (5, -5, 50)]);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(4_usize)), Some(&4_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_join_mut_missing_type() {
+    let mut het_map = world();
+
+    assert_eq!(het_map.join_mut::<(Position, String)>().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_join_mut_repeated_type() {
+    let mut het_map = world();
+
+    let _ = het_map.join_mut::<(Position, Position)>().count();
+}
@@ -0,0 +1,103 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TypeMetadata,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use core::any::TypeId;
+
+fn sorted_type_ids(het_map: &HeterogeneousHashMap<usize>, key: &usize) -> Vec<TypeId> {
+    let mut type_ids: Vec<TypeId> = het_map.types_for_key(key).map(|metadata| metadata.type_id()).collect();
+    type_ids.sort();
+
+    type_ids
+}
+
+#[test]
+fn test_heterogeneous_hash_map_types_for_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(42_usize), 1_i32);
+    het_map.insert(Key::new(42_usize), String::from("player"));
+    het_map.insert(Key::new(42_usize), 1.5_f64);
+    het_map.insert(Key::new(7_usize), 2_i32);
+    het_map.insert_type::<u8>();
+    let mut expected = Vec::from([TypeId::of::<i32>(), TypeId::of::<String>(), TypeId::of::<f64>()]);
+    expected.sort();
+
+    assert_eq!(sorted_type_ids(&het_map, &42_usize), expected);
+    assert_eq!(sorted_type_ids(&het_map, &7_usize), [TypeId::of::<i32>()]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_types_for_key_missing_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(42_usize), 1_i32);
+    het_map.insert(Key::new(42_usize), String::from("player"));
+    het_map.insert(Key::new(42_usize), 1.5_f64);
+    het_map.insert(Key::new(7_usize), 2_i32);
+    het_map.insert_type::<u8>();
+
+    assert_eq!(het_map.types_for_key(&0_usize).count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_types_for_key_empty_map() {
+    let het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert_eq!(het_map.types_for_key(&0_usize).count(), 0);
+    assert_eq!(het_map.values_for_key(&0_usize).count(), 0);
+    assert_eq!(het_map.count_types_for_key(&0_usize), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_values_for_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(42_usize), 1_i32);
+    het_map.insert(Key::new(42_usize), String::from("player"));
+    het_map.insert(Key::new(42_usize), 1.5_f64);
+    het_map.insert(Key::new(7_usize), 2_i32);
+    het_map.insert_type::<u8>();
+    let mut count = 0;
+    for (metadata, value) in het_map.values_for_key(&42_usize) {
+        assert_eq!(value.type_id(), metadata.type_id());
+        if let Some(value) = value.downcast_ref::<i32>() {
+            assert_eq!(metadata, TypeMetadata::of::<i32>());
+            assert_eq!(*value, 1_i32);
+        } else if let Some(value) = value.downcast_ref::<String>() {
+            assert_eq!(metadata, TypeMetadata::of::<String>());
+            assert_eq!(value.as_str(), "player");
+        } else if let Some(value) = value.downcast_ref::<f64>() {
+            assert_eq!(metadata, TypeMetadata::of::<f64>());
+            assert_eq!(*value, 1.5_f64);
+        } else {
+            panic!("unexpected type {}", metadata.type_name());
+        }
+        count += 1;
+    }
+
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_count_types_for_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(42_usize), 1_i32);
+    het_map.insert(Key::new(42_usize), String::from("player"));
+    het_map.insert(Key::new(42_usize), 1.5_f64);
+    het_map.insert(Key::new(7_usize), 2_i32);
+    het_map.insert_type::<u8>();
+
+    assert_eq!(het_map.count_types_for_key(&42_usize), 3);
+    assert_eq!(het_map.count_types_for_key(&7_usize), 1);
+
+    het_map.remove::<f64, _>(&Key::new(42_usize));
+
+    assert_eq!(het_map.count_types_for_key(&42_usize), 2);
+
+    het_map.remove_type::<i32>();
+
+    assert_eq!(het_map.count_types_for_key(&42_usize), 1);
+    assert_eq!(het_map.count_types_for_key(&7_usize), 0);
+}
@@ -0,0 +1,147 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::{
+    Health,
+    Position,
+    Velocity,
+};
+
+use alloc_crate::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_one() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+
+    assert_eq!(het_map.get_many_types::<(Position,)>(&1_usize), (Some(&Position(0, 0)),));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+
+    assert_eq!(
+        het_map.get_many_types::<(Position, Velocity, Health)>(&1_usize),
+        (Some(&Position(0, 0)), Some(&Velocity(1, 2)), Some(&Health(100)))
+    );
+    assert_eq!(
+        het_map.get_many_types::<(Position, Velocity, Health)>(&2_usize),
+        (Some(&Position(5, 5)), None, Some(&Health(50)))
+    );
+    assert_eq!(
+        het_map.get_many_types::<(Position, Velocity, Health)>(&3_usize),
+        (None, None, None)
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_missing_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+
+    assert_eq!(
+        het_map.get_many_types::<(Position, String)>(&1_usize),
+        (Some(&Position(0, 0)), None)
+    );
+    assert!(!het_map.contains_type::<String>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_repeated_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+
+    assert_eq!(
+        het_map.get_many_types::<(Health, Health)>(&2_usize),
+        (Some(&Health(50)), Some(&Health(50)))
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_twelve() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(0_usize), 1_u8);
+    het_map.insert(Key::new(0_usize), 2_u16);
+    het_map.insert(Key::new(0_usize), 3_u32);
+    het_map.insert(Key::new(0_usize), 4_u64);
+    het_map.insert(Key::new(0_usize), 5_u128);
+    het_map.insert(Key::new(0_usize), 6_usize);
+    het_map.insert(Key::new(0_usize), 7_i8);
+    het_map.insert(Key::new(0_usize), 8_i16);
+    het_map.insert(Key::new(0_usize), 9_i32);
+    het_map.insert(Key::new(0_usize), 10_i64);
+    het_map.insert(Key::new(0_usize), 11_i128);
+    het_map.insert(Key::new(0_usize), 12_isize);
+    let result = het_map.get_many_types::<(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)>(&0_usize);
+
+    assert_eq!(result.0, Some(&1_u8));
+    assert_eq!(result.5, Some(&6_usize));
+    assert_eq!(result.11, Some(&12_isize));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_mut() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+    let (position, velocity, health) = het_map.get_many_types_mut::<(Position, Velocity, Health)>(&1_usize);
+    let position = position.unwrap();
+    let velocity = velocity.unwrap();
+    position.0 += velocity.0;
+    position.1 += velocity.1;
+    health.unwrap().0 -= 10;
+
+    assert_eq!(het_map.get::<Position, _>(&Key::new(1_usize)), Some(&Position(1, 2)));
+    assert_eq!(het_map.get::<Health, _>(&Key::new(1_usize)), Some(&Health(90)));
+    assert_eq!(het_map.get::<Position, _>(&Key::new(2_usize)), Some(&Position(5, 5)));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_many_types_mut_missing() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+    let (velocity, name) = het_map.get_many_types_mut::<(Velocity, String)>(&2_usize);
+
+    assert_eq!(velocity, None);
+    assert_eq!(name, None);
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_get_many_types_mut_repeated_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Position(0, 0));
+    het_map.insert(Key::new(1_usize), Velocity(1, 2));
+    het_map.insert(Key::new(1_usize), Health(100));
+    het_map.insert(Key::new(2_usize), Position(5, 5));
+    het_map.insert(Key::new(2_usize), Health(50));
+
+    let _ = het_map.get_many_types_mut::<(Position, Health, Position)>(&1_usize);
+}
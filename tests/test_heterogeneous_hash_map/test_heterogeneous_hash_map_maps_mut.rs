@@ -0,0 +1,86 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::{
+    Position,
+    Velocity,
+    simulation_het_map,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_get_maps_mut() {
+    let mut het_map = simulation_het_map();
+    let (positions, velocities) = het_map.get_maps_mut::<(Position, Velocity)>().unwrap();
+    for (key, velocity) in velocities.iter() {
+        let position = positions.get_mut(key.id()).unwrap();
+        position.0 += velocity.0;
+        position.1 += velocity.1;
+    }
+    let positions: Vec<Position> = het_map.values::<Position>().unwrap().cloned().collect();
+
+    assert_eq!(positions, [Position(1, 1), Position(1, 0), Position(3, 1), Position(3, 0)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_maps_mut_single() {
+    let mut het_map = simulation_het_map();
+    let (velocities,) = het_map.get_maps_mut::<(Velocity,)>().unwrap();
+    velocities.clear();
+
+    assert_eq!(het_map.len::<Velocity>(), Some(0));
+    assert_eq!(het_map.len::<Position>(), Some(4));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_maps_mut_missing_type() {
+    let mut het_map = simulation_het_map();
+
+    assert!(het_map.get_maps_mut::<(Position, String)>().is_none());
+    assert!(het_map.get_maps_mut::<(String, Position)>().is_none());
+    assert!(!het_map.contains_type::<String>());
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_get_maps_mut_repeated_type() {
+    let mut het_map = simulation_het_map();
+
+    let _ = het_map.get_maps_mut::<(Position, Velocity, Position)>();
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_or_insert_maps_mut() {
+    let mut het_map = simulation_het_map();
+    let (positions, labels) = het_map.get_or_insert_maps_mut::<(Position, String)>();
+    for (key, position) in positions.iter() {
+        labels.insert(Key::new(*key.id()), alloc_crate::format!("{},{}", position.0, position.1));
+    }
+
+    assert!(het_map.contains_type::<String>());
+    assert_eq!(het_map.len::<String>(), Some(4));
+    assert_eq!(het_map.get::<String, _>(&Key::new(3_usize)), Some(&String::from("3,0")));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_or_insert_maps_mut_empty_map() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    let (ints, floats, strings) = het_map.get_or_insert_maps_mut::<(i32, f64, String)>();
+
+    assert!(ints.is_empty());
+    assert!(floats.is_empty());
+    assert!(strings.is_empty());
+    assert_eq!(het_map.len_types(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_heterogeneous_hash_map_get_or_insert_maps_mut_repeated_type() {
+    let mut het_map = simulation_het_map();
+
+    let _ = het_map.get_or_insert_maps_mut::<(String, String)>();
+}
@@ -0,0 +1,95 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use super::{
+    het_map_from_range,
+    keys,
+};
+
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_drain_range_oldest_entries_per_tick() {
+    let mut het_map = het_map_from_range(7);
+    let mut batches = Vec::new();
+    while het_map.len::<i32>() != Some(0) {
+        let len = het_map.len::<i32>().unwrap();
+        let batch: Vec<i32> = het_map
+            .drain_range::<i32, _>(..len.min(3))
+            .unwrap()
+            .map(|(_, value)| value)
+            .collect();
+        batches.push(batch);
+    }
+
+    assert_eq!(batches, [std::vec![0, 1, 2], std::vec![3, 4, 5], std::vec![6]]);
+    assert!(het_map.contains_type::<i32>());
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_drain_range_missing_type() {
+    let mut het_map = het_map_from_range(3);
+
+    assert!(het_map.drain_range::<u8, _>(..).is_none());
+    assert!(!het_map.contains_type::<u8>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_truncate() {
+    let mut het_map = het_map_from_range(5);
+    het_map.truncate::<i32>(2);
+    het_map.truncate::<u8>(0);
+
+    assert_eq!(keys::<i32>(&het_map), [0, 1]);
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2, 3, 4]);
+    assert!(!het_map.contains_type::<u8>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_split_off() {
+    let mut het_map = het_map_from_range(5);
+    let newest = het_map.split_off::<u64>(3).unwrap();
+
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2]);
+    assert_eq!(newest.keys().map(|key| *key.id()).collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(keys::<i32>(&het_map), [0, 1, 2, 3, 4]);
+    assert!(het_map.split_off::<u8>(0).is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_append_existing_type() {
+    let mut het_map = het_map_from_range(3);
+    let mut other = HomogeneousHashMap::from([(Key::new(1_usize), 10_i32), (Key::new(7_usize), 70_i32)]);
+    het_map.append::<i32, _>(&mut other);
+
+    assert!(other.is_empty());
+    assert_eq!(keys::<i32>(&het_map), [0, 1, 2, 7]);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&10_i32));
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_append_missing_type() {
+    let mut het_map = het_map_from_range(3);
+    let mut other = HomogeneousHashMap::from([(Key::new(1_usize), 1_u8), (Key::new(2_usize), 2_u8)]);
+    het_map.append::<u8, _>(&mut other);
+
+    assert!(other.is_empty());
+    assert!(het_map.contains_type::<u8>());
+    assert_eq!(keys::<u8>(&het_map), [1, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_range() {
+    let het_map = het_map_from_range(5);
+    let slice = het_map.get_range::<i32, _>(1..4).unwrap();
+
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.first(), Some((&Key::new(1_usize), &1_i32)));
+    assert_eq!(slice.last(), Some((&Key::new(3_usize), &3_i32)));
+    assert!(het_map.get_range::<i32, _>(4..6).is_none());
+    assert!(het_map.get_range::<u8, _>(..).is_none());
+}
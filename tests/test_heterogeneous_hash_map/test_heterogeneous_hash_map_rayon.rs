@@ -0,0 +1,199 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    SendHeterogeneousHashMap,
+    SyncHeterogeneousHashMap,
+    TypeMetadata,
+    TypeStorage,
+    TypeStorageMut,
+};
+
+use super::{
+    Position,
+    Velocity,
+    particle_het_map,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use core::any::TypeId;
+use rayon::prelude::*;
+use std::sync::Mutex;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+const LEN: usize = 10_000;
+
+#[test]
+fn test_heterogeneous_hash_map_par_iter() {
+    let het_map = particle_het_map(LEN);
+    let sum: u64 = het_map.par_iter::<u32>().unwrap().map(|(_key, value)| *value as u64).sum();
+
+    assert_eq!(sum, (LEN * (LEN - 1) / 2) as u64);
+    assert!(het_map.par_iter::<i8>().is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_iter_matches_iter() {
+    let het_map = particle_het_map(LEN);
+    let result: Vec<(usize, Position)> = het_map
+        .par_iter::<Position>()
+        .unwrap()
+        .map(|(key, value)| (*key.id(), value.clone()))
+        .collect();
+    let expected: Vec<(usize, Position)> = het_map
+        .iter::<Position>()
+        .unwrap()
+        .map(|(key, value)| (*key.id(), value.clone()))
+        .collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_iter_empty_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_type::<u32>();
+
+    assert_eq!(het_map.par_iter::<u32>().map(|iter| iter.count()), Some(0));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_iter_mut() {
+    let mut het_map = particle_het_map(LEN);
+    het_map
+        .par_iter_mut::<Position>()
+        .unwrap()
+        .for_each(|(key, position)| position.1 = *key.id() as i32);
+
+    for i in 0..LEN {
+        assert_eq!(het_map.get::<Position, _>(&i), Some(&Position(i as i32, i as i32)));
+        assert_eq!(het_map.get::<Velocity, _>(&i), Some(&Velocity(1, -1)));
+    }
+    assert!(het_map.par_iter_mut::<i8>().is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_values_mut() {
+    let mut het_map = particle_het_map(LEN);
+    het_map.par_values_mut::<u32>().unwrap().for_each(|value| *value += 1);
+
+    for i in 0..LEN {
+        assert_eq!(het_map.get::<u32, _>(&i), Some(&(i as u32 + 1)));
+    }
+    assert!(het_map.par_values_mut::<i8>().is_none());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_values_mut_zip_types() {
+    let mut het_map = particle_het_map(LEN);
+    let velocities: Vec<Velocity> = het_map.values::<Velocity>().unwrap().cloned().collect();
+    het_map
+        .par_values_mut::<Position>()
+        .unwrap()
+        .zip(velocities.par_iter())
+        .for_each(|(position, velocity)| {
+            position.0 += velocity.0;
+            position.1 += velocity.1;
+        });
+
+    assert_eq!(het_map.get::<Position, _>(&0_usize), Some(&Position(1, -1)));
+    assert_eq!(het_map.get::<Position, _>(&9_usize), Some(&Position(10, -1)));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_par_retain() {
+    let mut het_map = particle_het_map(LEN);
+
+    assert_eq!(het_map.par_retain::<u32, _>(|_key, value| *value % 2 == 0), Some(LEN / 2));
+    assert_eq!(het_map.par_retain::<i8, _>(|_key, _value| true), None);
+    assert_eq!(het_map.len::<u32>(), Some(LEN / 2));
+    assert_eq!(het_map.len::<Position>(), Some(LEN));
+    assert_eq!(het_map.get::<u32, _>(&2_usize), Some(&2_u32));
+    assert_eq!(het_map.get::<u32, _>(&3_usize), None);
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_par_for_each_type_mut() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map
+        .get_or_insert_map_mut::<u32>()
+        .extend((0..LEN).map(|i| (Key::new(i), 1_u32)));
+    het_map
+        .get_or_insert_map_mut::<f64>()
+        .extend((0..LEN).map(|i| (Key::new(i), 1_f64)));
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+    let visited = Mutex::new(Vec::new());
+
+    het_map.par_for_each_type_mut(|mut storage: TypeStorageMut<'_, usize, _, _>| {
+        visited.lock().unwrap().push(storage.metadata().type_id());
+        if let Some(map) = storage.downcast_mut::<u32>() {
+            map.values_mut().for_each(|value| *value = 2);
+        } else if let Some(map) = storage.downcast_mut::<f64>() {
+            map.values_mut().for_each(|value| *value = 0.5);
+        } else {
+            storage.clear();
+        }
+    });
+    let mut visited = visited.into_inner().unwrap();
+    visited.sort();
+    let mut expected = Vec::from([TypeId::of::<u32>(), TypeId::of::<f64>(), TypeId::of::<String>()]);
+    expected.sort();
+
+    assert_eq!(visited, expected);
+    assert!(het_map.values::<u32>().unwrap().all(|value| *value == 2));
+    assert!(het_map.values::<f64>().unwrap().all(|value| *value == 0.5));
+    assert_eq!(het_map.len::<String>(), Some(0));
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_par_for_each_type() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map
+        .get_or_insert_map_mut::<u32>()
+        .extend((0..LEN).map(|i| (Key::new(i), 1_u32)));
+    het_map
+        .get_or_insert_map_mut::<f64>()
+        .extend((0..(LEN / 2)).map(|i| (Key::new(i), 1_f64)));
+    het_map.insert_type::<u8>();
+    let total = AtomicUsize::new(0);
+    let types = AtomicUsize::new(0);
+
+    het_map.par_for_each_type(|storage: TypeStorage<'_, usize, _, _>| {
+        total.fetch_add(storage.len(), Ordering::Relaxed);
+        types.fetch_add(1, Ordering::Relaxed);
+        if storage.metadata() == TypeMetadata::of::<u32>() {
+            assert_eq!(
+                storage.get(&3_usize).and_then(|value| value.downcast_ref::<u32>()),
+                Some(&1_u32)
+            );
+        }
+    });
+
+    assert_eq!(total.into_inner(), LEN + LEN / 2);
+    assert_eq!(types.into_inner(), 3);
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_par_for_each_type_mut() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map
+        .get_or_insert_map_mut::<u32>()
+        .extend((0..LEN).map(|i| (Key::new(i), i as u32)));
+    het_map
+        .get_or_insert_map_mut::<u64>()
+        .extend((0..LEN).map(|i| (Key::new(i), i as u64)));
+
+    het_map.par_for_each_type_mut(|mut storage: TypeStorageMut<'_, usize, _, _>| {
+        if let Some(map) = storage.downcast_mut::<u32>() {
+            map.par_values_mut().for_each(|value| *value *= 2);
+        } else if let Some(map) = storage.downcast_mut::<u64>() {
+            map.par_retain(|_key, value| *value < 10);
+        }
+    });
+
+    assert_eq!(het_map.get::<u32, _>(&100_usize), Some(&200_u32));
+    assert_eq!(het_map.len::<u64>(), Some(10));
+}
@@ -0,0 +1,91 @@
+use heterogeneous_hash_map::Key;
+
+use super::{
+    het_map_from_range,
+    keys,
+};
+
+use alloc_crate::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_shift_remove_preserves_order() {
+    let mut het_map = het_map_from_range(6);
+
+    assert_eq!(het_map.shift_remove::<i32, _>(&Key::new(1_usize)), Some(1_i32));
+    assert_eq!(het_map.shift_remove::<i32, _>(&Key::new(4_usize)), Some(4_i32));
+    assert_eq!(het_map.shift_remove::<i32, _>(&Key::new(4_usize)), None);
+
+    assert_eq!(keys::<i32>(&het_map), [0, 2, 3, 5]);
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_shift_remove_entry() {
+    let mut het_map = het_map_from_range(4);
+
+    assert_eq!(
+        het_map.shift_remove_entry::<u64, _>(&Key::new(0_usize)),
+        Some((Key::new(0_usize), 0_u64))
+    );
+
+    assert_eq!(keys::<u64>(&het_map), [1, 2, 3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_shift_remove_full() {
+    let mut het_map = het_map_from_range(4);
+
+    assert_eq!(
+        het_map.shift_remove_full::<i32, _>(&Key::new(2_usize)),
+        Some((2, Key::new(2_usize), 2_i32))
+    );
+
+    assert_eq!(keys::<i32>(&het_map), [0, 1, 3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_swap_remove() {
+    let mut het_map = het_map_from_range(6);
+
+    assert_eq!(het_map.swap_remove::<i32, _>(&Key::new(1_usize)), Some(1_i32));
+
+    assert_eq!(keys::<i32>(&het_map), [0, 5, 2, 3, 4]);
+    assert_eq!(keys::<u64>(&het_map), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_swap_remove_entry() {
+    let mut het_map = het_map_from_range(4);
+
+    assert_eq!(
+        het_map.swap_remove_entry::<u64, _>(&Key::new(0_usize)),
+        Some((Key::new(0_usize), 0_u64))
+    );
+
+    assert_eq!(keys::<u64>(&het_map), [3, 1, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_swap_remove_full() {
+    let mut het_map = het_map_from_range(4);
+
+    assert_eq!(
+        het_map.swap_remove_full::<i32, _>(&Key::new(1_usize)),
+        Some((1, Key::new(1_usize), 1_i32))
+    );
+
+    assert_eq!(keys::<i32>(&het_map), [0, 3, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_variants_missing_type() {
+    let mut het_map = het_map_from_range(4);
+
+    assert_eq!(het_map.swap_remove::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.swap_remove_entry::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.swap_remove_full::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.shift_remove::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.shift_remove_entry::<String, _>(&Key::new(0_usize)), None);
+    assert_eq!(het_map.shift_remove_full::<String, _>(&Key::new(0_usize)), None);
+    assert!(!het_map.contains_type::<String>());
+}
@@ -0,0 +1,133 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::{
+    keys,
+    sorted,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_remove_key_everywhere() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+
+    assert_eq!(het_map.remove_key_everywhere(&2_usize), 3);
+    assert_eq!(sorted(keys::<i32>(&het_map)), [0, 1, 3, 4]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [0, 4]);
+    assert!(sorted(keys::<String>(&het_map)).is_empty());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_key_everywhere_partial() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+
+    assert_eq!(het_map.remove_key_everywhere(&1_usize), 1);
+    assert_eq!(sorted(keys::<i32>(&het_map)), [0, 2, 3, 4]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [0, 2, 4]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_key_everywhere_missing_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+
+    assert_eq!(het_map.remove_key_everywhere(&10_usize), 0);
+    assert_eq!(sorted(keys::<i32>(&het_map)), [0, 1, 2, 3, 4]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [0, 2, 4]);
+    assert_eq!(sorted(keys::<String>(&het_map)), [2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_key_everywhere_keeps_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+    het_map.insert_type::<u8>();
+    let len_types = het_map.len_types();
+    het_map.remove_key_everywhere(&2_usize);
+
+    assert_eq!(het_map.len_types(), len_types);
+    assert!(het_map.contains_type::<String>());
+    assert!(het_map.contains_type::<u8>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_keys_everywhere() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+
+    assert_eq!(het_map.remove_keys_everywhere([0_usize, 2, 10]), 5);
+    assert_eq!(sorted(keys::<i32>(&het_map)), [1, 3, 4]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [4]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_keys_everywhere_borrowed() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+    let doomed = Vec::from([3_usize, 4]);
+
+    assert_eq!(het_map.remove_keys_everywhere(&doomed), 3);
+    assert_eq!(sorted(keys::<i32>(&het_map)), [0, 1, 2]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [0, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_retain_keys() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+    het_map.retain_keys(|key| *key >= 2);
+
+    assert_eq!(sorted(keys::<i32>(&het_map)), [2, 3, 4]);
+    assert_eq!(sorted(keys::<u64>(&het_map)), [2, 4]);
+    assert_eq!(sorted(keys::<String>(&het_map)), [2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_retain_keys_preserves_order() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+    het_map.retain_keys(|key| key % 2 == 1 || *key == 4);
+    let position_keys: Vec<usize> = het_map.keys::<i32>().unwrap().map(|key| *key.id()).collect();
+
+    assert_eq!(position_keys, [1, 3, 4]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_retain_keys_calls_per_entry() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).filter(|i| i % 2 == 0).map(|i| (Key::new(i), 100_u64)));
+    het_map.extend([(Key::new(2_usize), String::from("boss"))]);
+    let mut calls = 0;
+    het_map.retain_keys(|_key| {
+        calls += 1;
+        true
+    });
+
+    assert_eq!(calls, 5 + 3 + 1);
+    assert_eq!(het_map.len::<i32>(), Some(5));
+    assert_eq!(het_map.len::<u64>(), Some(3));
+    assert_eq!(het_map.len::<String>(), Some(1));
+}
@@ -0,0 +1,294 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    SendHeterogeneousHashMap,
+    SyncHeterogeneousHashMap,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use core::cell::Cell;
+use std::sync::mpsc;
+use std::thread;
+
+fn assert_send<T>()
+where
+    T: Send,
+{
+}
+
+fn assert_send_sync<T>()
+where
+    T: Send + Sync,
+{
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_is_send() {
+    assert_send::<SendHeterogeneousHashMap<usize>>();
+    assert_send::<SendHeterogeneousHashMap<String>>();
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_is_send_sync() {
+    assert_send_sync::<SyncHeterogeneousHashMap<usize>>();
+    assert_send_sync::<SyncHeterogeneousHashMap<String>>();
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_insert_and_read() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+
+    assert!(het_map.insert_type::<i32>());
+    assert!(!het_map.insert_type::<i32>());
+    assert_eq!(het_map.insert(Key::new(1_usize), 2_i32), None);
+    assert_eq!(het_map.insert(Key::new(1_usize), 3_i32), Some(2_i32));
+    assert_eq!(het_map.insert(Key::new(1_usize), Cell::new(4_u8)), None);
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&3_i32));
+    assert_eq!(het_map.get::<Cell<u8>, _>(&1_usize).map(Cell::get), Some(4_u8));
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_mutation() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert_type_with_capacity::<i32>(4);
+    het_map
+        .get_map_mut::<i32>()
+        .unwrap()
+        .extend((0..4).map(|i| (Key::new(i), i as i32)));
+    het_map.get_or_insert_map_mut::<u64>().insert(Key::new(0_usize), 5_u64);
+    *het_map.get_mut::<i32, _>(&2_usize).unwrap() = 20_i32;
+
+    assert_eq!(het_map.remove::<i32, _>(&3_usize), Some(3_i32));
+    assert_eq!(het_map.get::<i32, _>(&2_usize), Some(&20_i32));
+    assert_eq!(het_map.len::<i32>(), Some(3));
+    assert_eq!(het_map.get::<u64, _>(&0_usize), Some(&5_u64));
+    assert_eq!(het_map.remove_type::<u64>(), Some(1));
+    assert_eq!(het_map.take_type::<i32>().map(|map| map.len()), Some(3));
+    assert!(het_map.is_empty_types());
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_clear() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+    het_map.clear();
+
+    assert!(het_map.is_empty_types());
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_move_to_thread() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Cell::new(2_i32));
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+
+    let het_map = thread::spawn(move || {
+        het_map.get::<Cell<i32>, _>(&1_usize).unwrap().set(3_i32);
+        het_map.insert(Key::new(2_usize), String::from("bar"));
+        het_map
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(het_map.get::<Cell<i32>, _>(&1_usize).map(Cell::get), Some(3_i32));
+    assert_eq!(het_map.len::<String>(), Some(2));
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_send_through_channel() {
+    let (sender, receiver) = mpsc::channel::<SendHeterogeneousHashMap<usize>>();
+    let consumer = thread::spawn(move || {
+        receiver
+            .iter()
+            .map(|het_map| het_map.len::<i32>().unwrap_or(0))
+            .sum::<usize>()
+    });
+
+    for count in 1..=4 {
+        let mut het_map = SendHeterogeneousHashMap::new();
+        for i in 0..count {
+            het_map.insert(Key::new(i), i as i32);
+        }
+        sender.send(het_map).unwrap();
+    }
+    drop(sender);
+
+    assert_eq!(consumer.join().unwrap(), 1 + 2 + 3 + 4);
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_share_between_threads() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map
+        .get_or_insert_map_mut::<i64>()
+        .extend((0..100).map(|i| (Key::new(i), i as i64)));
+    het_map.insert(Key::new(0_usize), String::from("foo"));
+
+    let sums: Vec<i64> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| het_map.values::<i64>().map(|values| values.sum()).unwrap_or(0)))
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    assert_eq!(sums, [4950, 4950, 4950, 4950]);
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_move_to_thread() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+
+    let het_map = thread::spawn(move || {
+        het_map.insert(Key::new(1_usize), String::from("foo"));
+        het_map
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(het_map.len_types(), 2);
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_into_send_heterogeneous_hash_map() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let mut het_map = SendHeterogeneousHashMap::from(het_map);
+    het_map.insert(Key::new(1_usize), Cell::new(3_i32));
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_into_inner() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let het_map: HeterogeneousHashMap<usize> = het_map.into_inner();
+
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&2_i32));
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_debug() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 2_i32);
+    let expected = alloc_crate::format!("SendHeterogeneousHashMap({:?})", *het_map);
+
+    assert_eq!(alloc_crate::format!("{:?}", het_map), expected);
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_default() {
+    let het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::default();
+
+    assert!(het_map.is_empty_types());
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_default() {
+    let het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::default();
+
+    assert!(het_map.is_empty_types());
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_forwarded_mutation() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.extend((0..6).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..3).map(|i| (Key::new(i), Cell::new(i as u8))));
+    *het_map.entry_or_insert_type(Key::new(0_usize)).or_insert(String::from("foo")) += "bar";
+    *het_map.entry::<i32>(Key::new(6_usize)).unwrap().or_insert(60_i32) += 1_i32;
+
+    for value in het_map.values_mut::<i32>().unwrap() {
+        *value *= 2_i32;
+    }
+    for (key, cell) in het_map.iter_mut::<Cell<u8>>().unwrap() {
+        cell.set(cell.get() + *key.id() as u8);
+    }
+    het_map.retain_keys(|key| *key != 1);
+
+    assert_eq!(het_map.len::<i32>(), Some(6));
+    assert_eq!(het_map.get::<i32, _>(&6_usize), Some(&122_i32));
+    assert_eq!(het_map.get::<Cell<u8>, _>(&2_usize).map(Cell::get), Some(4_u8));
+    assert_eq!(het_map.get::<String, _>(&0_usize), Some(&String::from("foobar")));
+    assert_eq!(het_map.swap_remove::<i32, _>(&0_usize), Some(0_i32));
+    assert_eq!(het_map.remove_key_everywhere(&2_usize), 2);
+    assert_eq!(het_map.len::<Cell<u8>>(), Some(1));
+
+    het_map.clear_values();
+
+    assert_eq!(het_map.len_types(), 3);
+    assert_eq!(het_map.len::<i32>(), Some(0));
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_forwarded_mutation() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map.extend((0..6).rev().map(|i| (Key::new(i), i as i64)));
+    het_map.sort_keys::<i64>();
+    assert_eq!(het_map.first::<i64>(), Some((&Key::new(0_usize), &0_i64)));
+
+    let odd: Vec<i64> = het_map
+        .extract_if::<i64, _>(|_, value| *value % 2 == 1)
+        .unwrap()
+        .map(|(_, value)| value)
+        .collect();
+    assert_eq!(odd, [1, 3, 5]);
+
+    let (keys, values): (Vec<usize>, Vec<i64>) = het_map.drain::<i64>().unwrap().map(|(key, value)| (*key.id(), value)).unzip();
+    assert_eq!(keys, [0, 2, 4]);
+    assert_eq!(values, [0, 2, 4]);
+    assert!(het_map.try_insert(Key::new(1_usize), 1_i64).is_ok());
+    assert_eq!(het_map.get::<i64, _>(&1_usize), Some(&1_i64));
+
+    let sums: Vec<i64> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| scope.spawn(|| het_map.values::<i64>().map(|values| values.sum()).unwrap_or(0)))
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    assert_eq!(sums, [1, 1]);
+}
+
+#[test]
+fn test_send_heterogeneous_hash_map_signature_index() {
+    let mut het_map: SendHeterogeneousHashMap<usize> = SendHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(2_usize), Cell::new(2_u8));
+
+    assert_eq!(het_map.signature_index().len(), 2);
+
+    het_map.remove::<Cell<u8>, _>(&2_usize);
+
+    assert_eq!(
+        het_map
+            .signature_index()
+            .keys_with_any::<(i32, Cell<u8>)>()
+            .collect::<Vec<_>>(),
+        [&1_usize]
+    );
+}
+
+#[test]
+fn test_sync_heterogeneous_hash_map_signature_index() {
+    let mut het_map: SyncHeterogeneousHashMap<usize> = SyncHeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+
+    assert_eq!(het_map.signature_index().len(), 2);
+
+    het_map.insert(Key::new(2_usize), 2_i32);
+
+    assert_eq!(
+        het_map.signature_index().keys_with_all::<(i32, f64)>().collect::<Vec<_>>(),
+        [&2_usize]
+    );
+}
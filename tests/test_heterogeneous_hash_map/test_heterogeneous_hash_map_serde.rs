@@ -0,0 +1,173 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TypeMetadata,
+    TypeRegistry,
+};
+
+use serde::de::DeserializeSeed;
+use std::string::{
+    String,
+    ToString,
+};
+use std::vec::Vec;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Position {
+    x: f64,
+    y: f64,
+}
+
+fn registry() -> TypeRegistry<String> {
+    let mut registry = TypeRegistry::new();
+    registry.register::<i32>("i32");
+    registry.register::<String>("string");
+    registry.register::<Position>("position");
+
+    registry
+}
+
+fn deserialize(registry: &TypeRegistry<String>, input: &str) -> Result<HeterogeneousHashMap<String>, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(input);
+
+    registry.seed().deserialize(&mut deserializer)
+}
+
+#[test]
+fn test_type_registry_register() {
+    let mut registry: TypeRegistry<String> = TypeRegistry::new();
+
+    assert!(registry.is_empty());
+    assert!(registry.register::<i32>("i32"));
+    assert!(registry.register::<String>("string"));
+    assert_eq!(registry.len(), 2);
+    assert!(registry.contains_type::<i32>());
+    assert!(registry.contains_type::<String>());
+    assert!(!registry.contains_type::<u32>());
+    assert_eq!(registry.get_tag::<i32>(), Some("i32"));
+    assert_eq!(registry.get_tag::<String>(), Some("string"));
+    assert_eq!(registry.get_metadata_by_tag("i32"), Some(TypeMetadata::of::<i32>()));
+    assert_eq!(registry.get_metadata_by_tag("string"), Some(TypeMetadata::of::<String>()));
+}
+
+#[test]
+fn test_type_registry_register_duplicate() {
+    let mut registry: TypeRegistry<String> = TypeRegistry::new();
+
+    assert!(registry.register::<i32>("i32"));
+    assert!(!registry.register::<i32>("other"));
+    assert!(!registry.register::<u32>("i32"));
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.get_tag::<i32>(), Some("i32"));
+    assert_eq!(registry.get_tag::<u32>(), None);
+    assert_eq!(registry.get_metadata_by_tag("other"), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_serialize_empty() {
+    let registry = registry();
+    let het_map: HeterogeneousHashMap<String> = HeterogeneousHashMap::new();
+    let result = serde_json::to_string(&registry.serializable(&het_map)).unwrap();
+
+    assert_eq!(result, "{}");
+}
+
+#[test]
+fn test_heterogeneous_hash_map_serialize() {
+    let registry = registry();
+    let mut het_map: HeterogeneousHashMap<String> = HeterogeneousHashMap::new();
+    het_map.insert_type::<String>();
+    het_map.insert::<i32>(Key::new(String::from("b")), 2_i32);
+    het_map.insert::<i32>(Key::new(String::from("a")), 1_i32);
+    het_map.insert::<Position>(Key::new(String::from("a")), Position { x: 1_f64, y: 2_f64 });
+
+    let result = serde_json::to_string(&registry.serializable(&het_map)).unwrap();
+
+    assert_eq!(
+        result,
+        r#"{"i32":{"b":2,"a":1},"position":{"a":{"x":1.0,"y":2.0}},"string":{}}"#
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_serialize_unregistered_type() {
+    let registry = registry();
+    let mut het_map: HeterogeneousHashMap<String> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(String::from("a")), 1_i32);
+    het_map.insert::<u64>(Key::new(String::from("a")), 1_u64);
+
+    let result = serde_json::to_string(&registry.serializable(&het_map));
+
+    assert!(result.is_err());
+
+    let message = result.unwrap_err().to_string();
+
+    assert!(message.contains("u64"));
+    assert!(message.contains("not registered"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_deserialize() {
+    let registry = registry();
+    let het_map = deserialize(
+        &registry,
+        r#"{"i32":{"b":2,"a":1},"position":{"a":{"x":1.0,"y":2.0}},"string":{}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(het_map.len_types(), 3);
+    assert_eq!(het_map.len::<i32>(), Some(2));
+    assert_eq!(het_map.len::<Position>(), Some(1));
+    assert_eq!(het_map.len::<String>(), Some(0));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(String::from("a"))), Some(&1_i32));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(String::from("b"))), Some(&2_i32));
+    assert_eq!(
+        het_map.get::<Position, _>(&Key::new(String::from("a"))),
+        Some(&Position { x: 1_f64, y: 2_f64 })
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_deserialize_unknown_tag() {
+    let registry = registry();
+    let result = deserialize(&registry, r#"{"i32":{"a":1},"u64":{"a":1}}"#);
+
+    assert!(result.is_err());
+
+    let message = result.err().unwrap().to_string();
+
+    assert!(message.contains("unknown type tag `u64`"));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_deserialize_invalid_entries() {
+    let registry = registry();
+    let result = deserialize(&registry, r#"{"i32":{"a":"not an integer"}}"#);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_round_trip() {
+    let registry = registry();
+    let mut het_map: HeterogeneousHashMap<String> = HeterogeneousHashMap::new();
+    for i in (0..16).rev() {
+        het_map.insert::<i32>(Key::new(i.to_string()), i);
+        het_map.insert::<String>(Key::new(i.to_string()), (i * i).to_string());
+    }
+
+    let serialized = serde_json::to_string(&registry.serializable(&het_map)).unwrap();
+    let result = deserialize(&registry, &serialized).unwrap();
+
+    assert_eq!(result.len_types(), 2);
+
+    let expected_i32: Vec<(&Key<String, i32>, &i32)> = het_map.iter::<i32>().unwrap().collect();
+    let result_i32: Vec<(&Key<String, i32>, &i32)> = result.iter::<i32>().unwrap().collect();
+
+    assert_eq!(result_i32, expected_i32);
+
+    let expected_string: Vec<(&Key<String, String>, &String)> = het_map.iter::<String>().unwrap().collect();
+    let result_string: Vec<(&Key<String, String>, &String)> = result.iter::<String>().unwrap().collect();
+
+    assert_eq!(result_string, expected_string);
+}
@@ -0,0 +1,508 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::sorted;
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+
+macro_rules! insert_array_types {
+    ($het_map:expr, $key:expr; $($len:literal)+) => {
+        $($het_map.insert(Key::new($key), [0_u8; $len]);)+
+    };
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_len() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    let index = het_map.signature_index();
+
+    assert_eq!(index.len(), 4);
+    assert!(!index.is_empty());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_empty() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert!(het_map.signature_index().is_empty());
+    assert_eq!(het_map.signature_index().keys_without::<(i32,)>().count(), 0);
+
+    het_map.insert_type::<i32>();
+    het_map.insert_type::<f64>();
+
+    let index = het_map.signature_index();
+
+    assert!(index.is_empty());
+    assert_eq!(index.keys_with_any::<(i32, f64)>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_keys_with_all() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    let index = het_map.signature_index();
+
+    assert_eq!(sorted(index.keys_with_all::<(i32,)>().copied()), [1, 2, 3]);
+    assert_eq!(sorted(index.keys_with_all::<(i32, f64)>().copied()), [1, 2]);
+    assert_eq!(sorted(index.keys_with_all::<(f64, i32)>().copied()), [1, 2]);
+    assert_eq!(sorted(index.keys_with_all::<(i32, f64, String)>().copied()), [1]);
+    assert_eq!(sorted(index.keys_with_all::<(i32, u8)>().copied()), Vec::<usize>::new());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_keys_with_all_missing_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    let index = het_map.signature_index();
+
+    assert_eq!(sorted(index.keys_with_all::<(i32, u16)>().copied()), Vec::<usize>::new());
+    assert_eq!(sorted(index.keys_with_all::<(u16,)>().copied()), Vec::<usize>::new());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_keys_with_any() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    let index = het_map.signature_index();
+
+    assert_eq!(sorted(index.keys_with_any::<(f64,)>().copied()), [1, 2]);
+    assert_eq!(sorted(index.keys_with_any::<(f64, String)>().copied()), [1, 2, 4]);
+    assert_eq!(sorted(index.keys_with_any::<(u8, u16)>().copied()), Vec::<usize>::new());
+    assert_eq!(sorted(index.keys_with_any::<(u16, String)>().copied()), [1, 4]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_keys_without() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    let index = het_map.signature_index();
+
+    assert_eq!(sorted(index.keys_without::<(f64,)>().copied()), [3, 4]);
+    assert_eq!(sorted(index.keys_without::<(i32, String)>().copied()), Vec::<usize>::new());
+    assert_eq!(sorted(index.keys_without::<(u8, u16)>().copied()), [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_matches_join() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..100).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..100).filter(|i| i % 2 == 0).map(|i| (Key::new(i), i as f64)));
+    het_map.extend((0..100).filter(|i| i % 3 == 0).map(|i| (Key::new(i), i as u64)));
+    let expected = {
+        let mut _expected: Vec<usize> = het_map.join::<(i32, f64, u64)>().map(|(key, ..)| *key).collect();
+        _expected.sort();
+        _expected
+    };
+    let index = het_map.signature_index();
+
+    assert_eq!(sorted(index.keys_with_all::<(i32, f64, u64)>().copied()), expected);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_after_removal() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(1_usize), String::from("one"));
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+    het_map.insert(Key::new(3_usize), 3_i32);
+    het_map.insert(Key::new(4_usize), String::from("four"));
+    het_map.insert_type::<u8>();
+    het_map.remove::<f64, _>(&1_usize);
+    het_map.remove_type::<String>();
+
+    let index = het_map.signature_index();
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(sorted(index.keys_with_all::<(i32, f64)>().copied()), [2]);
+    assert_eq!(sorted(index.keys_with_any::<(String,)>().copied()), Vec::<usize>::new());
+    assert_eq!(sorted(index.keys_without::<(f64,)>().copied()), [1, 3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_many_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    insert_array_types!(het_map, 1_usize;
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34
+        35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66 67 68 69
+    );
+    insert_array_types!(het_map, 2_usize; 0 64 69);
+    insert_array_types!(het_map, 3_usize; 65);
+
+    assert_eq!(het_map.len_types(), 70);
+
+    let index = het_map.signature_index();
+
+    assert_eq!(
+        sorted(index.keys_with_all::<([u8; 0], [u8; 64], [u8; 69])>().copied()),
+        [1, 2]
+    );
+    assert_eq!(sorted(index.keys_with_all::<([u8; 65],)>().copied()), [1, 3]);
+    assert_eq!(sorted(index.keys_with_any::<([u8; 66], [u8; 69])>().copied()), [1, 2]);
+    assert_eq!(sorted(index.keys_without::<([u8; 0],)>().copied()), [3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_insert() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32,)>().copied()), [1]);
+
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32,)>().copied()), [1, 2]);
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()), [1]);
+
+    het_map.insert(Key::new(3_usize), String::from("three"));
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+
+    assert_eq!(het_map.signature_index().len(), 3);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [1, 2]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(i32,)>().copied()), [3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_remove() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..10).filter(|i| i % 2 == 0).map(|i| (Key::new(i), i as f64)));
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [0, 2, 4, 6, 8]
+    );
+
+    het_map.remove::<f64, _>(&4_usize);
+    het_map.swap_remove::<f64, _>(&0_usize);
+    het_map.shift_remove_entry::<i32, _>(&9_usize);
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [2, 6, 8]
+    );
+    assert_eq!(het_map.signature_index().len(), 9);
+
+    het_map.pop::<f64>();
+    het_map.swap_remove_index::<i32>(0);
+    het_map.remove_dyn(core::any::TypeId::of::<i32>(), &1_usize);
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [2, 6]
+    );
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(i32, f64)>().copied()),
+        [2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!(het_map.signature_index().len(), 7);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_remove_key_everywhere() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).map(|i| (Key::new(i), i as f64)));
+
+    assert_eq!(het_map.signature_index().len(), 5);
+
+    het_map.remove_key_everywhere(&2_usize);
+    het_map.remove_keys_everywhere([0_usize, 7_usize]);
+
+    assert_eq!(het_map.signature_index().len(), 3);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [1, 3, 4]
+    );
+
+    het_map.insert(Key::new(2_usize), 2_i32);
+
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(f64,)>().copied()), [2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_retain_keys() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..10).filter(|i| i % 2 == 0).map(|i| (Key::new(i), i as f64)));
+
+    assert_eq!(het_map.signature_index().len(), 10);
+
+    het_map.retain_keys(|key| *key < 5);
+
+    assert_eq!(het_map.signature_index().len(), 5);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [0, 2, 4]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(f64,)>().copied()), [1, 3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_remove_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(1_usize), 1.5_f64);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_any::<(f64,)>().copied()), [1, 2]);
+
+    het_map.remove_type::<f64>();
+
+    assert_eq!(het_map.signature_index().len(), 1);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(f64,)>().copied()),
+        Vec::<usize>::new()
+    );
+
+    // The new type reuses the bit of the removed type.
+    het_map.insert(Key::new(3_usize), 3_u8);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_any::<(u8,)>().copied()), [3]);
+    assert_eq!(sorted(het_map.signature_index().keys_with_any::<(i32,)>().copied()), [1]);
+
+    let taken = het_map.take_type::<i32>().unwrap();
+
+    assert_eq!(taken.len(), 1);
+    assert_eq!(het_map.signature_index().len(), 1);
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(i32,)>().copied()), [3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_clear() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(2_usize), 2.5_f64);
+
+    assert_eq!(het_map.signature_index().len(), 2);
+
+    het_map.clear_values();
+
+    assert!(het_map.signature_index().is_empty());
+
+    het_map.insert(Key::new(3_usize), 3.5_f64);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(f64,)>().copied()), [3]);
+
+    het_map.clear();
+
+    assert!(het_map.signature_index().is_empty());
+
+    het_map.insert(Key::new(4_usize), 4_u8);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(u8,)>().copied()), [4]);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(i32, f64)>().copied()),
+        Vec::<usize>::new()
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_map_mut() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..5).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..5).map(|i| (Key::new(i), i as f64)));
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [0, 1, 2, 3, 4]
+    );
+
+    het_map.get_map_mut::<i32>().unwrap().retain(|key, _| *key.id() % 2 == 0);
+    *het_map.entry(Key::new(7_usize)).unwrap().or_insert(0.0_f64) += 7.0;
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [0, 2, 4]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(i32,)>().copied()), [1, 3, 7]);
+
+    het_map.drain::<f64>();
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(i32, f64)>().copied()),
+        [0, 2, 4]
+    );
+    assert_eq!(het_map.signature_index().len(), 3);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_extend() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(0_usize), 0_i32);
+
+    assert_eq!(het_map.signature_index().len(), 1);
+
+    het_map.extend((1..4).map(|i| (Key::new(i), i as i32)));
+    het_map.try_extend((2..6).map(|i| (Key::new(i), i as f64))).unwrap();
+    het_map.try_insert(Key::new(9_usize), 9_u8).unwrap();
+
+    assert_eq!(het_map.signature_index().len(), 7);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [2, 3]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_with_any::<(u8,)>().copied()), [9]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_many_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    insert_array_types!(het_map, 1_usize; 0 1 2 3);
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<([u8; 0], [u8; 3])>().copied()),
+        [1]
+    );
+
+    insert_array_types!(het_map, 2_usize;
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34
+        35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66 67 68 69
+    );
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<([u8; 0], [u8; 3])>().copied()),
+        [1, 2]
+    );
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<([u8; 0], [u8; 69])>().copied()),
+        [2]
+    );
+
+    het_map.remove::<[u8; 69], _>(&2_usize);
+    het_map.insert(Key::new(1_usize), [0_u8; 69]);
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<([u8; 0], [u8; 69])>().copied()),
+        [1]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_without::<([u8; 65],)>().copied()), [1]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_disable() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), 1_i32);
+
+    assert_eq!(het_map.signature_index().len(), 1);
+
+    het_map.disable_signature_index();
+    het_map.insert(Key::new(2_usize), 2_i32);
+    het_map.remove::<i32, _>(&1_usize);
+
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32,)>().copied()), [2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_clone() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<i32>();
+    het_map.insert(Key::new(1_usize), 1_i32);
+    het_map.insert(Key::new(2_usize), 2_i32);
+
+    assert_eq!(het_map.signature_index().len(), 2);
+
+    let mut cloned_het_map = het_map.clone();
+    cloned_het_map.remove::<i32, _>(&1_usize);
+
+    assert_eq!(
+        sorted(cloned_het_map.signature_index().keys_with_all::<(i32,)>().copied()),
+        [2]
+    );
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32,)>().copied()), [1, 2]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_many_stale_types() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend((0..10).map(|i| (Key::new(i), i as i32)));
+    het_map.extend((0..10).map(|i| (Key::new(i), i as f64)));
+    het_map.extend((0..10).map(|i| (Key::new(i), i as u8)));
+
+    assert_eq!(het_map.signature_index().len(), 10);
+
+    het_map.get_map_mut::<i32>().unwrap().retain(|key, _| *key.id() < 6);
+    het_map.get_map_mut::<f64>().unwrap().retain(|key, _| *key.id() >= 4);
+    het_map.get_map_mut::<u8>().unwrap().clear();
+
+    assert_eq!(het_map.signature_index().len(), 10);
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()),
+        [4, 5]
+    );
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(u8,)>().copied()),
+        Vec::<usize>::new()
+    );
+
+    het_map.get_map_mut::<i32>().unwrap().clear();
+    het_map.get_map_mut::<f64>().unwrap().retain(|key, _| *key.id() % 2 == 0);
+
+    assert_eq!(
+        sorted(het_map.signature_index().keys_with_any::<(i32, f64, u8)>().copied()),
+        [4, 6, 8]
+    );
+    assert_eq!(het_map.signature_index().len(), 3);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_signature_index_interleaved_try_insert() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.try_insert(Key::new(1_usize), 1_i32).unwrap();
+
+    assert_eq!(het_map.signature_index().len(), 1);
+
+    het_map.try_insert(Key::new(2_usize), 2_i32).unwrap();
+    het_map.try_insert(Key::new(1_usize), 1.5_f64).unwrap();
+    het_map.try_insert_type_with_capacity::<u8>(4).unwrap();
+    het_map.try_extend((2..5).map(|i| (Key::new(i), i as u8))).unwrap();
+
+    assert_eq!(het_map.signature_index().len(), 4);
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32, f64)>().copied()), [1]);
+    assert_eq!(sorted(het_map.signature_index().keys_with_all::<(i32, u8)>().copied()), [2]);
+    assert_eq!(sorted(het_map.signature_index().keys_without::<(i32,)>().copied()), [3, 4]);
+}
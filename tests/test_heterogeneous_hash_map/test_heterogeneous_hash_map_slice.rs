@@ -0,0 +1,57 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::het_map_from_range;
+
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_heterogeneous_hash_map_as_slice() {
+    let het_map = het_map_from_range(5);
+    let slice = het_map.as_slice::<i32>().unwrap();
+
+    assert_eq!(slice.len(), 5);
+    assert_eq!(slice[3], 3_i32);
+    assert_eq!(slice[1..3].keys().map(|key| *key.id()).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(
+        het_map.as_slice::<u64>().unwrap().values().copied().collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn test_heterogeneous_hash_map_as_slice_missing_type() {
+    let mut het_map = het_map_from_range(5);
+
+    assert!(het_map.as_slice::<u8>().is_none());
+    assert!(het_map.as_mut_slice::<u8>().is_none());
+    assert!(!het_map.contains_type::<u8>());
+}
+
+#[test]
+fn test_heterogeneous_hash_map_as_slice_binary_search_keys() {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend([(Key::new(9_usize), 9_i32), (Key::new(3_usize), 3_i32), (Key::new(6_usize), 6_i32)]);
+    het_map.sort_keys::<i32>();
+    let slice = het_map.as_slice::<i32>().unwrap();
+
+    assert_eq!(slice.binary_search_keys(&Key::new(6_usize)), Ok(1));
+    assert_eq!(slice.binary_search_keys(&Key::new(7_usize)), Err(2));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_as_mut_slice() {
+    let mut het_map = het_map_from_range(4);
+    {
+        let slice = het_map.as_mut_slice::<u64>().unwrap();
+        slice[0] = 10_u64;
+        for value in slice[2..].values_mut() {
+            *value = 0_u64;
+        }
+    }
+
+    assert_eq!(het_map.values::<u64>().unwrap().copied().collect::<Vec<_>>(), [10, 1, 0, 0]);
+    assert_eq!(het_map.values::<i32>().unwrap().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+}
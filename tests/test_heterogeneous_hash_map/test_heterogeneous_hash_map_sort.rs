@@ -0,0 +1,55 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use super::keys;
+
+use alloc_crate::string::String;
+
+fn scrambled_het_map() -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.extend([5_usize, 2, 7, 0, 3, 6, 1, 4].map(|i| (Key::new(i), -(i as i32))));
+    het_map.extend([3_usize, 1, 2, 0].map(|i| (Key::new(i), i as u64)));
+
+    het_map
+}
+
+#[test]
+fn test_heterogeneous_hash_map_sort_keys() {
+    let mut het_map = scrambled_het_map();
+    het_map.sort_keys::<i32>();
+
+    assert_eq!(keys::<i32>(&het_map), [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(keys::<u64>(&het_map), [3, 1, 2, 0]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_sort_by() {
+    let mut het_map = scrambled_het_map();
+    het_map.sort_by::<i32, _>(|_k1, v1, _k2, v2| v1.cmp(v2));
+
+    assert_eq!(keys::<i32>(&het_map), [7, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(keys::<u64>(&het_map), [3, 1, 2, 0]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_sort_by_lookup() {
+    let mut het_map = scrambled_het_map();
+    het_map.sort_by::<u64, _>(|k1, _v1, k2, _v2| k1.cmp(k2));
+
+    for i in 0..4 {
+        assert_eq!(het_map.get::<u64, _>(&Key::new(i)), Some(&(i as u64)));
+        assert_eq!(het_map.get_index_of::<u64, _>(&Key::new(i)), Some(i));
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_sort_missing_type() {
+    let mut het_map = scrambled_het_map();
+    het_map.sort_keys::<String>();
+    het_map.sort_by::<String, _>(|k1, _v1, k2, _v2| k1.cmp(k2));
+
+    assert!(!het_map.contains_type::<String>());
+    assert_eq!(het_map.len_types(), 2);
+}
@@ -0,0 +1,237 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::{
+    String,
+    ToString,
+};
+use alloc_crate::vec::Vec;
+use core::fmt;
+
+trait Tick {
+    fn tick(&mut self);
+    fn count(&self) -> u32;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Timer(u32);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Countdown(u32);
+
+impl Tick for Timer {
+    fn tick(&mut self) {
+        self.0 += 1;
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Tick for Countdown {
+    fn tick(&mut self) {
+        self.0 = self.0.saturating_sub(1);
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
+
+fn ticking_het_map() -> HeterogeneousHashMap<usize> {
+    let mut het_map = HeterogeneousHashMap::new();
+    het_map.register_as_mut::<Timer, dyn Tick>(|value| value, |value| value);
+    het_map.register_as_mut::<Countdown, dyn Tick>(|value| value, |value| value);
+    het_map.insert(Key::new(1_usize), Timer(0));
+    het_map.insert(Key::new(2_usize), Timer(10));
+    het_map.insert(Key::new(1_usize), Countdown(5));
+    het_map.insert(Key::new(1_usize), 7_i32);
+
+    het_map
+}
+
+fn sorted_counts(het_map: &HeterogeneousHashMap<usize>) -> Vec<(usize, u32)> {
+    let mut counts: Vec<(usize, u32)> = het_map
+        .iter_as::<dyn Tick>()
+        .map(|(key, value)| (*key, value.count()))
+        .collect();
+    counts.sort();
+
+    counts
+}
+
+#[test]
+fn test_heterogeneous_hash_map_register_as_inserts_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert!(het_map.register_as::<Timer, dyn Tick>(|value| value));
+    assert!(het_map.contains_type::<Timer>());
+    assert!(!het_map.register_as::<Timer, dyn fmt::Debug>(|value| value));
+    assert_eq!(het_map.len::<Timer>(), Some(0));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_register_as_existing_type_keeps_values() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert(Key::new(1_usize), Timer(3));
+
+    assert!(!het_map.register_as::<Timer, dyn Tick>(|value| value));
+    assert_eq!(het_map.get::<Timer, _>(&1_usize), Some(&Timer(3)));
+    assert_eq!(sorted_counts(&het_map), [(1, 3)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_as() {
+    let het_map = ticking_het_map();
+
+    assert_eq!(sorted_counts(&het_map), [(1, 0), (1, 5), (2, 10)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_as_unregistered_view() {
+    let het_map = ticking_het_map();
+
+    assert_eq!(het_map.iter_as::<dyn fmt::Display>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_as_several_views_of_one_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.register_as::<i32, dyn fmt::Display>(|value| value);
+    het_map.register_as::<i32, dyn fmt::Debug>(|value| value);
+    het_map.register_as::<String, dyn fmt::Display>(|value| value);
+    het_map.insert(Key::new(1_usize), 2_i32);
+    het_map.insert(Key::new(1_usize), String::from("foo"));
+
+    let mut displayed: Vec<String> = het_map
+        .iter_as::<dyn fmt::Display>()
+        .map(|(_key, value)| value.to_string())
+        .collect();
+    displayed.sort();
+    let debugged: Vec<String> = het_map
+        .iter_as::<dyn fmt::Debug>()
+        .map(|(_key, value)| alloc_crate::format!("{:?}", value))
+        .collect();
+
+    assert_eq!(displayed, ["2", "foo"]);
+    assert_eq!(debugged, ["2"]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_register_as_replaces_view() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.register_as::<(u32, u32), u32>(|value| &value.0);
+    het_map.insert(Key::new(1_usize), (2_u32, 3_u32));
+
+    assert_eq!(het_map.iter_as::<u32>().map(|(_key, value)| *value).collect::<Vec<_>>(), [2]);
+
+    het_map.register_as::<(u32, u32), u32>(|value| &value.1);
+
+    assert_eq!(het_map.iter_as::<u32>().map(|(_key, value)| *value).collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_as_mut() {
+    let mut het_map = ticking_het_map();
+    for (_key, value) in het_map.iter_as_mut::<dyn Tick>() {
+        value.tick();
+    }
+
+    assert_eq!(het_map.get::<Timer, _>(&1_usize), Some(&Timer(1)));
+    assert_eq!(het_map.get::<Timer, _>(&2_usize), Some(&Timer(11)));
+    assert_eq!(het_map.get::<Countdown, _>(&1_usize), Some(&Countdown(4)));
+    assert_eq!(het_map.get::<i32, _>(&1_usize), Some(&7_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_iter_as_mut_skips_shared_views() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.register_as_mut::<Timer, dyn Tick>(|value| value, |value| value);
+    het_map.register_as::<Countdown, dyn Tick>(|value| value);
+    het_map.insert(Key::new(1_usize), Timer(0));
+    het_map.insert(Key::new(1_usize), Countdown(5));
+
+    for (_key, value) in het_map.iter_as_mut::<dyn Tick>() {
+        value.tick();
+    }
+
+    assert_eq!(het_map.iter_as::<dyn Tick>().count(), 2);
+    assert_eq!(het_map.get::<Timer, _>(&1_usize), Some(&Timer(1)));
+    assert_eq!(het_map.get::<Countdown, _>(&1_usize), Some(&Countdown(5)));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_get_as() {
+    let het_map = ticking_het_map();
+    let mut counts: Vec<u32> = het_map.get_as::<dyn Tick>(&1_usize).map(|value| value.count()).collect();
+    counts.sort();
+
+    assert_eq!(counts, [0, 5]);
+    assert_eq!(
+        het_map
+            .get_as::<dyn Tick>(&2_usize)
+            .map(|value| value.count())
+            .collect::<Vec<_>>(),
+        [10]
+    );
+    assert_eq!(het_map.get_as::<dyn Tick>(&3_usize).count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_remove_type_removes_views() {
+    let mut het_map = ticking_het_map();
+    het_map.remove_type::<Timer>();
+
+    assert_eq!(sorted_counts(&het_map), [(1, 5)]);
+
+    het_map.insert(Key::new(3_usize), Timer(0));
+
+    assert_eq!(sorted_counts(&het_map), [(1, 5)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_take_type_removes_views() {
+    let mut het_map = ticking_het_map();
+    let timers = het_map.take_type::<Timer>().unwrap();
+    het_map.insert(Key::new(3_usize), Timer(0));
+
+    assert_eq!(timers.len(), 2);
+    assert_eq!(sorted_counts(&het_map), [(1, 5)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clear_removes_views() {
+    let mut het_map = ticking_het_map();
+    het_map.clear();
+    het_map.insert(Key::new(1_usize), Timer(0));
+
+    assert_eq!(het_map.iter_as::<dyn Tick>().count(), 0);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_clear_values_keeps_views() {
+    let mut het_map = ticking_het_map();
+    het_map.clear_values();
+    het_map.insert(Key::new(1_usize), Timer(4));
+
+    assert_eq!(sorted_counts(&het_map), [(1, 4)]);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_clone_keeps_views() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert_cloneable_type::<Timer>();
+    het_map.register_as_mut::<Timer, dyn Tick>(|value| value, |value| value);
+    het_map.insert(Key::new(1_usize), Timer(2));
+
+    let mut cloned_het_map = het_map.try_clone().unwrap();
+    for (_key, value) in cloned_het_map.iter_as_mut::<dyn Tick>() {
+        value.tick();
+    }
+
+    assert_eq!(sorted_counts(&het_map), [(1, 2)]);
+    assert_eq!(sorted_counts(&cloned_het_map), [(1, 3)]);
+}
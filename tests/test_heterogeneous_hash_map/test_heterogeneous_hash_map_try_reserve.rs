@@ -0,0 +1,131 @@
+use heterogeneous_hash_map::{
+    HeterogeneousHashMap,
+    Key,
+    TryReserveErrorKind,
+};
+
+use alloc_crate::string::String;
+
+#[test]
+fn test_heterogeneous_hash_map_try_insert_type_with_capacity() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert_eq!(het_map.try_insert_type_with_capacity::<i32>(10), Ok(true));
+    assert!(het_map.contains_type::<i32>());
+    assert!(het_map.capacity::<i32>() >= Some(10));
+    assert_eq!(het_map.len::<i32>(), Some(0));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_insert_type_with_capacity_existing_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    assert_eq!(het_map.try_insert_type_with_capacity::<i32>(10), Ok(false));
+    assert_eq!(het_map.len_types(), 1);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_insert_type_with_capacity_overflow() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    let result = het_map.try_insert_type_with_capacity::<u64>(usize::MAX);
+
+    assert_eq!(result.map_err(|e| e.kind()), Err(TryReserveErrorKind::CapacityOverflow));
+    assert!(!het_map.contains_type::<u64>());
+    assert_eq!(het_map.len_types(), 1);
+    assert_eq!(het_map.get_metadata::<u64>(), None);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_reserve() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32), (Key::new(2_usize), 3_i32)]);
+
+    assert!(het_map.try_reserve::<i32>(10).is_ok());
+    assert!(het_map.capacity::<i32>() >= Some(3 + 10));
+
+    let old_capacity = het_map.capacity::<i32>();
+    for i in 3..13 {
+        het_map.insert::<i32>(Key::new(i), i as i32);
+    }
+
+    assert_eq!(het_map.capacity::<i32>(), old_capacity);
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_reserve_inserts_type() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert!(!het_map.contains_type::<String>());
+    assert!(het_map.try_reserve::<String>(5).is_ok());
+    assert!(het_map.contains_type::<String>());
+    assert!(het_map.capacity::<String>() >= Some(5));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_reserve_overflow_preserves_contents() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.extend([(Key::new(0_usize), 1_i32), (Key::new(1_usize), 2_i32)]);
+
+    let result = het_map.try_reserve::<i32>(usize::MAX);
+
+    assert_eq!(result.map_err(|e| e.kind()), Err(TryReserveErrorKind::CapacityOverflow));
+    assert_eq!(het_map.len::<i32>(), Some(2));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(0_usize)), Some(&1_i32));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_insert() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    assert_eq!(het_map.try_insert::<i32>(Key::new(1_usize), 2_i32), Ok(None));
+    assert_eq!(het_map.try_insert::<String>(Key::new(1_usize), String::from("foo")), Ok(None));
+
+    assert_eq!(het_map.len_types(), 2);
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&2_i32));
+    assert_eq!(het_map.get::<String, _>(&Key::new(1_usize)), Some(&String::from("foo")));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_insert_existing_key() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(1_usize), 2_i32);
+
+    assert_eq!(het_map.try_insert::<i32>(Key::new(1_usize), 3_i32), Ok(Some(2_i32)));
+    assert_eq!(het_map.len::<i32>(), Some(1));
+    assert_eq!(het_map.get::<i32, _>(&Key::new(1_usize)), Some(&3_i32));
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_extend() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+    het_map.insert::<i32>(Key::new(0_usize), 0_i32);
+
+    let result = het_map.try_extend((1..=10).map(|i| (Key::new(i), i as i32)));
+
+    assert!(result.is_ok());
+    assert_eq!(het_map.len::<i32>(), Some(11));
+    for i in 0..=10 {
+        assert_eq!(het_map.get::<i32, _>(&Key::new(i)), Some(&(i as i32)));
+    }
+}
+
+#[test]
+fn test_heterogeneous_hash_map_try_extend_duplicate_keys() {
+    let mut het_map: HeterogeneousHashMap<usize> = HeterogeneousHashMap::new();
+
+    let result = het_map.try_extend([
+        (Key::new(1_usize), String::from("foo")),
+        (Key::new(2_usize), String::from("bar")),
+        (Key::new(1_usize), String::from("baz")),
+    ]);
+
+    assert!(result.is_ok());
+    assert_eq!(het_map.len::<String>(), Some(2));
+    assert_eq!(het_map.get::<String, _>(&Key::new(1_usize)), Some(&String::from("baz")));
+    assert_eq!(het_map.get::<String, _>(&Key::new(2_usize)), Some(&String::from("bar")));
+}
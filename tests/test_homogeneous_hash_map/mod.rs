@@ -1,2 +1,31 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::vec::Vec;
+
 mod test_entry_api;
 mod test_homogeneous_hash_map;
+mod test_homogeneous_hash_map_allocator;
+mod test_homogeneous_hash_map_index;
+mod test_homogeneous_hash_map_range;
+mod test_homogeneous_hash_map_remove;
+mod test_homogeneous_hash_map_slice;
+mod test_homogeneous_hash_map_sort;
+
+#[cfg(feature = "rayon")]
+mod test_homogeneous_hash_map_rayon;
+#[cfg(feature = "serde")]
+mod test_homogeneous_hash_map_serde;
+
+/// Constructs a homogeneous hash map storing the values `0..len`, each under the key of the same
+/// value, in increasing key order.
+fn map_from_range(len: usize) -> HomogeneousHashMap<usize, i32> {
+    HomogeneousHashMap::from_iter((0..len).map(|i| (Key::new(i), i as i32)))
+}
+
+/// Returns the keys of a homogeneous hash map, in storage order.
+fn keys(map: &HomogeneousHashMap<usize, i32>) -> Vec<usize> {
+    map.keys().map(|key| *key.id()).collect()
+}
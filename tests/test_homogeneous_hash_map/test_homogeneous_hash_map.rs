@@ -553,7 +553,7 @@ fn test_homogeneous_hash_map_values1() {
 #[test]
 fn test_homogeneous_hash_map_values2() {
     let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::new();
-    let expected = Vec::new();
+    let expected: Vec<i32> = Vec::new();
     let result: Vec<i32> = map.values().cloned().collect();
 
     assert_eq!(result, expected);
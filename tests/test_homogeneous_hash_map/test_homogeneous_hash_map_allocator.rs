@@ -0,0 +1,81 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::vec::Vec;
+use std::hash;
+
+#[cfg(feature = "nightly")]
+use std::alloc;
+
+#[cfg(not(feature = "nightly"))]
+use opaque::allocator_api::alloc;
+
+#[test]
+fn test_homogeneous_hash_map_new_in() {
+    let map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> = HomogeneousHashMap::new_in(alloc::Global);
+
+    assert!(map.is_empty());
+    assert_eq!(map.len(), 0);
+}
+
+#[test]
+fn test_homogeneous_hash_map_with_capacity_in() {
+    let map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> =
+        HomogeneousHashMap::with_capacity_in(3, alloc::Global);
+
+    assert!(map.is_empty());
+    assert!(map.capacity() >= 3);
+}
+
+#[test]
+fn test_homogeneous_hash_map_with_hasher_in() {
+    let mut map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> =
+        HomogeneousHashMap::with_hasher_in(hash::RandomState::new(), alloc::Global);
+    map.insert(Key::new(1_usize), 2_i32);
+    map.insert(Key::new(2_usize), 3_i32);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&Key::new(1_usize)), Some(&2_i32));
+    assert_eq!(map.get(&Key::new(2_usize)), Some(&3_i32));
+}
+
+#[test]
+fn test_homogeneous_hash_map_with_capacity_and_hasher_in() {
+    let mut map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> =
+        HomogeneousHashMap::with_capacity_and_hasher_in(3, hash::RandomState::new(), alloc::Global);
+
+    assert!(map.capacity() >= 3);
+    let old_capacity = map.capacity();
+
+    map.insert(Key::new(1_usize), 2_i32);
+    map.insert(Key::new(2_usize), 3_i32);
+    map.insert(Key::new(3_usize), 5_i32);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.capacity(), old_capacity);
+}
+
+#[test]
+fn test_homogeneous_hash_map_clone_in() {
+    let mut map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> = HomogeneousHashMap::new_in(alloc::Global);
+    map.insert(Key::new(1_usize), 2_i32);
+
+    let cloned_map = map.clone();
+    let _alloc: &alloc::Global = cloned_map.allocator();
+
+    assert_eq!(cloned_map, map);
+}
+
+#[test]
+fn test_homogeneous_hash_map_into_iter_in() {
+    let mut map: HomogeneousHashMap<usize, i32, hash::RandomState, alloc::Global> = HomogeneousHashMap::new_in(alloc::Global);
+    map.insert(Key::new(1_usize), 2_i32);
+    map.insert(Key::new(2_usize), 3_i32);
+
+    let mut result: Vec<i32> = map.into_values().collect();
+    result.sort();
+
+    assert_eq!(result, [2_i32, 3_i32]);
+}
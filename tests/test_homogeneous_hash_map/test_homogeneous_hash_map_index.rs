@@ -0,0 +1,133 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+#[test]
+fn test_homogeneous_hash_map_get_index_insertion_order() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..16).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    for i in 0..16 {
+        assert_eq!(map.get_index(i), Some((&Key::new(i), &((i as i32) * 10))));
+    }
+
+    assert_eq!(map.get_index(16), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_index_empty() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::new();
+
+    assert_eq!(map.get_index(0), None);
+    assert_eq!(map.first(), None);
+    assert_eq!(map.last(), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_index_mut() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+    for i in 0..4 {
+        let (_, value) = map.get_index_mut(i).unwrap();
+        *value += 1;
+    }
+
+    for i in 0..4 {
+        assert_eq!(map.get(&Key::new(i)), Some(&((i as i32) * 10 + 1)));
+    }
+
+    assert_eq!(map.get_index_mut(4), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_index_of() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..8).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    for i in 0..8 {
+        assert_eq!(map.get_index_of(&Key::new(i)), Some(i));
+    }
+
+    assert_eq!(map.get_index_of(&Key::new(8_usize)), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_full() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..8).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    for i in 0..8 {
+        assert_eq!(map.get_full(&Key::new(i)), Some((i, &Key::new(i), &((i as i32) * 10))));
+    }
+
+    assert_eq!(map.get_full(&Key::new(8_usize)), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_index_of_get_index_consistent() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..8).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    for i in 0..8 {
+        let index = map.get_index_of(&Key::new(i)).unwrap();
+
+        assert_eq!(map.get_index(index).map(|(key, _)| key), Some(&Key::new(i)));
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_first_last() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..5).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.first(), Some((&Key::new(0_usize), &0_i32)));
+    assert_eq!(map.last(), Some((&Key::new(4_usize), &40_i32)));
+}
+
+#[test]
+fn test_homogeneous_hash_map_pop() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..3).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.pop(), Some((Key::new(2_usize), 20_i32)));
+    assert_eq!(map.pop(), Some((Key::new(1_usize), 10_i32)));
+    assert_eq!(map.pop(), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(map.pop(), None);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_remove_index() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.swap_remove_index(1), Some((Key::new(1_usize), 10_i32)));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_index(0), Some((&Key::new(0_usize), &0_i32)));
+    assert_eq!(map.get_index(1), Some((&Key::new(3_usize), &30_i32)));
+    assert_eq!(map.get_index(2), Some((&Key::new(2_usize), &20_i32)));
+    assert_eq!(map.get(&Key::new(1_usize)), None);
+    assert_eq!(map.get_index_of(&Key::new(3_usize)), Some(1));
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_remove_index_out_of_bounds() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.swap_remove_index(4), None);
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn test_homogeneous_hash_map_shift_remove_index() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.shift_remove_index(1), Some((Key::new(1_usize), 10_i32)));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_index(0), Some((&Key::new(0_usize), &0_i32)));
+    assert_eq!(map.get_index(1), Some((&Key::new(2_usize), &20_i32)));
+    assert_eq!(map.get_index(2), Some((&Key::new(3_usize), &30_i32)));
+    assert_eq!(map.get(&Key::new(1_usize)), None);
+    assert_eq!(map.get_index_of(&Key::new(3_usize)), Some(2));
+}
+
+#[test]
+fn test_homogeneous_hash_map_shift_remove_index_out_of_bounds() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((0..4).map(|i| (Key::new(i), (i as i32) * 10)));
+
+    assert_eq!(map.shift_remove_index(4), None);
+    assert_eq!(map.len(), 4);
+}
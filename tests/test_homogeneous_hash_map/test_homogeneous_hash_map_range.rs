@@ -0,0 +1,148 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use super::{
+    keys,
+    map_from_range,
+};
+
+use alloc_crate::vec::Vec;
+
+#[test]
+fn test_homogeneous_hash_map_drain_range_front() {
+    let mut map = map_from_range(6);
+    let drained: Vec<(Key<usize, i32>, i32)> = map.drain_range(..2).collect();
+
+    assert_eq!(drained, [(Key::new(0_usize), 0_i32), (Key::new(1_usize), 1_i32)]);
+    assert_eq!(keys(&map), [2, 3, 4, 5]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_drain_range_middle_preserves_order() {
+    let mut map = map_from_range(6);
+    let drained: Vec<usize> = map.drain_range(2..4).map(|(key, _)| *key.id()).collect();
+
+    assert_eq!(drained, [2, 3]);
+    assert_eq!(keys(&map), [0, 1, 4, 5]);
+
+    for i in [0_usize, 1, 4, 5] {
+        assert_eq!(map.get(&Key::new(i)), Some(&(i as i32)));
+    }
+    for i in [2_usize, 3] {
+        assert_eq!(map.get(&Key::new(i)), None);
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_drain_range_empty() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.drain_range(2..2).count(), 0);
+    assert_eq!(keys(&map), [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_drain_range_full() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.drain_range(..).count(), 4);
+    assert!(map.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_homogeneous_hash_map_drain_range_out_of_bounds() {
+    let mut map = map_from_range(4);
+
+    let _ = map.drain_range(2..5);
+}
+
+#[test]
+fn test_homogeneous_hash_map_truncate() {
+    let mut map = map_from_range(6);
+    map.truncate(3);
+
+    assert_eq!(keys(&map), [0, 1, 2]);
+    assert_eq!(map.get(&Key::new(4_usize)), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_truncate_longer_than_map() {
+    let mut map = map_from_range(3);
+    map.truncate(10);
+
+    assert_eq!(keys(&map), [0, 1, 2]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_split_off() {
+    let mut map = map_from_range(6);
+    let other = map.split_off(4);
+
+    assert_eq!(keys(&map), [0, 1, 2, 3]);
+    assert_eq!(keys(&other), [4, 5]);
+    assert_eq!(other.get(&Key::new(5_usize)), Some(&5_i32));
+    assert_eq!(map.get(&Key::new(5_usize)), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_split_off_at_len() {
+    let mut map = map_from_range(3);
+    let other = map.split_off(3);
+
+    assert_eq!(keys(&map), [0, 1, 2]);
+    assert!(other.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_homogeneous_hash_map_split_off_out_of_bounds() {
+    let mut map = map_from_range(3);
+
+    let _ = map.split_off(4);
+}
+
+#[test]
+fn test_homogeneous_hash_map_append() {
+    let mut map = map_from_range(3);
+    let mut other: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter((3..5).map(|i: usize| (Key::new(i), i as i32)));
+    map.append(&mut other);
+
+    assert!(other.is_empty());
+    assert_eq!(keys(&map), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_append_overlapping_keys() {
+    let mut map = map_from_range(3);
+    let mut other = HomogeneousHashMap::from([(Key::new(5_usize), 50_i32), (Key::new(1_usize), 10_i32)]);
+    map.append(&mut other);
+
+    assert!(other.is_empty());
+    assert_eq!(keys(&map), [0, 1, 2, 5]);
+    assert_eq!(map.get(&Key::new(1_usize)), Some(&10_i32));
+    assert_eq!(map.get(&Key::new(5_usize)), Some(&50_i32));
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_range() {
+    let map = map_from_range(6);
+    let slice = map.get_range(2..5).unwrap();
+    let slice_keys: Vec<usize> = slice.iter().map(|(key, _)| *key.id()).collect();
+
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice_keys, [2, 3, 4]);
+    assert_eq!(slice.first(), Some((&Key::new(2_usize), &2_i32)));
+    assert_eq!(slice.last(), Some((&Key::new(4_usize), &4_i32)));
+}
+
+#[test]
+fn test_homogeneous_hash_map_get_range_out_of_bounds() {
+    let map = map_from_range(4);
+
+    assert!(map.get_range(2..5).is_none());
+    assert!(map.get_range(5..).is_none());
+    assert!(map.get_range(4..).unwrap().is_empty());
+}
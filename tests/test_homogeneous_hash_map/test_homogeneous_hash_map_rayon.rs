@@ -0,0 +1,107 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use alloc_crate::string::String;
+use alloc_crate::vec::Vec;
+use rayon::prelude::*;
+
+const LEN: usize = 10_000;
+
+fn numbered_map() -> HomogeneousHashMap<usize, u64> {
+    let mut map = HomogeneousHashMap::new();
+    map.extend((0..LEN).map(|i| (Key::new(i), i as u64)));
+
+    map
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_iter() {
+    let map = numbered_map();
+    let sum: u64 = map.par_iter().map(|(_key, value)| *value).sum();
+
+    assert_eq!(sum, (LEN * (LEN - 1) / 2) as u64);
+    assert_eq!(map.par_iter().len(), LEN);
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_iter_preserves_storage_order() {
+    let map = numbered_map();
+    let keys: Vec<usize> = map.par_iter().map(|(key, _value)| *key.id()).collect();
+    let expected: Vec<usize> = map.keys().map(|key| *key.id()).collect();
+
+    assert_eq!(keys, expected);
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_iter_matches_iter() {
+    let map = numbered_map();
+    let result: Vec<(usize, u64)> = map.par_iter().map(|(key, value)| (*key.id(), *value)).collect();
+    let expected: Vec<(usize, u64)> = map.iter().map(|(key, value)| (*key.id(), *value)).collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_iter_empty() {
+    let map: HomogeneousHashMap<usize, u64> = HomogeneousHashMap::new();
+
+    assert_eq!(map.par_iter().count(), 0);
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_iter_mut() {
+    let mut map = numbered_map();
+    map.par_iter_mut().for_each(|(key, value)| *value += *key.id() as u64);
+
+    for (key, value) in map.iter() {
+        assert_eq!(*value, 2 * (*key.id() as u64));
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_values_mut() {
+    let mut map: HomogeneousHashMap<usize, String> = HomogeneousHashMap::new();
+    map.extend((0..100).map(|i| (Key::new(i), String::from("value"))));
+    map.par_values_mut().for_each(|value| value.push('!'));
+
+    assert!(map.values().all(|value| value == "value!"));
+    assert_eq!(map.par_values_mut().len(), 100);
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_retain() {
+    let mut map = numbered_map();
+    map.par_retain(|key, _value| *key.id() % 7 == 0);
+    let expected: Vec<usize> = (0..LEN).filter(|i| i % 7 == 0).collect();
+
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), expected);
+    for key in expected.iter() {
+        assert_eq!(map.get(key), Some(&(*key as u64)));
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_retain_mutates_kept_values() {
+    let mut map = numbered_map();
+    map.par_retain(|_key, value| {
+        *value *= 2;
+        *value % 4 == 0
+    });
+
+    assert_eq!(map.len(), LEN / 2);
+    assert!(map.iter().all(|(key, value)| *value == 2 * (*key.id() as u64)));
+}
+
+#[test]
+fn test_homogeneous_hash_map_par_retain_matches_retain() {
+    let mut par_map = numbered_map();
+    let mut map = numbered_map();
+    map.swap_remove(&5_usize);
+    par_map.swap_remove(&5_usize);
+    map.retain(|key, _value| key.id() % 3 != 1);
+    par_map.par_retain(|key, _value| key.id() % 3 != 1);
+
+    assert_eq!(par_map.keys().collect::<Vec<_>>(), map.keys().collect::<Vec<_>>());
+}
@@ -0,0 +1,131 @@
+use heterogeneous_hash_map::{
+    Entry,
+    Key,
+};
+
+use super::{
+    keys,
+    map_from_range,
+};
+
+
+#[test]
+fn test_homogeneous_hash_map_shift_remove_preserves_order() {
+    let mut map = map_from_range(6);
+
+    assert_eq!(map.shift_remove(&Key::new(1_usize)), Some(1_i32));
+    assert_eq!(map.shift_remove(&Key::new(4_usize)), Some(4_i32));
+    assert_eq!(map.shift_remove(&Key::new(4_usize)), None);
+
+    assert_eq!(keys(&map), [0, 2, 3, 5]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_shift_remove_entry() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.shift_remove_entry(&Key::new(0_usize)), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(map.shift_remove_entry(&Key::new(0_usize)), None);
+
+    assert_eq!(keys(&map), [1, 2, 3]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_shift_remove_full() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.shift_remove_full(&Key::new(2_usize)), Some((2, Key::new(2_usize), 2_i32)));
+    assert_eq!(map.shift_remove_full(&Key::new(2_usize)), None);
+
+    assert_eq!(keys(&map), [0, 1, 3]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_remove() {
+    let mut map = map_from_range(6);
+
+    assert_eq!(map.swap_remove(&Key::new(1_usize)), Some(1_i32));
+    assert_eq!(map.swap_remove(&Key::new(1_usize)), None);
+
+    assert_eq!(keys(&map), [0, 5, 2, 3, 4]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_remove_entry() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.swap_remove_entry(&Key::new(0_usize)), Some((Key::new(0_usize), 0_i32)));
+    assert_eq!(map.swap_remove_entry(&Key::new(0_usize)), None);
+
+    assert_eq!(keys(&map), [3, 1, 2]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_remove_full() {
+    let mut map = map_from_range(4);
+
+    assert_eq!(map.swap_remove_full(&Key::new(1_usize)), Some((1, Key::new(1_usize), 1_i32)));
+    assert_eq!(map.swap_remove_full(&Key::new(1_usize)), None);
+
+    assert_eq!(keys(&map), [0, 3, 2]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_remove_matches_swap_remove() {
+    let mut map1 = map_from_range(8);
+    let mut map2 = map_from_range(8);
+
+    for i in [3_usize, 0, 6] {
+        assert_eq!(map1.remove(&Key::new(i)), map2.swap_remove(&Key::new(i)));
+    }
+
+    assert_eq!(keys(&map1), keys(&map2));
+}
+
+#[test]
+fn test_homogeneous_hash_map_occupied_entry_shift_remove() {
+    let mut map = map_from_range(5);
+    let result = match map.entry(Key::new(1_usize)) {
+        Entry::Occupied(occupied_entry) => occupied_entry.shift_remove(),
+        Entry::Vacant(_) => panic!("The entry must be occupied"),
+    };
+
+    assert_eq!(result, 1_i32);
+    assert_eq!(keys(&map), [0, 2, 3, 4]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_occupied_entry_shift_remove_entry() {
+    let mut map = map_from_range(5);
+    let result = match map.entry(Key::new(1_usize)) {
+        Entry::Occupied(occupied_entry) => occupied_entry.shift_remove_entry(),
+        Entry::Vacant(_) => panic!("The entry must be occupied"),
+    };
+
+    assert_eq!(result, (Key::new(1_usize), 1_i32));
+    assert_eq!(keys(&map), [0, 2, 3, 4]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_occupied_entry_swap_remove() {
+    let mut map = map_from_range(5);
+    let result = match map.entry(Key::new(1_usize)) {
+        Entry::Occupied(occupied_entry) => occupied_entry.swap_remove(),
+        Entry::Vacant(_) => panic!("The entry must be occupied"),
+    };
+
+    assert_eq!(result, 1_i32);
+    assert_eq!(keys(&map), [0, 4, 2, 3]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_occupied_entry_swap_remove_entry() {
+    let mut map = map_from_range(5);
+    let result = match map.entry(Key::new(1_usize)) {
+        Entry::Occupied(occupied_entry) => occupied_entry.swap_remove_entry(),
+        Entry::Vacant(_) => panic!("The entry must be occupied"),
+    };
+
+    assert_eq!(result, (Key::new(1_usize), 1_i32));
+    assert_eq!(keys(&map), [0, 4, 2, 3]);
+}
@@ -0,0 +1,97 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use std::string::{
+    String,
+    ToString,
+};
+use std::vec::Vec;
+
+#[test]
+fn test_key_serialize() {
+    let key: Key<usize, i32> = Key::new(3_usize);
+    let result = serde_json::to_string(&key).unwrap();
+
+    assert_eq!(result, "3");
+}
+
+#[test]
+fn test_key_deserialize() {
+    let result: Key<usize, i32> = serde_json::from_str("3").unwrap();
+
+    assert_eq!(result, Key::new(3_usize));
+}
+
+#[test]
+fn test_key_round_trip_string() {
+    let key: Key<String, i32> = Key::new(String::from("foo"));
+    let serialized = serde_json::to_string(&key).unwrap();
+    let result: Key<String, i32> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(serialized, "\"foo\"");
+    assert_eq!(result, key);
+}
+
+#[test]
+fn test_homogeneous_hash_map_serialize_empty() {
+    let map: HomogeneousHashMap<String, i32> = HomogeneousHashMap::new();
+    let result = serde_json::to_string(&map).unwrap();
+
+    assert_eq!(result, "{}");
+}
+
+#[test]
+fn test_homogeneous_hash_map_serialize_insertion_order() {
+    let mut map: HomogeneousHashMap<String, i32> = HomogeneousHashMap::new();
+    map.insert(Key::new(String::from("c")), 3_i32);
+    map.insert(Key::new(String::from("a")), 1_i32);
+    map.insert(Key::new(String::from("b")), 2_i32);
+
+    let result = serde_json::to_string(&map).unwrap();
+
+    assert_eq!(result, r#"{"c":3,"a":1,"b":2}"#);
+}
+
+#[test]
+fn test_homogeneous_hash_map_deserialize() {
+    let result: HomogeneousHashMap<String, i32> = serde_json::from_str(r#"{"c":3,"a":1,"b":2}"#).unwrap();
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.get(&Key::new(String::from("a"))), Some(&1_i32));
+    assert_eq!(result.get(&Key::new(String::from("b"))), Some(&2_i32));
+    assert_eq!(result.get(&Key::new(String::from("c"))), Some(&3_i32));
+}
+
+#[test]
+fn test_homogeneous_hash_map_deserialize_duplicate_keys() {
+    let result: HomogeneousHashMap<String, i32> = serde_json::from_str(r#"{"a":1,"a":2}"#).unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.get(&Key::new(String::from("a"))), Some(&2_i32));
+}
+
+#[test]
+fn test_homogeneous_hash_map_deserialize_invalid() {
+    let result: Result<HomogeneousHashMap<String, i32>, _> = serde_json::from_str(r#"[1, 2, 3]"#);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_homogeneous_hash_map_round_trip_preserves_order() {
+    let mut map: HomogeneousHashMap<String, String> = HomogeneousHashMap::new();
+    for i in (0..32).rev() {
+        map.insert(Key::new(i.to_string()), (i * i).to_string());
+    }
+
+    let serialized = serde_json::to_string(&map).unwrap();
+    let result: HomogeneousHashMap<String, String> = serde_json::from_str(&serialized).unwrap();
+
+    let expected: Vec<(&Key<String, String>, &String)> = map.iter().collect();
+    let result_entries: Vec<(&Key<String, String>, &String)> = result.iter().collect();
+
+    assert_eq!(result, map);
+    assert_eq!(result_entries, expected);
+}
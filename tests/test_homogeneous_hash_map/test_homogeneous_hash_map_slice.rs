@@ -0,0 +1,195 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+    Slice,
+};
+
+use super::map_from_range;
+
+use alloc_crate::format;
+use alloc_crate::vec::Vec;
+use core::cmp;
+
+fn slice_keys(slice: &Slice<usize, i32>) -> Vec<usize> {
+    slice.keys().map(|key| *key.id()).collect()
+}
+
+fn sum_divide_and_conquer(slice: &Slice<usize, i32>) -> i32 {
+    match slice.len() {
+        0 => 0,
+        1 => slice[0],
+        len => {
+            let (left, right) = slice.split_at(len / 2);
+
+            sum_divide_and_conquer(left) + sum_divide_and_conquer(right)
+        }
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_as_slice() {
+    let map = map_from_range(5);
+    let slice = map.as_slice();
+
+    assert_eq!(slice.len(), map.len());
+    assert_eq!(slice_keys(slice), [0, 1, 2, 3, 4]);
+    assert_eq!(slice.values().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_as_slice_empty() {
+    let map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::new();
+    let slice = map.as_slice();
+
+    assert!(slice.is_empty());
+    assert_eq!(slice.first(), None);
+    assert_eq!(slice.last(), None);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_index() {
+    let map = map_from_range(5);
+    let slice = map.as_slice();
+
+    for i in 0..5 {
+        assert_eq!(slice[i], i as i32);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_homogeneous_hash_map_slice_index_out_of_bounds() {
+    let map = map_from_range(5);
+
+    let _ = map.as_slice()[5];
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_sub_slice() {
+    let map = map_from_range(6);
+    let slice = map.as_slice();
+
+    assert_eq!(slice_keys(&slice[1..4]), [1, 2, 3]);
+    assert_eq!(slice_keys(&slice[..2]), [0, 1]);
+    assert_eq!(slice_keys(&slice[4..]), [4, 5]);
+    assert_eq!(slice_keys(&slice[..=1]), [0, 1]);
+    assert_eq!(slice_keys(&slice[2..=3]), [2, 3]);
+    assert_eq!(slice_keys(&slice[1..4][1..]), [2, 3]);
+    assert_eq!(slice[2..][0], 2_i32);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_get_range() {
+    let map = map_from_range(6);
+    let slice = &map.as_slice()[1..];
+
+    assert_eq!(slice_keys(slice.get_range(1..3).unwrap()), [2, 3]);
+    assert!(slice.get_range(4..6).is_none());
+    assert!(slice.get_range(5..).unwrap().is_empty());
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_pagination() {
+    let map = map_from_range(10);
+    let slice = map.as_slice();
+    let page_size = 4;
+    let pages: Vec<Vec<usize>> = (0..slice.len())
+        .step_by(page_size)
+        .map(|start| slice_keys(&slice[start..(start + page_size).min(slice.len())]))
+        .collect();
+
+    assert_eq!(pages, [std::vec![0, 1, 2, 3], std::vec![4, 5, 6, 7], std::vec![8, 9]]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_split_at_divide_and_conquer() {
+    let map = map_from_range(17);
+
+    assert_eq!(sum_divide_and_conquer(map.as_slice()), (0..17).sum::<i32>());
+}
+
+#[test]
+fn test_homogeneous_hash_map_as_mut_slice() {
+    let mut map = map_from_range(4);
+    {
+        let slice = map.as_mut_slice();
+        slice[0] = 100_i32;
+        for (_key, value) in &mut slice[2..] {
+            *value *= 10_i32;
+        }
+        if let Some((_key, value)) = slice.get_index_mut(1) {
+            *value = -1_i32;
+        }
+    }
+
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), [100, -1, 20, 30]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_split_at_mut() {
+    let mut map = map_from_range(4);
+    {
+        let (left, right) = map.as_mut_slice().split_at_mut(2);
+        for value in left.values_mut() {
+            *value = 0_i32;
+        }
+        if let Some((_key, value)) = right.last_mut() {
+            *value = 99_i32;
+        }
+        if let Some((_key, value)) = right.first_mut() {
+            *value = 42_i32;
+        }
+    }
+
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), [0, 0, 42, 99]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_binary_search_keys() {
+    let mut map: HomogeneousHashMap<usize, i32> =
+        HomogeneousHashMap::from_iter([8_usize, 2, 6, 0, 4].into_iter().map(|i| (Key::new(i), i as i32)));
+    map.sort_keys();
+    let slice = map.as_slice();
+
+    for (index, key) in [0_usize, 2, 4, 6, 8].into_iter().enumerate() {
+        assert_eq!(slice.binary_search_keys(&Key::new(key)), Ok(index));
+    }
+    for (index, key) in [1_usize, 3, 5, 7, 9].into_iter().enumerate() {
+        assert_eq!(slice.binary_search_keys(&Key::new(key)), Err(index + 1));
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_binary_search_by() {
+    let mut map = map_from_range(6);
+    map.sort_by(|_k1, v1, _k2, v2| v2.cmp(v1));
+    let slice = map.as_slice();
+
+    assert_eq!(slice.binary_search_by(|_key, value| 3_i32.cmp(value)), Ok(2));
+    assert_eq!(slice.binary_search_by(|_key, value| 10_i32.cmp(value)), Err(0));
+    assert_eq!(
+        slice.binary_search_by_key(&cmp::Reverse(0_i32), |_key, value| cmp::Reverse(*value)),
+        Ok(5)
+    );
+    assert_eq!(slice.partition_point(|_key, value| *value > 1_i32), 4);
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_eq() {
+    let map1 = map_from_range(5);
+    let map2 = map_from_range(3);
+
+    assert_eq!(&map1.as_slice()[..3], map2.as_slice());
+    assert_ne!(map1.as_slice(), map2.as_slice());
+    assert_ne!(&map1.as_slice()[1..4], map2.as_slice());
+}
+
+#[test]
+fn test_homogeneous_hash_map_slice_debug() {
+    let map = map_from_range(2);
+
+    assert_eq!(
+        format!("{:?}", map.as_slice()),
+        format!("{:?}", [(Key::<usize, i32>::new(0), 0_i32), (Key::new(1), 1)])
+    );
+}
@@ -0,0 +1,113 @@
+use heterogeneous_hash_map::{
+    HomogeneousHashMap,
+    Key,
+};
+
+use super::keys;
+
+
+fn scrambled_map() -> HomogeneousHashMap<usize, i32> {
+    HomogeneousHashMap::from_iter([5_usize, 2, 7, 0, 3, 6, 1, 4].map(|i| (Key::new(i), -(i as i32))))
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_keys() {
+    let mut map = scrambled_map();
+    map.sort_keys();
+
+    assert_eq!(keys(&map), [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_keys_lookup() {
+    let mut map = scrambled_map();
+    map.sort_keys();
+
+    for i in 0..8 {
+        assert_eq!(map.get(&Key::new(i)), Some(&-(i as i32)));
+        assert_eq!(map.get_index_of(&Key::new(i)), Some(i));
+    }
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_by() {
+    let mut map = scrambled_map();
+    map.sort_by(|_k1, v1, _k2, v2| v1.cmp(v2));
+
+    assert_eq!(keys(&map), [7, 6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_by_stable() {
+    let mut map: HomogeneousHashMap<usize, i32> = HomogeneousHashMap::from_iter([
+        (Key::new(0_usize), 1_i32),
+        (Key::new(1_usize), 0_i32),
+        (Key::new(2_usize), 1_i32),
+        (Key::new(3_usize), 0_i32),
+    ]);
+    map.sort_by(|_k1, v1, _k2, v2| v1.cmp(v2));
+
+    assert_eq!(keys(&map), [1, 3, 0, 2]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_unstable_by() {
+    let mut map = scrambled_map();
+    map.sort_unstable_by(|k1, _v1, k2, _v2| k2.cmp(k1));
+
+    assert_eq!(keys(&map), [7, 6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_sort_by_cached_key() {
+    let mut map = scrambled_map();
+    map.sort_by_cached_key(|key, _value| *key.id() % 4);
+
+    assert_eq!(keys(&map), [0, 4, 5, 1, 2, 6, 7, 3]);
+}
+
+#[test]
+fn test_homogeneous_hash_map_reverse() {
+    let mut map = scrambled_map();
+    map.reverse();
+
+    assert_eq!(keys(&map), [4, 1, 6, 3, 0, 7, 2, 5]);
+    assert_eq!(map.get(&Key::new(5_usize)), Some(&-5_i32));
+}
+
+#[test]
+fn test_homogeneous_hash_map_move_index() {
+    let mut map = scrambled_map();
+    map.move_index(1, 5);
+
+    assert_eq!(keys(&map), [5, 7, 0, 3, 6, 2, 1, 4]);
+    assert_eq!(map.get_index_of(&Key::new(2_usize)), Some(5));
+
+    map.move_index(5, 1);
+
+    assert_eq!(keys(&map), [5, 2, 7, 0, 3, 6, 1, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_homogeneous_hash_map_move_index_out_of_bounds() {
+    let mut map = scrambled_map();
+    map.move_index(0, 8);
+}
+
+#[test]
+fn test_homogeneous_hash_map_swap_indices() {
+    let mut map = scrambled_map();
+    map.swap_indices(0, 7);
+
+    assert_eq!(keys(&map), [4, 2, 7, 0, 3, 6, 1, 5]);
+    assert_eq!(map.get_index_of(&Key::new(5_usize)), Some(7));
+    assert_eq!(map.get_index_of(&Key::new(4_usize)), Some(0));
+}
+
+#[test]
+#[should_panic]
+fn test_homogeneous_hash_map_swap_indices_out_of_bounds() {
+    let mut map = scrambled_map();
+    map.swap_indices(0, 8);
+}